@@ -269,6 +269,18 @@ impl<'a, T: Scalar> UnsafeSlice<'a, T> {
         };
         Self { data }
     }
+    // For kernels executed on the host.
+    #[doc(hidden)]
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub unsafe fn from_host_raw_parts(ptr: *mut T, len: usize) -> Self {
+        let data = UnsafeSliceRepr {
+            ptr,
+            len,
+            _m: PhantomData,
+        };
+        Self { data }
+    }
     /// A mutable pointer to the buffer's data.
     #[cfg(not(target_arch = "spirv"))]
     #[inline]
@@ -1,19 +1,29 @@
 #[doc(hidden)]
-#[cfg(target_arch = "spirv")]
 pub mod __private {
     use super::{ItemKernel, Kernel};
+    #[cfg(target_arch = "spirv")]
     use core::mem::size_of;
 
+    // Synchronizes the threads of a group executed on the host.
+    #[cfg(not(target_arch = "spirv"))]
+    #[derive(Clone, Copy)]
+    pub struct GroupBarrier {
+        pub data: *const (),
+        pub wait: unsafe fn(*const ()),
+    }
+
     pub struct KernelArgs {
-        pub global_id: u32,
-        pub groups: u32,
-        pub group_id: u32,
+        pub global_id: [u32; 3],
+        pub groups: [u32; 3],
+        pub group_id: [u32; 3],
         pub subgroups: u32,
         pub subgroup_id: u32,
         //pub subgroup_threads: u32,
         pub subgroup_thread_id: u32,
         pub threads: u32,
         pub thread_id: u32,
+        #[cfg(not(target_arch = "spirv"))]
+        pub group_barrier: GroupBarrier,
     }
 
     #[allow(deprecated)]
@@ -30,9 +40,11 @@ pub mod __private {
                 subgroup_thread_id,
                 threads,
                 thread_id,
+                #[cfg(not(target_arch = "spirv"))]
+                group_barrier,
             } = self;
             Kernel {
-                global_threads: groups * threads,
+                global_threads: [groups[0] * threads, groups[1], groups[2]],
                 global_id,
                 groups,
                 group_id,
@@ -42,12 +54,15 @@ pub mod __private {
                 subgroup_thread_id,
                 threads,
                 thread_id,
+                #[cfg(not(target_arch = "spirv"))]
+                group_barrier,
             }
         }
     }
 
     // ensures __krnl_kernel_data is used, and not optimized away
     // removed by krnlc
+    #[cfg(target_arch = "spirv")]
     #[inline]
     pub unsafe fn kernel_data(data: &mut [u32]) {
         use spirv_std::arch::IndexUnchecked;
@@ -59,6 +74,7 @@ pub mod __private {
 
     // passes the length (constant, spec constant, or spec const expr) to krnlc
     // the array is changed from len 1 to the constant
+    #[cfg(target_arch = "spirv")]
     #[inline]
     pub unsafe fn group_buffer_len(data: &mut [u32], index: usize, len: usize) {
         use spirv_std::arch::IndexUnchecked;
@@ -68,6 +84,7 @@ pub mod __private {
         }
     }
 
+    #[cfg(target_arch = "spirv")]
     #[inline]
     pub unsafe fn zero_group_buffer<T: Default + Copy>(
         kernel: &Kernel,
@@ -132,42 +149,92 @@ pub mod __private {
 }
 
 pub struct Kernel {
-    global_threads: u32,
-    global_id: u32,
-    groups: u32,
-    group_id: u32,
+    global_threads: [u32; 3],
+    global_id: [u32; 3],
+    groups: [u32; 3],
+    group_id: [u32; 3],
     subgroups: u32,
     subgroup_id: u32,
     //subgroup_threads: u32,
     subgroup_thread_id: u32,
     threads: u32,
     thread_id: u32,
+    #[cfg(not(target_arch = "spirv"))]
+    group_barrier: __private::GroupBarrier,
 }
 
 impl Kernel {
     /// The number of global threads.
     ///
     /// `global_threads = groups * threads`
+    ///
+    /// The x dimension of [`.global_threads_3d()`](Self::global_threads_3d).
     #[inline]
     pub fn global_threads(&self) -> usize {
-        self.global_threads as usize
+        self.global_threads[0] as usize
+    }
+    /// The number of global threads in each dimension.
+    ///
+    /// Threads are 1d, so `global_threads_3d = [groups_x * threads, groups_y, groups_z]`.
+    #[inline]
+    pub fn global_threads_3d(&self) -> [usize; 3] {
+        [
+            self.global_threads[0] as usize,
+            self.global_threads[1] as usize,
+            self.global_threads[2] as usize,
+        ]
     }
     /// The global thread id.
     ///
     /// `global_id = group_id * threads + thread_id`
+    ///
+    /// The x dimension of [`.global_id_3d()`](Self::global_id_3d).
     #[inline]
     pub fn global_id(&self) -> usize {
-        self.global_id as usize
+        self.global_id[0] as usize
+    }
+    /// The global thread id in each dimension.
+    ///
+    /// `global_id_3d = [group_id_x * threads + thread_id, group_id_y, group_id_z]`
+    #[inline]
+    pub fn global_id_3d(&self) -> [usize; 3] {
+        [
+            self.global_id[0] as usize,
+            self.global_id[1] as usize,
+            self.global_id[2] as usize,
+        ]
     }
     /// The number of thread groups.
+    ///
+    /// The x dimension of [`.groups_3d()`](Self::groups_3d).
     #[inline]
     pub fn groups(&self) -> usize {
-        self.groups as usize
+        self.groups[0] as usize
+    }
+    /// The number of thread groups in each dimension.
+    #[inline]
+    pub fn groups_3d(&self) -> [usize; 3] {
+        [
+            self.groups[0] as usize,
+            self.groups[1] as usize,
+            self.groups[2] as usize,
+        ]
     }
     /// The group id.
+    ///
+    /// The x dimension of [`.group_id_3d()`](Self::group_id_3d).
     #[inline]
     pub fn group_id(&self) -> usize {
-        self.group_id as usize
+        self.group_id[0] as usize
+    }
+    /// The group id in each dimension.
+    #[inline]
+    pub fn group_id_3d(&self) -> [usize; 3] {
+        [
+            self.group_id[0] as usize,
+            self.group_id[1] as usize,
+            self.group_id[2] as usize,
+        ]
     }
     /// The number of subgroups per group.
     #[inline]
@@ -202,6 +269,29 @@ impl Kernel {
     pub fn thread_id(&self) -> usize {
         self.thread_id as usize
     }
+    /// Synchronizes the threads in the group.
+    ///
+    /// Blocks until all threads in the group have reached the barrier, and makes writes to
+    /// group memory visible to all threads in the group.
+    ///
+    /// Kernels executed on the host run the threads of a group concurrently, and the barrier
+    /// blocks until the other threads of the group reach it.
+    ///
+    /// # Safety
+    /// The barrier must be reached by all threads in the group, otherwise the kernel may hang or
+    /// produce undefined results. Do not call within control flow that depends on the thread,
+    /// ie `if kernel.thread_id() < n { .. }`, or after a thread has returned early.
+    #[inline]
+    pub unsafe fn group_barrier(&self) {
+        #[cfg(target_arch = "spirv")]
+        unsafe {
+            spirv_std::arch::workgroup_memory_barrier_with_group_sync();
+        }
+        #[cfg(not(target_arch = "spirv"))]
+        unsafe {
+            (self.group_barrier.wait)(self.group_barrier.data);
+        }
+    }
 }
 
 pub struct ItemKernel {
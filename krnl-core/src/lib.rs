@@ -1,7 +1,7 @@
 //! Shared core library for [krnl](https://docs.rs/krnl).
+#![no_std]
 #![cfg_attr(
     target_arch = "spirv",
-    no_std,
     feature(asm_experimental_arch, min_specialization)
 )]
 #![cfg_attr(doc_cfg, feature(doc_cfg, doc_auto_cfg))]
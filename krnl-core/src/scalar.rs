@@ -1,6 +1,11 @@
 #[cfg(not(target_arch = "spirv"))]
 use bytemuck::Pod;
 #[cfg(not(target_arch = "spirv"))]
+use core::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+#[cfg(not(target_arch = "spirv"))]
 use derive_more::Display;
 use dry::macro_for;
 #[cfg(not(target_arch = "spirv"))]
@@ -10,11 +15,6 @@ use num_traits::{AsPrimitive, FromPrimitive, NumAssign, NumCast};
 use paste::paste;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-#[cfg(not(target_arch = "spirv"))]
-use std::{
-    fmt::{Debug, Display},
-    str::FromStr,
-};
 
 mod sealed {
     use half::{bf16, f16};
@@ -64,7 +64,7 @@ impl ScalarType {
     }
     /// Size of the type in bytes.
     #[inline]
-    pub fn size(&self) -> usize {
+    pub const fn size(&self) -> usize {
         use ScalarType::*;
         match self {
             U8 | I8 => 1,
@@ -73,12 +73,24 @@ impl ScalarType {
             U64 | I64 | F64 => 8,
         }
     }
+    /// Alignment of the type in bytes.
+    ///
+    /// Equal to the size.
+    #[inline]
+    pub const fn align(&self) -> usize {
+        self.size()
+    }
+    /// Compares with `other`, usable in const contexts.
+    #[inline]
+    pub const fn const_eq(&self, other: &Self) -> bool {
+        *self as u32 == *other as u32
+    }
     /// Name of the type.
     ///
     /// Lowercase, ie "f16", "i32", etc.
     #[cfg(not(target_arch = "spirv"))]
     #[inline]
-    pub fn name(&self) -> &'static str {
+    pub const fn name(&self) -> &'static str {
         use ScalarType::*;
         match self {
             U8 => "u8",
@@ -100,7 +112,7 @@ impl ScalarType {
     /// Uppercase, ie "F16", "I32", etc.
     #[cfg(not(target_arch = "spirv"))]
     #[inline]
-    pub fn as_str(&self) -> &'static str {
+    pub const fn as_str(&self) -> &'static str {
         use ScalarType::*;
         match self {
             U8 => "U8",
@@ -193,7 +205,7 @@ impl<'de> Deserialize<'de> for ScalarType {
                 if let Ok(scalar_type) = ScalarType::from_str(v) {
                     Ok(scalar_type)
                 } else {
-                    Err(E::custom(format!("unknown ScalarType {v}")))
+                    Err(E::custom(format_args!("unknown ScalarType {v}")))
                 }
             }
         }
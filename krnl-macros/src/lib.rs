@@ -19,7 +19,7 @@ use syn::{
     punctuated::Punctuated,
     token::{
         And, Brace, Bracket, Colon, Comma, Const, Eq as SynEq, Fn, Gt, Lt, Mod, Mut, Paren, Pound,
-        Unsafe,
+        RArrow, Unsafe,
     },
     Attribute, Block, Error, Ident, LitInt, LitStr, Visibility,
 };
@@ -104,6 +104,32 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
             item.attr.push(attr);
         }
     }
+    let build_all = {
+        let kernel_builds = module_kernels(&item.tokens)
+            .into_iter()
+            .map(|(attrs, ident)| {
+                quote! {
+                    #(#attrs)*
+                    #ident::__build_default(device.clone())?;
+                }
+            });
+        quote! {
+            /// Builds all kernels in the module for `device`.
+            ///
+            /// Kernels are built with default threads. Kernels that require
+            /// specialization are skipped. Kernels in nested modules are not included.
+            ///
+            /// # Errors
+            /// - See the `build()` method of each kernel's builder.
+            #[cfg(not(target_arch = "spirv"))]
+            #[allow(dead_code)]
+            pub fn build_all(device: #krnl::device::Device) -> #krnl::anyhow::Result<()> {
+                #(#kernel_builds)*
+                let _ = device;
+                Ok(())
+            }
+        }
+    };
     {
         let tokens = item.tokens;
         item.tokens = quote! {
@@ -138,6 +164,14 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                         };
                     }
                 };
+                ($v:literal, $c:ident, $x:literal) => {
+                    #[doc(hidden)]
+                    macro_rules! __krnl_kernel {
+                        ($k:ident) => {
+                            Some(#krnl::macros::__krnl_cache!($v, $c, #ident, $k, $x))
+                        };
+                    }
+                };
             }
             #[cfg(not(krnlc))]
             include!(concat!(env!("CARGO_MANIFEST_DIR"), "/krnl-cache.rs"));
@@ -162,9 +196,40 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
             #tokens
         }
     }
+    item.tokens.extend(build_all);
     item.into_token_stream().into()
 }
 
+// kernels declared directly in the module, with their cfg attributes
+fn module_kernels(tokens: &TokenStream2) -> Vec<(Vec<Attribute>, Ident)> {
+    let Ok(file) = syn::parse2::<syn::File>(tokens.clone()) else {
+        return Vec::new();
+    };
+    file.items
+        .into_iter()
+        .filter_map(|item| {
+            let syn::Item::Fn(item_fn) = item else {
+                return None;
+            };
+            let is_kernel = item_fn.attrs.iter().any(|attr| {
+                attr.path
+                    .segments
+                    .last()
+                    .is_some_and(|x| x.ident == "kernel")
+            });
+            if !is_kernel {
+                return None;
+            }
+            let cfgs = item_fn
+                .attrs
+                .into_iter()
+                .filter(|attr| attr.path.is_ident("cfg"))
+                .collect();
+            Some((cfgs, item_fn.sig.ident))
+        })
+        .collect()
+}
+
 #[derive(Parse, Debug)]
 struct ModuleKrnlArgs {
     #[allow(unused)]
@@ -216,17 +281,186 @@ impl ToTokens for ModuleItem {
 
 #[proc_macro_attribute]
 pub fn kernel(attr: TokenStream, item: TokenStream) -> TokenStream {
-    if !attr.is_empty() {
-        return Error::new_spanned(&TokenStream2::from(attr), "unexpected tokens")
-            .into_compile_error()
-            .into();
-    }
-    match kernel_impl(item.into()) {
+    let result = if attr.is_empty() {
+        kernel_impl(item.into(), None, false)
+    } else {
+        let attr = TokenStream2::from(attr);
+        match syn::parse2::<Ident>(attr.clone()) {
+            Ok(ident) if ident == "reduce" => reduce_kernel_impl(item.into()),
+            Ok(ident) if ident == "host" => kernel_impl(item.into(), None, true),
+            _ => Err(Error::new_spanned(&attr, "expected `reduce` or `host`")),
+        }
+    };
+    match result {
         Ok(tokens) => tokens.into(),
         Err(e) => e.into_compile_error().into(),
     }
 }
 
+#[derive(Parse, Debug)]
+struct ReduceKernelItem {
+    #[call(Attribute::parse_outer)]
+    attrs: Vec<Attribute>,
+    #[allow(unused)]
+    vis: Visibility,
+    #[allow(unused)]
+    fn_token: Fn,
+    ident: Ident,
+    #[allow(unused)]
+    #[paren]
+    paren: Paren,
+    #[inside(paren)]
+    a: Ident,
+    #[allow(unused)]
+    #[inside(paren)]
+    a_colon: Colon,
+    #[inside(paren)]
+    a_ty: KernelTypeScalar,
+    #[allow(unused)]
+    #[inside(paren)]
+    comma: Comma,
+    #[inside(paren)]
+    b: Ident,
+    #[allow(unused)]
+    #[inside(paren)]
+    b_colon: Colon,
+    #[inside(paren)]
+    b_ty: KernelTypeScalar,
+    #[allow(unused)]
+    #[inside(paren)]
+    trailing_comma: Option<Comma>,
+    #[allow(unused)]
+    arrow: RArrow,
+    output_ty: KernelTypeScalar,
+    block: Block,
+}
+
+struct KernelReduce {
+    item_tokens: TokenStream2,
+    ty: Ident,
+}
+
+// Length of the group buffers used to combine the values of each thread in a group.
+const REDUCE_GROUP_LEN: usize = 256;
+
+// Expands `fn combine(a: T, b: T) -> T` into a kernel that reduces `x` into `y`, with one
+// element per group. Each thread combines a strided subset of `x`, then the group is
+// reduced in group memory. Elements are combined out of order, so `combine` must be
+// commutative as well as associative.
+fn reduce_kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
+    let item: ReduceKernelItem = syn::parse2(item_tokens.clone())?;
+    let ReduceKernelItem {
+        attrs,
+        ident,
+        a,
+        a_ty,
+        b,
+        b_ty,
+        output_ty,
+        block,
+        ..
+    } = &item;
+    for ty in [b_ty, output_ty] {
+        if ty.scalar_type != a_ty.scalar_type {
+            return Err(Error::new_spanned(
+                &ty.ident,
+                format!("expected `{}`", a_ty.scalar_type.name()),
+            ));
+        }
+    }
+    let ty = &a_ty.ident;
+    let group_len = Literal::usize_unsuffixed(REDUCE_GROUP_LEN);
+    let group_stride = Literal::usize_unsuffixed(REDUCE_GROUP_LEN / 2);
+    let kernel_tokens = quote! {
+        #(#attrs)*
+        fn #ident(
+            #[global] x: Slice<#ty>,
+            #[group] x_group: UnsafeSlice<#ty, #group_len>,
+            #[group] flags_group: UnsafeSlice<u32, #group_len>,
+            #[global] y: UnsafeSlice<#ty>,
+        ) {
+            use ::krnl_core::{
+                buffer::UnsafeIndex,
+                spirv_std::arch::workgroup_memory_barrier_with_group_sync as group_barrier,
+            };
+
+            #[inline]
+            fn combine(#a: #ty, #b: #ty) -> #ty #block
+
+            let threads = kernel.threads();
+            let thread_id = kernel.thread_id();
+            let global_threads = kernel.global_threads();
+            let mut index = kernel.global_id();
+            let valid = index < x.len();
+            let mut acc: #ty = Default::default();
+            if valid {
+                acc = x[index];
+                index += global_threads;
+                while index < x.len() {
+                    acc = combine(acc, x[index]);
+                    index += global_threads;
+                }
+            }
+            unsafe {
+                group_barrier();
+            }
+            let slot = thread_id % #group_len;
+            let rounds = threads / #group_len + usize::from(threads % #group_len != 0);
+            let mut round = 0;
+            while round < rounds {
+                if valid && thread_id / #group_len == round {
+                    unsafe {
+                        if *flags_group.unsafe_index(slot) != 0 {
+                            *x_group.unsafe_index_mut(slot) = combine(*x_group.unsafe_index(slot), acc);
+                        } else {
+                            *x_group.unsafe_index_mut(slot) = acc;
+                            *flags_group.unsafe_index_mut(slot) = 1;
+                        }
+                    }
+                }
+                unsafe {
+                    group_barrier();
+                }
+                round += 1;
+            }
+            let mut stride = #group_stride;
+            while stride > 0 {
+                if thread_id < stride {
+                    let other = thread_id + stride;
+                    unsafe {
+                        if *flags_group.unsafe_index(other) != 0 {
+                            let value = *x_group.unsafe_index(other);
+                            if *flags_group.unsafe_index(thread_id) != 0 {
+                                *x_group.unsafe_index_mut(thread_id) = combine(*x_group.unsafe_index(thread_id), value);
+                            } else {
+                                *x_group.unsafe_index_mut(thread_id) = value;
+                                *flags_group.unsafe_index_mut(thread_id) = 1;
+                            }
+                        }
+                    }
+                }
+                unsafe {
+                    group_barrier();
+                }
+                stride /= 2;
+            }
+            if thread_id == 0 {
+                unsafe {
+                    *y.unsafe_index_mut(kernel.group_id()) = *x_group.unsafe_index(0);
+                }
+            }
+        }
+    };
+    kernel_impl(
+        kernel_tokens,
+        Some(KernelReduce {
+            item_tokens,
+            ty: ty.clone(),
+        }),
+        false,
+    )
+}
+
 #[derive(Parse, Debug)]
 struct KernelItem {
     #[call(Attribute::parse_outer)]
@@ -389,6 +623,19 @@ impl Parse for KernelTypeScalar {
     }
 }
 
+impl KernelTypeScalar {
+    fn parse_push(input: ParseStream<'_>) -> Result<Self> {
+        if input.peek(Bracket) {
+            let array: syn::TypeArray = input.parse()?;
+            return Err(Error::new_spanned(
+                array,
+                "push constants can't be arrays, try a `#[global]` Slice",
+            ));
+        }
+        input.parse()
+    }
+}
+
 #[derive(Parse, Debug)]
 struct KernelArg {
     kind: KernelArgKind,
@@ -402,6 +649,7 @@ struct KernelArg {
     #[parse_if(kind.is_group())]
     array_ty: Option<KernelTypeArray>,
     #[parse_if(kind.is_push())]
+    #[call(KernelTypeScalar::parse_push)]
     push_ty: Option<KernelTypeScalar>,
 }
 
@@ -474,7 +722,7 @@ impl KernelArgMeta {
             None
         }
     }
-    fn device_fn_def_tokens(&self) -> TokenStream2 {
+    fn device_fn_def_tokens(&self, krnl_core: &TokenStream2) -> TokenStream2 {
         let ident = &self.ident;
         let ty = &self.scalar_ty.ident;
         let mutable = self.mutable;
@@ -483,11 +731,11 @@ impl KernelArgMeta {
             Global => {
                 if mutable {
                     quote! {
-                        #ident: ::krnl_core::buffer::UnsafeSlice<#ty>
+                        #ident: #krnl_core::buffer::UnsafeSlice<#ty>
                     }
                 } else {
                     quote! {
-                        #ident: ::krnl_core::buffer::Slice<#ty>
+                        #ident: #krnl_core::buffer::Slice<#ty>
                     }
                 }
             }
@@ -503,7 +751,7 @@ impl KernelArgMeta {
                 }
             }
             Group => quote! {
-                #ident: ::krnl_core::buffer::UnsafeSlice<#ty>
+                #ident: #krnl_core::buffer::UnsafeSlice<#ty>
             },
             Push => quote! {
                 #ident: #ty
@@ -549,7 +797,7 @@ impl KernelArgMeta {
             Push => TokenStream2::new(),
         }
     }
-    fn device_fn_call_tokens(&self) -> TokenStream2 {
+    fn device_fn_call_tokens(&self, krnl_core: &TokenStream2) -> TokenStream2 {
         let ident = &self.ident;
         let mutable = self.mutable;
         use KernelArgKind::*;
@@ -561,7 +809,7 @@ impl KernelArgMeta {
                 if mutable {
                     quote! {
                         unsafe {
-                            use ::krnl_core::buffer::UnsafeIndex;
+                            use #krnl_core::buffer::UnsafeIndex;
                             #ident.unsafe_index_mut(__krnl_item_id as usize)
                         }
                     }
@@ -751,6 +999,16 @@ impl KernelMeta {
         kernel_desc
             .push_descs
             .sort_by_key(|x| -(x.scalar_type.size() as i32));
+        // Vulkan guarantees maxPushConstantsSize >= 128.
+        const MAX_PUSH_CONSTANTS_SIZE: usize = 128;
+        let push_consts_size = kernel_desc.push_consts_size();
+        if push_consts_size > MAX_PUSH_CONSTANTS_SIZE {
+            let slices = kernel_desc.slice_descs.len();
+            return Err(Error::new(
+                self.ident.span(),
+                format!("{slices} slices and push constants require {push_consts_size} bytes, more than the {MAX_PUSH_CONSTANTS_SIZE} bytes of push constants supported by all devices, try combining slices into fewer buffers"),
+            ));
+        }
         Ok(kernel_desc)
     }
     fn compute_def_args(&self) -> Punctuated<TokenStream2, Comma> {
@@ -932,7 +1190,7 @@ impl KernelMeta {
             }
         }
     }
-    fn device_fn_def_args(&self) -> Punctuated<TokenStream2, Comma> {
+    fn device_fn_def_args(&self, krnl_core: &TokenStream2) -> Punctuated<TokenStream2, Comma> {
         self.spec_metas
             .iter()
             .map(|x| {
@@ -949,16 +1207,164 @@ impl KernelMeta {
                     #ident: #ty
                 }
             })
-            .chain(self.arg_metas.iter().map(|arg| arg.device_fn_def_tokens()))
+            .chain(
+                self.arg_metas
+                    .iter()
+                    .map(|arg| arg.device_fn_def_tokens(krnl_core)),
+            )
             .collect()
     }
-    fn device_fn_call_args(&self) -> Punctuated<TokenStream2, Comma> {
+    fn device_fn_call_args(&self, krnl_core: &TokenStream2) -> Punctuated<TokenStream2, Comma> {
         self.spec_metas
             .iter()
             .map(|spec| spec.ident.to_token_stream())
-            .chain(self.arg_metas.iter().map(|arg| arg.device_fn_call_tokens()))
+            .chain(
+                self.arg_metas
+                    .iter()
+                    .map(|arg| arg.device_fn_call_tokens(krnl_core)),
+            )
             .collect()
     }
+    // Executes one thread of the kernel on the host, for `#[kernel(host)]`.
+    fn host_fn(&self, kernel_desc: &KernelDesc, item_attrs: &[Attribute]) -> TokenStream2 {
+        let krnl_core = quote! { krnl_core };
+        let ident = &self.ident;
+        let unsafe_token = self.unsafe_token;
+        let block = &self.block;
+        let host_fn_def_args = self.device_fn_def_args(&krnl_core);
+        let host_fn_call_args = self.device_fn_call_args(&krnl_core);
+        let specs: TokenStream2 = self
+            .spec_metas
+            .iter()
+            .enumerate()
+            .map(|(index, spec)| {
+                let ident = &spec.ident;
+                let ty = &spec.ty.ident;
+                quote! {
+                    let #ident: #ty = __krnl_thread.spec(#index);
+                }
+            })
+            .collect();
+        let push_fields = kernel_desc.push_descs.iter().map(|push_desc| {
+            let ident = format_ident!("{}", push_desc.name);
+            let ty = format_ident!("{}", push_desc.scalar_type.name());
+            quote! {
+                #ident: #ty
+            }
+        });
+        let push_values = kernel_desc
+            .push_descs
+            .iter()
+            .enumerate()
+            .map(|(index, push_desc)| {
+                let ident = format_ident!("{}", push_desc.name);
+                quote! {
+                    #ident: __krnl_thread.push(#index)
+                }
+            });
+        let spec_def_args: Punctuated<_, Comma> = self
+            .spec_def_args()
+            .into_iter()
+            .map(|arg| {
+                quote! {
+                    #[allow(unused)] #arg
+                }
+            })
+            .collect();
+        let spec_args: Punctuated<_, Comma> = self.spec_args().into_iter().collect();
+        let mut group_index = 0usize;
+        let slices: TokenStream2 = self
+            .arg_metas
+            .iter()
+            .map(|arg| {
+                let ident = &arg.ident;
+                let ty = &arg.scalar_ty.ident;
+                if let Some(binding) = arg.binding {
+                    let index = binding as usize;
+                    let slice_fn = if arg.mutable {
+                        format_ident!("unsafe_slice")
+                    } else {
+                        format_ident!("slice")
+                    };
+                    quote! {
+                        let #ident = unsafe { __krnl_thread.#slice_fn::<#ty>(#index) };
+                    }
+                } else if let Some(len) = arg.len.as_ref() {
+                    let index = group_index;
+                    group_index += 1;
+                    quote! {
+                        let #ident = {
+                            const fn __krnl_array_len(#spec_def_args) -> usize {
+                                #len
+                            }
+                            unsafe {
+                                __krnl_thread.group_buffer::<#ty>(#index, __krnl_array_len(#spec_args))
+                            }
+                        };
+                    }
+                } else {
+                    TokenStream2::new()
+                }
+            })
+            .collect();
+        let mut fn_call = quote! {
+            #unsafe_token {
+                #ident (
+                    kernel,
+                    #host_fn_call_args
+                );
+            }
+        };
+        let kernel_type = if self.itemwise {
+            let items = self.device_items();
+            fn_call = quote! {
+                let __krnl_items = #items;
+                let mut __krnl_item_id = kernel.global_id();
+                while __krnl_item_id < __krnl_items {
+                    {
+                        let kernel = unsafe {
+                            krnl_core::kernel::__private::ItemKernelArgs {
+                                item_id: __krnl_item_id as u32,
+                                items: __krnl_items as u32,
+                            }.into_item_kernel()
+                        };
+                        #fn_call
+                    }
+                    __krnl_item_id += kernel.global_threads();
+                }
+            };
+            quote! { ItemKernel }
+        } else {
+            quote! { Kernel }
+        };
+        quote! {
+            #[doc(hidden)]
+            #[allow(unused, non_snake_case, non_camel_case_types, clippy::too_many_arguments)]
+            fn __krnl_host(__krnl_thread: &__krnl::kernel::__private::HostThread) {
+                use super::*;
+                use __krnl::krnl_core::{self, half::{bf16, f16}};
+
+                #(#item_attrs)*
+                #unsafe_token fn #ident(
+                    #[allow(unused)]
+                    kernel: krnl_core::kernel::#kernel_type,
+                    #host_fn_def_args
+                ) #block
+
+                struct __krnl_PushConsts {
+                    #(#push_fields),*
+                }
+
+                let kernel = __krnl_thread.kernel();
+                #specs
+                let __krnl_push_consts = __krnl_PushConsts {
+                    #(#push_values),*
+                };
+                #slices
+                #fn_call
+            }
+        }
+    }
     fn dispatch_args(&self) -> TokenStream2 {
         let mut tokens = TokenStream2::new();
         for arg in self.arg_metas.iter() {
@@ -981,6 +1387,94 @@ impl KernelMeta {
         }
         tokens
     }
+    fn prepare_args(&self) -> TokenStream2 {
+        let mut tokens = TokenStream2::new();
+        for arg in self.arg_metas.iter() {
+            let ident = &arg.ident;
+            let ty = &arg.scalar_ty.ident;
+            if arg.binding.is_some() {
+                let slice_ty = if arg.mutable {
+                    format_ident!("SliceMut")
+                } else {
+                    format_ident!("Slice")
+                };
+                tokens.extend(quote! {
+                    #ident: #slice_ty<'a, #ty>,
+                });
+            }
+        }
+        tokens
+    }
+    fn prepared_dispatch_args(&self) -> TokenStream2 {
+        let mut tokens = TokenStream2::new();
+        for arg in self.arg_metas.iter() {
+            let ident = &arg.ident;
+            let ty = &arg.scalar_ty.ident;
+            if arg.binding.is_none() && arg.kind.is_push() {
+                tokens.extend(quote! {
+                    #ident: #ty,
+                });
+            }
+        }
+        tokens
+    }
+    fn dispatch_owned_args(&self) -> TokenStream2 {
+        let mut tokens = TokenStream2::new();
+        for arg in self.arg_metas.iter() {
+            let ident = &arg.ident;
+            let ty = &arg.scalar_ty.ident;
+            if arg.binding.is_some() {
+                let mut_token = if arg.mutable {
+                    quote! { mut }
+                } else {
+                    TokenStream2::new()
+                };
+                tokens.extend(quote! {
+                    #mut_token #ident: Buffer<#ty>,
+                });
+            } else if arg.kind.is_push() {
+                tokens.extend(quote! {
+                    #ident: #ty,
+                });
+            }
+        }
+        tokens
+    }
+    fn dispatch_owned_slice_args(&self) -> TokenStream2 {
+        let mut tokens = TokenStream2::new();
+        for arg in self.arg_metas.iter() {
+            let ident = &arg.ident;
+            if arg.binding.is_some() {
+                if arg.mutable {
+                    tokens.extend(quote! {
+                        #ident.as_slice_mut().into(),
+                    });
+                } else {
+                    tokens.extend(quote! {
+                        #ident.as_slice().into(),
+                    });
+                }
+            }
+        }
+        tokens
+    }
+    fn dispatch_owned_output(&self) -> (TokenStream2, TokenStream2) {
+        let mut tys = TokenStream2::new();
+        let mut idents = TokenStream2::new();
+        for arg in self.arg_metas.iter() {
+            let ident = &arg.ident;
+            let ty = &arg.scalar_ty.ident;
+            if arg.binding.is_some() {
+                tys.extend(quote! {
+                    Buffer<#ty>,
+                });
+                idents.extend(quote! {
+                    #ident,
+                });
+            }
+        }
+        (tys, idents)
+    }
     fn dispatch_slice_args(&self) -> TokenStream2 {
         let mut tokens = TokenStream2::new();
         for arg in self.arg_metas.iter() {
@@ -1135,6 +1629,17 @@ impl KernelDesc {
         let bytes = bincode2::serialize(self).map_err(|e| Error::new(Span2::call_site(), e))?;
         Ok(format!("__krnl_kernel_data_{}", hex::encode(bytes)))
     }
+    // Each slice adds an offset and len.
+    fn push_consts_size(&self) -> usize {
+        let mut size = 0;
+        for push_desc in self.push_descs.iter() {
+            size += push_desc.scalar_type.size();
+        }
+        while size % 4 != 0 {
+            size += 1;
+        }
+        size + self.slice_descs.len() * 2 * 4
+    }
     fn push_const_fields(&self) -> Punctuated<TokenStream2, Comma> {
         let mut fields = Punctuated::new();
         let mut size = 0;
@@ -1326,10 +1831,7 @@ impl ToTokens for SpecDesc {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let Self { name, scalar_type } = self;
         tokens.extend(quote! {
-            SpecDesc {
-                name: #name,
-                scalar_type: #scalar_type,
-            }
+            SpecDesc::new(#name, #scalar_type)
         });
     }
 }
@@ -1351,12 +1853,7 @@ impl ToTokens for SliceDesc {
             item,
         } = self;
         tokens.extend(quote! {
-            SliceDesc {
-                name: #name,
-                scalar_type: #scalar_type,
-                mutable: #mutable,
-                item: #item,
-            }
+            SliceDesc::new(#name, #scalar_type, #mutable, #item)
         })
     }
 }
@@ -1371,15 +1868,16 @@ impl ToTokens for PushDesc {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let Self { name, scalar_type } = self;
         tokens.extend(quote! {
-            PushDesc {
-                name: #name,
-                scalar_type: #scalar_type,
-            }
+            PushDesc::new(#name, #scalar_type)
         })
     }
 }
 
-fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
+fn kernel_impl(
+    item_tokens: TokenStream2,
+    reduce: Option<KernelReduce>,
+    host: bool,
+) -> Result<TokenStream2> {
     let item: KernelItem = syn::parse2(item_tokens.clone())?;
     let kernel_meta = item.meta()?;
     let kernel_desc = kernel_meta.desc()?;
@@ -1396,8 +1894,9 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
         let items = kernel_meta.device_items();
         let device_arrays = kernel_meta.device_arrays();
         let device_slices = kernel_meta.device_slices();
-        let device_fn_def_args = kernel_meta.device_fn_def_args();
-        let device_fn_call_args = kernel_meta.device_fn_call_args();
+        let krnl_core = quote! { ::krnl_core };
+        let device_fn_def_args = kernel_meta.device_fn_def_args(&krnl_core);
+        let device_fn_call_args = kernel_meta.device_fn_call_args(&krnl_core);
         let push_consts_ident = format_ident!("__krnl_{ident}PushConsts");
         let (push_struct_tokens, push_consts_arg) =
             if !kernel_desc.push_descs.is_empty() || !kernel_desc.slice_descs.is_empty() {
@@ -1491,9 +1990,9 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                     #declare_specs
                     let mut kernel = unsafe {
                         ::krnl_core::kernel::__private::KernelArgs {
-                            global_id: __krnl_global_id.x,
-                            groups: __krnl_groups.x,
-                            group_id: __krnl_group_id.x,
+                            global_id: [__krnl_global_id.x, __krnl_global_id.y, __krnl_global_id.z],
+                            groups: [__krnl_groups.x, __krnl_groups.y, __krnl_groups.z],
+                            group_id: [__krnl_group_id.x, __krnl_group_id.y, __krnl_group_id.z],
                             subgroups: __krnl_subgroups,
                             subgroup_id: __krnl_subgroup_id,
                             subgroup_thread_id: __krnl_subgroup_thread_id,
@@ -1515,6 +2014,12 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
         let dispatch_args = kernel_meta.dispatch_args();
         let dispatch_slice_args = kernel_meta.dispatch_slice_args();
         let dispatch_push_args = kernel_desc.dispatch_push_args();
+        let prepare_args = kernel_meta.prepare_args();
+        let prepared_dispatch_args = kernel_meta.prepared_dispatch_args();
+        let dispatch_owned_args = kernel_meta.dispatch_owned_args();
+        let dispatch_owned_slice_args = kernel_meta.dispatch_owned_slice_args();
+        let (dispatch_owned_output_tys, dispatch_owned_output) =
+            kernel_meta.dispatch_owned_output();
         let safe = unsafe_token.is_none();
         let safety = if safe {
             quote! {
@@ -1526,6 +2031,16 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
             }
         };
         let host_array_length_checks = kernel_meta.host_array_length_checks();
+        let (host_fn, with_host_fn) = if host {
+            (
+                kernel_meta.host_fn(&kernel_desc, item_attrs),
+                quote! {
+                    .map(|builder| builder.with_host_fn(__krnl_host))
+                },
+            )
+        } else {
+            (TokenStream2::new(), TokenStream2::new())
+        };
         let specialize = !kernel_desc.spec_descs.is_empty();
         let specialized = [format_ident!("S")];
         let specialized = if specialize {
@@ -1545,6 +2060,23 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
         } else {
             TokenStream2::new()
         };
+        let build_default_fn = if specialize {
+            quote! {
+                #[doc(hidden)]
+                pub fn __build_default(device: Device) -> Result<()> {
+                    let _ = device;
+                    Ok(())
+                }
+            }
+        } else {
+            quote! {
+                #[doc(hidden)]
+                pub fn __build_default(device: Device) -> Result<()> {
+                    builder()?.build(device)?;
+                    Ok(())
+                }
+            }
+        };
         let kernel_builder_specialize_fn = if specialize {
             let spec_def_args = kernel_meta.spec_def_args();
             let spec_args = kernel_meta.spec_args();
@@ -1561,6 +2093,27 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
         } else {
             TokenStream2::new()
         };
+        let kernel_builder_group_buffer_len_fn = if specialize {
+            quote! {
+                /// Overrides the spec constant `name` with `len`.
+                ///
+                /// Intended for group buffers sized by a spec constant, ie
+                /// `#[group] x: UnsafeSlice<f32, N>`, so that the length can be tuned without
+                /// depending on the order of the other spec constants.
+                ///
+                /// # Errors
+                /// - The kernel has no spec constant `name`.
+                /// - `len` does not fit in the type of the spec constant.
+                pub fn with_group_buffer_len(self, name: &str, len: usize) -> Result<Self> {
+                    Ok(Self {
+                        inner: self.inner.with_group_buffer_len(name, len)?,
+                        _m: PhantomData,
+                    })
+                }
+            }
+        } else {
+            TokenStream2::new()
+        };
         let needs_groups = !kernel_meta.itemwise;
         let with_groups = [format_ident!("G")];
         let with_groups = if needs_groups {
@@ -1579,15 +2132,125 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
             TokenStream2::new()
         };
         let input_docs = {
-            let input_tokens_string = prettyplease::unparse(&syn::parse2(quote! {
-                #[kernel]
-                #item_tokens
-            })?);
+            let input_tokens = if let Some(reduce) = reduce.as_ref() {
+                let item_tokens = &reduce.item_tokens;
+                quote! {
+                    #[kernel(reduce)]
+                    #item_tokens
+                }
+            } else {
+                quote! {
+                    #[kernel]
+                    #item_tokens
+                }
+            };
+            let input_tokens_string = prettyplease::unparse(&syn::parse2(input_tokens)?);
             let input_doc_string = format!("```\n{input_tokens_string}\n```");
             quote! {
                 #![cfg_attr(not(doctest), doc = #input_doc_string)]
             }
         };
+        let run_fn = match kernel_meta.arg_metas.as_slice() {
+            [x, y]
+                if kernel_meta.itemwise
+                    && !specialize
+                    && x.kind.is_item()
+                    && !x.mutable
+                    && y.kind.is_item()
+                    && y.mutable =>
+            {
+                let x_ident = &x.ident;
+                let x_ty = &x.scalar_ty.ident;
+                let y_ty = &y.scalar_ty.ident;
+                let summary_doc = format!(" Maps `{x_ident}` to a new buffer.");
+                let description_doc = if host {
+                    format!(" Builds the kernel for the device of `{x_ident}` with default threads, allocates the output with zeros and dispatches the kernel. On the host, the kernel is executed by its host implementation.")
+                } else {
+                    format!(" Builds the kernel for the device of `{x_ident}` with default threads, allocates the output with zeros and dispatches the kernel.")
+                };
+                let host_error_doc = if host {
+                    TokenStream2::new()
+                } else {
+                    let doc = format!(
+                        " - `{x_ident}` is on the host, the kernel is not `#[kernel(host)]`."
+                    );
+                    quote! {
+                        #[doc = #doc]
+                    }
+                };
+                quote! {
+                    #[doc = #summary_doc]
+                    ///
+                    #[doc = #description_doc]
+                    ///
+                    /// # Errors
+                    #host_error_doc
+                    /// - See [`builder()`](builder), [`KernelBuilder::build()`](KernelBuilder::build) and [`Kernel::dispatch()`](Kernel::dispatch).
+                    pub #unsafe_token fn run(#x_ident: Slice<#x_ty>) -> Result<Buffer<#y_ty>> {
+                        let device = #x_ident.device();
+                        let kernel = builder()?.build(device.clone())?;
+                        let mut output = Buffer::zeros(device, #x_ident.len())?;
+                        kernel.dispatch(#x_ident, output.as_slice_mut())?;
+                        Ok(output)
+                    }
+                }
+            }
+            _ => TokenStream2::new(),
+        };
+        let reduce_fns = if let Some(reduce) = reduce.as_ref() {
+            let ty = &reduce.ty;
+            quote! {
+                /// Reduces `x` to a single element.
+                ///
+                /// Dispatches the kernel with [`.reduce_partial()`](Self::reduce_partial) until
+                /// one element remains.
+                ///
+                /// # Errors
+                /// - `x` is empty.
+                /// - `x` is on the host.
+                /// - [`DeviceLost`].
+                /// - The kernel could not be queued.
+                pub fn reduce(&self, x: Slice<#ty>) -> Result<Buffer<#ty>> {
+                    let mut y = self.reduce_partial(x)?;
+                    while y.len() > 1 {
+                        y = self.reduce_partial(y.as_slice())?;
+                    }
+                    Ok(y)
+                }
+                /// Reduces `x` per group.
+                ///
+                /// Groups are inferred from the length of `x`, returning one element per group.
+                ///
+                /// # Errors
+                /// - `x` is empty.
+                /// - `x` is on the host.
+                /// - [`DeviceLost`].
+                /// - The kernel could not be queued.
+                pub fn reduce_partial(&self, x: Slice<#ty>) -> Result<Buffer<#ty>> {
+                    let name = self.inner.name();
+                    if x.is_empty() {
+                        anyhow::bail!("Kernel `{name}`.`x` is empty!");
+                    }
+                    let device = x.device();
+                    if device.is_host() {
+                        anyhow::bail!("Kernel `{name}`.`x` expected device, found host!");
+                    }
+                    let threads = self.inner.threads() as usize;
+                    let max_groups = device.info().map_or(1, |info| info.max_groups()) as usize;
+                    let groups = (x.len() / threads + usize::from(x.len() % threads != 0)).min(max_groups);
+                    let mut y = unsafe { Buffer::uninit(device, groups)? };
+                    unsafe {
+                        self.inner
+                            .clone()
+                            .with_groups(groups as u32)
+                            .dispatch(&[x.into(), y.as_slice_mut().into()], &[])?;
+                    }
+                    Ok(y)
+                }
+            }
+        } else {
+            TokenStream2::new()
+        };
         let expansion = if rustversion::cfg!(nightly) {
             let expansion_tokens_string =
                 prettyplease::unparse(&syn::parse2(device_tokens.clone())?);
@@ -1612,12 +2275,14 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                 use __krnl::{
                     anyhow::{self, Result},
                     krnl_core::half::{f16, bf16},
-                    buffer::{Slice, SliceMut},
+                    buffer::{Buffer, Slice, SliceMut},
                     device::{Device, Features},
                     scalar::ScalarType,
+                    kernel::{CancelToken, DispatchHandle},
                     kernel::__private::{
                         Kernel as KernelBase,
                         KernelBuilder as KernelBuilderBase,
+                        PreparedDispatch as PreparedDispatchBase,
                         Specialized,
                         WithGroups,
                         KernelDesc,
@@ -1638,6 +2303,8 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
 
                 #host_array_length_checks
 
+                #host_fn
+
                 /// Builder for creating a [`Kernel`].
                 ///
                 /// See [`builder()`](builder).
@@ -1659,7 +2326,7 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                     let builder = BUILDER.get_or_init(|| {
                         const DESC: Option<KernelDesc> = validate_kernel(__krnl_kernel!(#ident), #safety, &[#(#spec_descs),*], &[#(#slice_descs),*], &[#(#push_descs),*]);
                         if let Some(desc) = DESC.as_ref() {
-                            KernelBuilderBase::from_desc(desc.clone())
+                            KernelBuilderBase::from_desc(desc.clone())#with_host_fn
                         } else {
                             Err(format!("Kernel `{}` not compiled!", ::std::module_path!()))
                         }
@@ -1673,16 +2340,75 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                     }
                 }
 
+                /// Sets the default threads for the kernel.
+                ///
+                /// See [`kernel::set_default_threads()`](kernel::set_default_threads).
+                pub fn set_default_threads(threads: Option<u32>) {
+                    __krnl::kernel::set_default_threads(::std::module_path!(), threads);
+                }
+
+                #build_default_fn
+
+                #run_fn
+
                 impl #(<#specialized>)* KernelBuilder #(<#specialized>)* {
                     /// Threads per group.
                     ///
-                    /// Defaults to [`DeviceInfo::default_threads()`](DeviceInfo::default_threads).
+                    /// Defaults to [`kernel::default_threads()`](kernel::default_threads) if set,
+                    /// otherwise [`DeviceInfo::default_threads()`](DeviceInfo::default_threads).
                     pub fn with_threads(self, threads: u32) -> Self {
                         Self {
                             inner: self.inner.with_threads(threads),
                             _m: PhantomData,
                         }
                     }
+                    /// Clamp threads to [`DeviceInfo::max_threads()`](DeviceInfo::max_threads).
+                    ///
+                    /// By default, building a kernel with threads greater than max_threads is an
+                    /// error. If `clamp_threads` is true, threads are clamped silently instead.
+                    /// Use [`Kernel::threads()`](Kernel::threads) to get the effective threads.
+                    pub fn clamp_threads(self, clamp_threads: bool) -> Self {
+                        Self {
+                            inner: self.inner.clamp_threads(clamp_threads),
+                            _m: PhantomData,
+                        }
+                    }
+                    /// Checks that the kernel is supported on `device`, without building it.
+                    ///
+                    /// # Errors
+                    /// - `device` is the host, and the kernel is not `#[kernel(host)]`.
+                    /// - `device` doesn't have required features.
+                    /// - Threads, push constants, or group memory exceed the limits of `device`.
+                    pub fn supported_on(&self, device: &Device) -> Result<()> {
+                        self.inner.supported_on(device)
+                    }
+                    /// The SPIR-V capabilities declared by the kernel, ie "GroupNonUniformArithmetic".
+                    ///
+                    /// Capabilities are checked when the kernel is built, most require one of the
+                    /// [`features`](Features). Kernels declaring a capability that krnl does not support
+                    /// fail to build.
+                    ///
+                    /// # Errors
+                    /// - The SPIR-V could not be loaded.
+                    /// - Without the device feature.
+                    pub fn required_capabilities(&self) -> Result<Vec<String>> {
+                        self.inner.required_capabilities()
+                    }
+                    /// The SPIR-V extensions declared by the kernel, ie "SPV_KHR_vulkan_memory_model".
+                    ///
+                    /// # Errors
+                    /// - The SPIR-V could not be loaded.
+                    /// - Without the device feature.
+                    pub fn required_extensions(&self) -> Result<Vec<String>> {
+                        self.inner.required_extensions()
+                    }
+                    /// Encodes the kernel, including its SPIR-V and spec constants, independent of any device.
+                    ///
+                    /// The kernel can be loaded in another process with
+                    /// [`DynKernelBuilder::from_bytes()`](__krnl::kernel::DynKernelBuilder::from_bytes).
+                    pub fn to_bytes(&self) -> Vec<u8> {
+                        self.inner.to_bytes()
+                    }
                     #kernel_builder_specialize_fn
                     #[doc(hidden)]
                     #[inline]
@@ -1707,6 +2433,7 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                             _m: PhantomData,
                         })
                     }
+                    #kernel_builder_group_buffer_len_fn
                 }
 
                 /// Kernel.
@@ -1740,6 +2467,80 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                             _m: PhantomData,
                         }
                     }
+                    /// Global threads to dispatch in 3 dimensions.
+                    ///
+                    /// Threads per group are 1d, so only the x dimension is rounded up to the
+                    /// next multiple of threads.
+                    pub fn with_global_threads_3d(self, global_threads: [u32; 3]) -> Kernel #kernel_dispatch_generics {
+                        Kernel {
+                            inner: self.inner.with_global_threads_3d(global_threads),
+                            _m: PhantomData,
+                        }
+                    }
+                    /// Groups to dispatch in 3 dimensions.
+                    ///
+                    /// Item kernels only support 1d groups.
+                    pub fn with_groups_3d(self, groups: [u32; 3]) -> Kernel #kernel_dispatch_generics {
+                        Kernel {
+                            inner: self.inner.with_groups_3d(groups),
+                            _m: PhantomData,
+                        }
+                    }
+                    /// Cancels dispatches when `cancel_token` is cancelled.
+                    ///
+                    /// See [`CancelToken`].
+                    pub fn with_cancel_token(self, cancel_token: CancelToken) -> Self {
+                        Self {
+                            inner: self.inner.with_cancel_token(cancel_token),
+                            _m: PhantomData,
+                        }
+                    }
+                    /// Whether groups are inferred from item arguments.
+                    ///
+                    /// Otherwise groups must be provided with [`.with_groups()`](Self::with_groups) or
+                    /// [`.with_global_threads()`](Self::with_global_threads).
+                    pub fn is_item_kernel(&self) -> bool {
+                        self.inner.is_item_kernel()
+                    }
+                    /// The name of the kernel, including threads and spec constants.
+                    pub fn name(&self) -> &str {
+                        self.inner.name()
+                    }
+                    /// The features required by the kernel.
+                    pub fn features(&self) -> Features {
+                        self.inner.features()
+                    }
+                    /// The spec constants, in declaration order.
+                    pub fn spec_descs(&self) -> &[SpecDesc] {
+                        self.inner.spec_descs()
+                    }
+                    /// The slice arguments, both items and globals, in declaration order.
+                    pub fn slice_descs(&self) -> &[SliceDesc] {
+                        self.inner.slice_descs()
+                    }
+                    /// The push constant arguments, sorted by descending size, the order they are packed in.
+                    pub fn push_descs(&self) -> &[PushDesc] {
+                        self.inner.push_descs()
+                    }
+                    /// The number of slice arguments, both items and globals.
+                    pub fn slice_count(&self) -> usize {
+                        self.inner.slice_count()
+                    }
+                    /// The number of push constants.
+                    pub fn push_count(&self) -> usize {
+                        self.inner.push_count()
+                    }
+                    /// Disassembles the specialized SPIR-V, for debugging.
+                    ///
+                    /// Variable names are preserved by krnlc.
+                    ///
+                    /// # Errors
+                    /// - krnl was built without the device feature.
+                    /// - The SPIR-V could not be loaded.
+                    pub fn disassemble(&self) -> Result<String> {
+                        self.inner.disassemble()
+                    }
+                    #reduce_fns
                 }
 
                 impl Kernel #kernel_dispatch_generics {
@@ -1755,6 +2556,70 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                     pub #unsafe_token fn dispatch(&self, #dispatch_args) -> Result<()> {
                         unsafe { self.inner.dispatch(&[#dispatch_slice_args], &[#(#dispatch_push_args.into()),*]) }
                     }
+                    /// Dispatches the kernel with owned buffers.
+                    ///
+                    /// Like [`.dispatch()`](Self::dispatch), but takes ownership of the buffers,
+                    /// so that a dispatch can be built in one scope and executed in another, ie
+                    /// on a worker thread. The buffers are returned once the kernel is queued.
+                    ///
+                    /// # Errors
+                    /// - [`DeviceLost`].
+                    /// - The kernel could not be queued.
+                    pub #unsafe_token fn dispatch_owned(&self, #dispatch_owned_args) -> Result<(#dispatch_owned_output_tys)> {
+                        unsafe { self.inner.dispatch(&[#dispatch_owned_slice_args], &[#(#dispatch_push_args.into()),*])? };
+                        Ok((#dispatch_owned_output))
+                    }
+                    /// Dispatches the kernel without waiting for it to finish.
+                    ///
+                    /// Like [`.dispatch()`](Self::dispatch), but returns a [`DispatchHandle`]
+                    /// that can be waited on. With debug printf, panics are reported by
+                    /// [`DispatchHandle::wait()`] instead.
+                    ///
+                    /// # Errors
+                    /// - [`DeviceLost`].
+                    /// - The kernel could not be queued.
+                    pub #unsafe_token fn dispatch_async(&self, #dispatch_args) -> Result<DispatchHandle> {
+                        unsafe { self.inner.dispatch_async(&[#dispatch_slice_args], &[#(#dispatch_push_args.into()),*]) }
+                    }
+                    /// Prepares a dispatch with slice arguments.
+                    ///
+                    /// The slices are validated and bound once, and the returned
+                    /// [`PreparedDispatch`] can be dispatched repeatedly with different
+                    /// push constants.
+                    ///
+                    /// # Errors
+                    /// - [`DeviceLost`].
+                    /// - The slices are invalid, see [`.dispatch()`](Self::dispatch).
+                    pub #unsafe_token fn prepare<'a>(&self, #prepare_args) -> Result<PreparedDispatch<'a>> {
+                        Ok(PreparedDispatch {
+                            inner: unsafe { self.inner.prepare(&[#dispatch_slice_args])? },
+                            _m: PhantomData,
+                        })
+                    }
+                }
+
+                /// Prepared dispatch.
+                ///
+                /// See [`Kernel::prepare()`](Kernel::prepare).
+                pub struct PreparedDispatch<'a> {
+                    #[doc(hidden)]
+                    inner: PreparedDispatchBase,
+                    #[doc(hidden)]
+                    _m: PhantomData<&'a ()>,
+                }
+
+                impl PreparedDispatch<'_> {
+                    /// Dispatches the kernel.
+                    ///
+                    /// Like [`Kernel::dispatch()`](Kernel::dispatch), but reuses the slices
+                    /// bound by [`Kernel::prepare()`](Kernel::prepare).
+                    ///
+                    /// # Errors
+                    /// - [`DeviceLost`].
+                    /// - The kernel could not be queued.
+                    pub #unsafe_token fn dispatch(&self, #prepared_dispatch_args) -> Result<()> {
+                        unsafe { self.inner.dispatch(&[#(#dispatch_push_args.into()),*]) }
+                    }
                 }
             }
         }
@@ -1778,17 +2643,40 @@ pub fn __krnl_cache(input: TokenStream) -> TokenStream {
     .into()
 }
 
-#[derive(Parse)]
 struct KrnlCacheInput {
     version: LitStr,
-    __comma1: Comma,
+    compression: Option<Ident>,
     module: Ident,
-    _comma2: Comma,
     kernel: Ident,
-    _comma3: Comma,
     data: LitStr,
 }
 
+impl Parse for KrnlCacheInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let version = input.parse()?;
+        input.parse::<Comma>()?;
+        let mut idents = Vec::with_capacity(3);
+        while input.peek(Ident) {
+            idents.push(input.parse::<Ident>()?);
+            input.parse::<Comma>()?;
+        }
+        let data = input.parse()?;
+        let mut idents = idents.into_iter();
+        let (compression, module, kernel) = match (idents.next(), idents.next(), idents.next()) {
+            (Some(module), Some(kernel), None) => (None, module, kernel),
+            (Some(compression), Some(module), Some(kernel)) => (Some(compression), module, kernel),
+            _ => return Err(input.error("expected module and kernel")),
+        };
+        Ok(Self {
+            version,
+            compression,
+            module,
+            kernel,
+            data,
+        })
+    }
+}
+
 fn __krnl_cache_impl(input: TokenStream2) -> Result<TokenStream2> {
     use flate2::{
         read::{GzDecoder, GzEncoder},
@@ -1802,6 +2690,25 @@ fn __krnl_cache_impl(input: TokenStream2) -> Result<TokenStream2> {
 
     let input = syn::parse2::<KrnlCacheInput>(input)?;
     let span = input.module.span();
+    let compression = input.compression.as_ref().map(Ident::to_string);
+    match compression.as_deref() {
+        None | Some("gzip" | "none") => (),
+        #[cfg(feature = "zstd")]
+        Some("zstd") => (),
+        #[cfg(not(feature = "zstd"))]
+        Some("zstd") => {
+            return Err(Error::new(
+                span,
+                "Kernels compressed with zstd require the `zstd` feature of krnl!",
+            ));
+        }
+        Some(compression) => {
+            return Err(Error::new(
+                span,
+                format!("Unknown compression `{compression}`, expected gzip, zstd, or none!"),
+            ));
+        }
+    }
     let cache = CACHE
         .get_or_init(|| {
             let version = env!("CARGO_PKG_VERSION");
@@ -1847,10 +2754,22 @@ fn __krnl_cache_impl(input: TokenStream2) -> Result<TokenStream2> {
                 slice_descs,
                 push_descs,
             } = kernel;
-            let mut bytes = Vec::new();
-            GzEncoder::new(bytemuck::cast_slice(spirv), Compression::best())
-                .read_to_end(&mut bytes)
-                .unwrap();
+            let spirv_bytes: &[u8] = bytemuck::cast_slice(spirv);
+            let bytes = match compression.as_deref() {
+                #[cfg(feature = "zstd")]
+                Some("zstd") => ruzstd::encoding::compress_to_vec(
+                    spirv_bytes,
+                    ruzstd::encoding::CompressionLevel::Fastest,
+                ),
+                Some("none") => spirv_bytes.to_vec(),
+                _ => {
+                    let mut bytes = Vec::new();
+                    GzEncoder::new(spirv_bytes, Compression::best())
+                        .read_to_end(&mut bytes)
+                        .unwrap();
+                    bytes
+                }
+            };
             let spirv = LitByteStr::new(&bytes, span);
             quote! {
                 KernelDesc::from_args(KernelDescArgs {
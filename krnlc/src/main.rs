@@ -14,6 +14,7 @@ use std::{
     path::{Path, PathBuf},
     process::{Command, Stdio},
     str::FromStr,
+    time::{Duration, Instant},
 };
 use syn::{visit::Visit, Expr, Item, ItemMod, Lit, Visibility};
 
@@ -43,19 +44,123 @@ struct Cli {
     /// Check mode
     #[arg(long = "check")]
     check: bool,
-    /// Enable DebugPrintf
+    /// Enable DebugPrintf, kernels are compiled with debug info and without optimizations.
+    /// DebugPrintf is stripped at runtime unless enabled on the device
     #[arg(long = "debug-printf")]
     debug_printf: bool,
+    /// Fail if a kernel requires features other than these, ie "INT8,BUFFER8"
+    #[arg(long = "require-features", value_delimiter = ',')]
+    require_features: Option<Vec<String>>,
+    /// Fail if a kernel requires any of these features, ie "FLOAT64,INT64"
+    #[arg(long = "forbid-features", value_delimiter = ',')]
+    forbid_features: Vec<String>,
+    /// Compression of kernels embedded in the binary
+    #[arg(long = "compression", value_enum, default_value_t)]
+    compression: SpirvCompression,
+    /// Target environment, kernels can only run on devices supporting this Vulkan version
+    #[arg(long = "spirv-target", value_enum, default_value_t)]
+    spirv_target: SpirvTarget,
+    /// Optimization level of the spirv-opt pass after compiling, "none" to skip it. Ignored with --debug-printf
+    #[arg(long = "opt-level", value_enum, default_value_t)]
+    opt_level: OptLevel,
+    /// Number of packages to compile in parallel, defaults to the number of logical CPUs
+    #[arg(short = 'j', long = "jobs", value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+    /// Print the size of each kernel and the time to compile
+    #[arg(long = "stats")]
+    stats: bool,
     /// Use verbose output
     #[arg(short = 'v', long = "verbose")]
     verbose: bool,
+    /// Treat warnings as errors
+    #[arg(long = "strict")]
+    strict: bool,
     // Dumps kernels to <target>/krnlc/crates/<crate>/kernels/path/to/kernel.[spv, json]
     #[arg(long = "dump-kernels", hide = true)]
     dump_kernels: bool,
+    #[command(subcommand)]
+    command: Option<KrnlcCommand>,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Default, Debug)]
+enum SpirvCompression {
+    #[default]
+    Gzip,
+    Zstd,
+    None,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Default, Debug)]
+enum SpirvTarget {
+    #[value(name = "vulkan1.1")]
+    Vulkan1_1,
+    #[value(name = "vulkan1.1spv1.4")]
+    Vulkan1_1Spv1_4,
+    #[default]
+    #[value(name = "vulkan1.2")]
+    Vulkan1_2,
+}
+
+impl SpirvTarget {
+    fn target(&self) -> &'static str {
+        match self {
+            Self::Vulkan1_1 => "spirv-unknown-vulkan1.1",
+            Self::Vulkan1_1Spv1_4 => "spirv-unknown-vulkan1.1spv1.4",
+            Self::Vulkan1_2 => "spirv-unknown-vulkan1.2",
+        }
+    }
+    fn target_env(&self) -> spirv_tools::TargetEnv {
+        use spirv_tools::TargetEnv;
+        match self {
+            Self::Vulkan1_1 => TargetEnv::Vulkan_1_1,
+            Self::Vulkan1_1Spv1_4 => TargetEnv::Vulkan_1_1_Spirv_1_4,
+            Self::Vulkan1_2 => TargetEnv::Vulkan_1_2,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Default, PartialEq, Eq, Debug)]
+enum OptLevel {
+    None,
+    Size,
+    #[default]
+    #[value(name = "perf")]
+    Performance,
+}
+
+impl OptLevel {
+    fn opt_kind(&self) -> Option<SpirvOptKind> {
+        match self {
+            Self::None => None,
+            Self::Size => Some(SpirvOptKind::Size),
+            Self::Performance => Some(SpirvOptKind::Performance),
+        }
+    }
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum KrnlcCommand {
+    /// Lists the kernels in a cache
+    Inspect {
+        /// Path to "krnl-cache.rs"
+        cache: PathBuf,
+    },
 }
 
 fn main() -> Result<()> {
-    let cli = Cli::parse();
+    run(Cli::parse())
+}
+
+fn run(cli: Cli) -> Result<()> {
+    if let Some(KrnlcCommand::Inspect { cache }) = cli.command.as_ref() {
+        return inspect(cache);
+    }
+    let allowed_features = if let Some(require_features) = cli.require_features.as_ref() {
+        Features::parse_names(require_features)?
+    } else {
+        Features::all()
+    };
+    let forbidden_features = Features::parse_names(&cli.forbid_features)?;
     let metadata = cli.manifest.metadata().exec()?;
     let (selected, _) = cli.workspace.partition_packages(&metadata);
     let target_dir = cli
@@ -63,23 +168,86 @@ fn main() -> Result<()> {
         .as_ref()
         .map(|x| x.to_string_lossy())
         .unwrap_or(metadata.target_directory.as_str().into());
-    for package in selected.iter().copied() {
-        let krnlc_metadata = KrnlcMetadata::new(&metadata, package)?;
-        let module_sources = cargo_expand(package, &target_dir, &krnlc_metadata, cli.verbose)?;
-        if module_sources.is_empty() {
-            continue;
+    init_lib_dir(&target_dir)?;
+    // each package has its own device crate and target dir, so packages are compiled concurrently
+    let jobs = if let Some(jobs) = cli.jobs {
+        jobs as usize
+    } else {
+        std::thread::available_parallelism().map_or(1, |x| x.get())
+    };
+    if jobs == 1 {
+        for package in selected.iter().copied() {
+            build_package(
+                &cli,
+                &metadata,
+                package,
+                &target_dir,
+                allowed_features,
+                forbidden_features,
+            )?;
         }
-        let modules = compile(
-            package,
-            &target_dir,
-            &krnlc_metadata.dependencies,
-            module_sources,
-            cli.debug_printf,
-            cli.verbose,
-            cli.dump_kernels,
-        )?;
-        cache(package, modules, cli.check, cli.debug_printf)?;
+        return Ok(());
+    }
+    let thread_pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build()?;
+    thread_pool.install(|| {
+        selected.par_iter().try_for_each(|package| {
+            build_package(
+                &cli,
+                &metadata,
+                package,
+                &target_dir,
+                allowed_features,
+                forbidden_features,
+            )
+        })
+    })
+}
+
+fn build_package(
+    cli: &Cli,
+    metadata: &Metadata,
+    package: &Package,
+    target_dir: &str,
+    allowed_features: Features,
+    forbidden_features: Features,
+) -> Result<()> {
+    let krnlc_metadata = KrnlcMetadata::new(metadata, package)?;
+    let module_sources = cargo_expand(package, target_dir, &krnlc_metadata, cli.verbose)?;
+    if module_sources.is_empty() {
+        let package_name = &package.name;
+        let msg = format!(
+            "no modules found in package `{package_name}`!
+  `#[module]`s are collected with `cargo +nightly rustc -- -Zunpretty=expanded`, check that:
+  - a nightly toolchain is installed
+  - `#[module]` is applied to a `mod`, and the module is reachable from the crate root"
+        );
+        if cli.strict {
+            bail!("{msg}");
+        }
+        eprintln!("warning: {msg}");
+        return Ok(());
     }
+    let modules = compile(
+        package,
+        target_dir,
+        &krnlc_metadata,
+        module_sources,
+        cli.debug_printf,
+        cli.verbose,
+        cli.dump_kernels,
+        cli.stats,
+        cli.spirv_target,
+        cli.opt_level,
+    )?;
+    check_features(&modules, allowed_features, forbidden_features)?;
+    cache(
+        package,
+        modules,
+        cli.check,
+        cli.debug_printf,
+        cli.opt_level,
+        cli.compression,
+    )?;
     Ok(())
 }
 
@@ -160,6 +328,10 @@ struct KrnlcMetadata {
     default_features: bool,
     features: String,
     dependencies: String,
+    device_manifest: String,
+    device_config: String,
+    // sources of path dependencies, which are hashed to detect changes
+    dependency_sources: Vec<PathBuf>,
 }
 
 impl KrnlcMetadata {
@@ -193,6 +365,38 @@ impl KrnlcMetadata {
             }
             None
         }
+        // sources of root and the path packages it depends on, which cargo_metadata reports with
+        // no source
+        fn find_path_sources<'a>(
+            metadata: &'a Metadata,
+            root: &'a PackageId,
+            searched: &mut FxHashSet<&'a PackageId>,
+            sources: &mut Vec<PathBuf>,
+        ) {
+            if !searched.insert(root) {
+                return;
+            }
+            if let Some(package) = metadata.packages.iter().find(|x| &x.id == root) {
+                if package.source.is_some() {
+                    return;
+                }
+                sources.push(package.manifest_path.clone().into());
+                for target in package.targets.iter() {
+                    if target.is_lib() || target.is_custom_build() {
+                        sources.push(target.src_path.parent().unwrap().into());
+                    }
+                }
+            }
+            if let Some(node) = metadata
+                .resolve
+                .as_ref()
+                .and_then(|resolve| resolve.nodes.iter().find(|x| &x.id == root))
+            {
+                for id in node.dependencies.iter() {
+                    find_path_sources(metadata, id, searched, sources);
+                }
+            }
+        }
         let mut searched = FxHashSet::default();
         let krnl_core_package =
             if let Some(package) = find_krnl_core(metadata, &package.id, &mut searched) {
@@ -219,6 +423,16 @@ impl KrnlcMetadata {
         let mut features = String::new();
         let mut dependencies = String::new();
         let mut has_krnl_core = false;
+        let mut device_manifest = String::new();
+        let mut device_config = String::new();
+        let mut dependency_sources = Vec::new();
+        let mut searched_sources = FxHashSet::default();
+        find_path_sources(
+            metadata,
+            &krnl_core_package.id,
+            &mut searched_sources,
+            &mut dependency_sources,
+        );
         if let Some(krnlc_metadata) = package.metadata.get("krnlc") {
             if let Some(metadata_default_features) = krnlc_metadata.get("default-features") {
                 if let Some(metadata_default_features) = metadata_default_features.as_bool() {
@@ -256,6 +470,19 @@ impl KrnlcMetadata {
                             .find(|x| x.rename.as_deref().unwrap_or(x.name.as_str()) == dep)
                         {
                             let source = if let Some(path) = dependency.path.as_ref() {
+                                if let Some(dependency_package) =
+                                    metadata.packages.iter().find(|x| {
+                                        x.source.is_none()
+                                            && x.manifest_path.parent() == Some(path.as_path())
+                                    })
+                                {
+                                    find_path_sources(
+                                        metadata,
+                                        &dependency_package.id,
+                                        &mut searched_sources,
+                                        &mut dependency_sources,
+                                    );
+                                }
                                 let path = path.canonicalize()?;
                                 format!("path = {path:?}")
                             } else if let Some(source) = dependency.source.as_ref() {
@@ -275,6 +502,20 @@ impl KrnlcMetadata {
                                 dependency.uses_default_features,
                                 dependency.features.clone(),
                             )
+                        } else if let Some(member) = metadata
+                            .workspace_packages()
+                            .into_iter()
+                            .find(|x| x.name == *dep && x.id != package.id)
+                        {
+                            find_path_sources(
+                                metadata,
+                                &member.id,
+                                &mut searched_sources,
+                                &mut dependency_sources,
+                            );
+                            let path = member.manifest_path.parent().unwrap().canonicalize()?;
+                            let source = format!("path = {path:?}");
+                            (source, true, Vec::new())
                         } else {
                             let source = String::new();
                             let dep_default_features = false;
@@ -302,6 +543,8 @@ impl KrnlcMetadata {
                                                 );
                                             }
                                             dep_source = format!("path = {path:?}");
+                                            dependency_sources.push(path.join("Cargo.toml"));
+                                            dependency_sources.push(path.join("src"));
                                             inherit_from_host_dep = false;
                                         } else {
                                             bail!(
@@ -356,7 +599,7 @@ impl KrnlcMetadata {
                             features = format!("{features:?}");
                         }
                         if dep_source.is_empty() {
-                            bail!("{manifest_path_str:?} [package.metadata.krnlc.dependencies] {dep:?} is not a dependency of {:?}!", package.name);
+                            bail!("{manifest_path_str:?} [package.metadata.krnlc.dependencies] {dep:?} is not a dependency of {:?} or a workspace member!", package.name);
                         }
                         writeln!(&mut dependencies, "{dep:?} = {{ {dep_source}, features = [{features}], default-features = {default_features} }}").unwrap();
                     }
@@ -366,6 +609,28 @@ impl KrnlcMetadata {
                 );
                 }
             }
+            if let Some(metadata_device_crate) = krnlc_metadata.get("device-crate") {
+                if let Some(metadata_device_crate) = metadata_device_crate.as_object() {
+                    for (key, value) in metadata_device_crate.iter() {
+                        let value = if let Some(value) = value.as_str() {
+                            value.to_string()
+                        } else {
+                            bail!("{manifest_path_str:?} [package.metadata.krnlc.device-crate] {key}, expected string!");
+                        };
+                        match key.as_str() {
+                            "manifest" => device_manifest = value,
+                            "config" => device_config = value,
+                            _ => {
+                                bail!("{manifest_path_str:?} [package.metadata.krnlc.device-crate], unexpected key {key:?}!");
+                            }
+                        }
+                    }
+                } else {
+                    bail!(
+                        "{manifest_path_str:?} [package.metadata.krnlc.device-crate], expected table!"
+                    );
+                }
+            }
         }
         if !has_krnl_core {
             writeln!(
@@ -378,6 +643,9 @@ impl KrnlcMetadata {
             default_features,
             features,
             dependencies,
+            device_manifest,
+            device_config,
+            dependency_sources,
         })
     }
 }
@@ -402,7 +670,16 @@ impl<'a, 'ast> Visit<'ast> for ModuleVisitor<'a> {
                     if item_const.ident == "__krnl_module_source" {
                         if let Expr::Lit(expr_lit) = item_const.expr.as_ref() {
                             if let Lit::Str(lit_str) = &expr_lit.lit {
-                                self.modules.insert(self.path.clone(), lit_str.value());
+                                if self
+                                    .modules
+                                    .insert(self.path.clone(), lit_str.value())
+                                    .is_some()
+                                {
+                                    *self.result = Err(Error::msg(format!(
+                                        "duplicate module `{}`!",
+                                        self.path
+                                    )));
+                                }
                                 return;
                             }
                         }
@@ -424,12 +701,58 @@ impl<'a, 'ast> Visit<'ast> for ModuleVisitor<'a> {
     }
 }
 
+fn check_features(
+    kernels: &[KernelDesc],
+    allowed_features: Features,
+    forbidden_features: Features,
+) -> Result<()> {
+    for kernel in kernels {
+        let name = &kernel.name;
+        let features = kernel.features;
+        let forbidden = Features::new(features.bits & forbidden_features.bits);
+        if forbidden != Features::empty() {
+            bail!("Kernel `{name}` requires forbidden features {forbidden:?}!");
+        }
+        if !allowed_features.contains(features) {
+            let missing = Features::new(features.bits & !allowed_features.bits);
+            bail!("Kernel `{name}` requires features {missing:?}, not in --require-features!");
+        }
+    }
+    Ok(())
+}
+
 fn cache(
     package: &Package,
     kernels: Vec<KernelDesc>,
     check: bool,
     debug_printf: bool,
+    opt_level: OptLevel,
+    compression: SpirvCompression,
 ) -> Result<()> {
+    let cache = encode_cache(kernels, debug_printf, opt_level, compression)?;
+    let manifest_dir = package.manifest_path.parent().unwrap();
+    let cache_path = manifest_dir.join("krnl-cache.rs");
+    if check {
+        let prev = std::fs::read_to_string(&cache_path)?;
+        for (i, (prev, cache)) in prev.lines().zip(cache.lines()).enumerate() {
+            if prev != cache {
+                eprintln!("{i}: {prev}");
+                eprintln!("{i}: {cache}");
+                bail!("{cache_path:?} check failed!");
+            }
+        }
+    } else {
+        std::fs::write(cache_path, cache.as_bytes())?;
+    }
+    Ok(())
+}
+
+fn encode_cache(
+    kernels: Vec<KernelDesc>,
+    debug_printf: bool,
+    opt_level: OptLevel,
+    compression: SpirvCompression,
+) -> Result<String> {
     use flate2::{write::GzEncoder, Compression};
     use zero85::ToZ85;
 
@@ -444,9 +767,20 @@ fn cache(
     let info = if debug_printf {
         "/* debug-printf */\n"
     } else {
-        ""
+        // perf is the default, omitted for compatibility
+        match opt_level {
+            OptLevel::None => "/* opt-level = none */\n",
+            OptLevel::Size => "/* opt-level = size */\n",
+            OptLevel::Performance => "",
+        }
+    };
+    // gzip is the default, omitted for compatibility
+    let compression = match compression {
+        SpirvCompression::Gzip => "",
+        SpirvCompression::Zstd => " zstd,",
+        SpirvCompression::None => " none,",
     };
-    let prefix = format!("{info}__krnl_cache!({version:?}, \"\n");
+    let prefix = format!("{info}__krnl_cache!({version:?},{compression} \"\n");
     let suffix = "\");";
     let mut chunks = bytes.chunks_exact(800);
     let encoded_len = chunks.len() + (bytes.len() / 4 + chunks.remainder().is_empty() as usize) * 5;
@@ -470,87 +804,76 @@ fn cache(
         cache.push('\n');
     }
     cache.push_str(suffix);
-    let manifest_dir = package.manifest_path.parent().unwrap();
-    let cache_path = manifest_dir.join("krnl-cache.rs");
-    if check {
-        let prev = std::fs::read_to_string(&cache_path)?;
-        for (i, (prev, cache)) in prev.lines().zip(cache.lines()).enumerate() {
-            if prev != cache {
-                eprintln!("{i}: {prev}");
-                eprintln!("{i}: {cache}");
-                bail!("{cache_path:?} check failed!");
-            }
+    Ok(cache)
+}
+
+// Writes the codegen backend and its libs to the lib dir and adds it to the library path.
+//
+// spirv-builder finds the backend in the library path of krnlc, and there is no way to pass the
+// environment to the cargo it spawns, so this is called before any threads are spawned.
+fn init_lib_dir(target_dir: &str) -> Result<()> {
+    use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
+
+    let target_krnl_dir = PathBuf::from(target_dir).join("krnlc");
+    std::fs::create_dir_all(&target_krnl_dir)?;
+    let lib_dir = target_krnl_dir.join("lib");
+    if !lib_dir.exists() {
+        std::fs::create_dir(&lib_dir)?;
+    }
+    for lib in [
+        option_env!("KRNLC_LIBLLVM"),
+        option_env!("KRNLC_LIBRUSTC_DRIVER"),
+        option_env!("KRNLC_LIBSTD"),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        let link = lib_dir.join(lib);
+        if !link.exists() {
+            symlink::symlink_file(&PathBuf::from(env!("KRNLC_TOOLCHAIN_LIB")).join(lib), &link)?;
+        }
+    }
+    let rustc_codegen_spirv_lib = format!("{DLL_PREFIX}rustc_codegen_spirv{DLL_SUFFIX}");
+    let librustc_codegen_spirv = include_bytes!(env!("KRNLC_LIBRUSTC_CODEGEN_SPIRV"));
+    std::fs::write(
+        lib_dir.join(rustc_codegen_spirv_lib),
+        librustc_codegen_spirv.as_ref(),
+    )?;
+    // https://github.com/EmbarkStudios/rust-gpu/blob/main/crates/spirv-builder/src/lib.rs
+    fn dylib_path_envvar() -> &'static str {
+        if cfg!(windows) {
+            "PATH"
+        } else if cfg!(target_os = "macos") {
+            "DYLD_FALLBACK_LIBRARY_PATH"
+        } else {
+            "LD_LIBRARY_PATH"
         }
-    } else {
-        std::fs::write(cache_path, cache.as_bytes())?;
     }
+    let lib_dir = lib_dir.canonicalize()?;
+    let path_var = dylib_path_envvar();
+    let path = if let Ok(path) = std::env::var(path_var) {
+        std::env::join_paths(std::iter::once(lib_dir).chain(std::env::split_paths(&path)))?
+    } else {
+        lib_dir.into_os_string()
+    };
+    std::env::set_var(path_var, path);
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn compile(
     package: &Package,
     target_dir: &str,
-    dependencies: &str,
+    krnlc_metadata: &KrnlcMetadata,
     module_sources: FxHashMap<String, String>,
     debug_printf: bool,
     verbose: bool,
     dump_kernels: bool,
+    stats: bool,
+    spirv_target: SpirvTarget,
+    opt_level: OptLevel,
 ) -> Result<Vec<KernelDesc>> {
-    use std::{
-        env::consts::{DLL_PREFIX, DLL_SUFFIX},
-        sync::Once,
-    };
     let target_krnl_dir = PathBuf::from(target_dir).join("krnlc");
-
-    static INIT_LIB_DIR: Once = Once::new();
-    if !INIT_LIB_DIR.is_completed() {
-        std::fs::create_dir_all(&target_krnl_dir)?;
-        let lib_dir = target_krnl_dir.join("lib");
-        if !lib_dir.exists() {
-            std::fs::create_dir(&lib_dir)?;
-        }
-        for lib in [
-            option_env!("KRNLC_LIBLLVM"),
-            option_env!("KRNLC_LIBRUSTC_DRIVER"),
-            option_env!("KRNLC_LIBSTD"),
-        ]
-        .into_iter()
-        .flatten()
-        {
-            let link = lib_dir.join(lib);
-            if !link.exists() {
-                symlink::symlink_file(
-                    &PathBuf::from(env!("KRNLC_TOOLCHAIN_LIB")).join(lib),
-                    &link,
-                )?;
-            }
-        }
-        let rustc_codegen_spirv_lib = format!("{DLL_PREFIX}rustc_codegen_spirv{DLL_SUFFIX}");
-        let librustc_codegen_spirv = include_bytes!(env!("KRNLC_LIBRUSTC_CODEGEN_SPIRV"));
-        std::fs::write(
-            lib_dir.join(&rustc_codegen_spirv_lib),
-            librustc_codegen_spirv.as_ref(),
-        )?;
-        // https://github.com/EmbarkStudios/rust-gpu/blob/main/crates/spirv-builder/src/lib.rs
-        fn dylib_path_envvar() -> &'static str {
-            if cfg!(windows) {
-                "PATH"
-            } else if cfg!(target_os = "macos") {
-                "DYLD_FALLBACK_LIBRARY_PATH"
-            } else {
-                "LD_LIBRARY_PATH"
-            }
-        }
-        let lib_dir = lib_dir.canonicalize()?;
-        let path_var = dylib_path_envvar();
-        let path = if let Ok(path) = std::env::var(path_var) {
-            std::env::join_paths(std::iter::once(lib_dir).chain(std::env::split_paths(&path)))?
-        } else {
-            lib_dir.into_os_string()
-        };
-        std::env::set_var(path_var, path);
-        INIT_LIB_DIR.call_once(|| {});
-    }
     let crate_name = package.name.as_str();
     let device_crate_dir = target_krnl_dir.join("crates").join(crate_name);
     let device_crate_manifest_path = device_crate_dir.join("Cargo.toml");
@@ -560,13 +883,18 @@ fn compile(
         std::fs::create_dir_all(&device_crate_dir)?;
         let config_dir = device_crate_dir.join(".cargo");
         std::fs::create_dir_all(&config_dir)?;
+        // a target dir per device crate, so that concurrent builds don't wait on the lock of a
+        // shared target dir
+        let device_target_dir = target_krnl_dir.join("targets").join(crate_name);
         let config = format!(
             r#"[build]
-target-dir = {target_dir:?}
+target-dir = {device_target_dir:?}
 
 [term]
 verbose = {verbose}
-"#
+
+{device_config}"#,
+            device_config = krnlc_metadata.device_config,
         );
         std::fs::write(config_dir.join("config.toml"), config.as_bytes())?;
         let build_script = r#"fn main() {
@@ -589,7 +917,9 @@ crate-type = ["dylib"]
 
 [dependencies]
 {dependencies}
-"#
+{device_manifest}"#,
+            dependencies = krnlc_metadata.dependencies,
+            device_manifest = krnlc_metadata.device_manifest,
         );
         if let Ok(old_manifest) = std::fs::read_to_string(&device_crate_manifest_path) {
             if manifest != old_manifest {
@@ -620,14 +950,37 @@ crate-type = ["dylib"]
             bail!("cargo update failed!");
         }
     }
+    // modules are only recompiled if the inputs have changed
+    let cache_manifest_path = device_crate_dir.join("cache-manifest.json");
+    let cached_kernels_path = device_crate_dir.join("kernels.bin");
+    let hash = cache_manifest_hash(
+        krnlc_metadata,
+        &module_sources,
+        &device_crate_dir,
+        debug_printf,
+        spirv_target,
+        opt_level,
+    )?;
+    if !dump_kernels {
+        if let Some(kernels) =
+            load_cached_kernels(&cache_manifest_path, &cached_kernels_path, &hash)
+        {
+            if stats {
+                print_stats(crate_name, None, &kernels);
+            }
+            return Ok(kernels);
+        }
+    }
     let crate_name_ident = crate_name.replace('-', "_");
     let kernels_dir = device_crate_dir.join("kernels");
     if dump_kernels {
         std::fs::create_dir_all(&kernels_dir)?;
     }
-    let mut builder = SpirvBuilder::new(&device_crate_dir, "spirv-unknown-vulkan1.2")
+    let mut builder = SpirvBuilder::new(&device_crate_dir, spirv_target.target())
         .spirv_metadata(SpirvMetadata::NameVariables)
-        .print_metadata(MetadataPrintout::None);
+        .print_metadata(MetadataPrintout::None)
+        // rust-gpu does not reliably support the debug profile, opt_level only selects the spirv-opt pass
+        .release(true);
     if debug_printf {
         builder = builder
             .extension("SPV_KHR_non_semantic_info")
@@ -662,7 +1015,17 @@ crate-type = ["dylib"]
     for cap in capabilites {
         builder = builder.capability(cap);
     }
-    let output = builder.build()?;
+    if verbose {
+        eprintln!("device crate: {device_crate_dir:?}");
+    }
+    // the device crate is kept after compiling, so failures can be reproduced by hand
+    let build_start = Instant::now();
+    let output = builder.build().map_err(|e| {
+        Error::new(e).context(format!(
+            "Failed to compile device crate {device_crate_dir:?}!"
+        ))
+    })?;
+    let build_time = build_start.elapsed();
     let spirv_path = output.module.unwrap_single();
     let mut spirv_module = rspirv::dr::load_bytes(std::fs::read(spirv_path)?)
         .map_err(|e| Error::msg(e.to_string()))?;
@@ -676,7 +1039,7 @@ crate-type = ["dylib"]
         .iter()
         .map(|inst| inst.operands[1].unwrap_id_ref())
         .collect();
-    spirv_module
+    let kernels: Vec<KernelDesc> = spirv_module
         .entry_points
         .par_iter()
         .map(|entry_point| {
@@ -688,9 +1051,134 @@ crate-type = ["dylib"]
                 &entry_fns,
                 debug_printf,
                 dump_kernels,
+                spirv_target,
+                opt_level,
             )
         })
-        .collect()
+        .collect::<Result<_>>()?;
+    std::fs::write(&cached_kernels_path, bincode2::serialize(&kernels)?)?;
+    // the lock file may have been created or updated by the build
+    let hash = cache_manifest_hash(
+        krnlc_metadata,
+        &module_sources,
+        &device_crate_dir,
+        debug_printf,
+        spirv_target,
+        opt_level,
+    )?;
+    std::fs::write(
+        &cache_manifest_path,
+        serde_json::to_string_pretty(&CacheManifest { hash })?,
+    )?;
+    if stats {
+        print_stats(crate_name, Some(build_time), &kernels);
+    }
+    Ok(kernels)
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheManifest {
+    hash: String,
+}
+
+fn cache_manifest_hash(
+    krnlc_metadata: &KrnlcMetadata,
+    module_sources: &FxHashMap<String, String>,
+    device_crate_dir: &Path,
+    debug_printf: bool,
+    spirv_target: SpirvTarget,
+    opt_level: OptLevel,
+) -> Result<String> {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = fxhash::FxHasher64::default();
+    VERSION_AND_SHA.hash(&mut hasher);
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/rust-toolchain.toml")).hash(&mut hasher);
+    std::fs::read(device_crate_dir.join("Cargo.lock"))
+        .ok()
+        .hash(&mut hasher);
+    for source in krnlc_metadata.dependency_sources.iter() {
+        if !source.exists() {
+            continue;
+        }
+        for entry in walkdir::WalkDir::new(source).sort_by_file_name() {
+            let entry = entry?;
+            if entry.file_type().is_file() {
+                entry.path().hash(&mut hasher);
+                std::fs::read(entry.path())?.hash(&mut hasher);
+            }
+        }
+    }
+    krnlc_metadata.default_features.hash(&mut hasher);
+    krnlc_metadata.features.hash(&mut hasher);
+    krnlc_metadata.dependencies.hash(&mut hasher);
+    krnlc_metadata.device_manifest.hash(&mut hasher);
+    krnlc_metadata.device_config.hash(&mut hasher);
+    let mut module_sources: Vec<_> = module_sources.iter().collect();
+    module_sources.sort_unstable();
+    module_sources.hash(&mut hasher);
+    debug_printf.hash(&mut hasher);
+    format!("{spirv_target:?}").hash(&mut hasher);
+    format!("{opt_level:?}").hash(&mut hasher);
+    Ok(format!("{:016x}", hasher.finish()))
+}
+
+fn load_cached_kernels(
+    cache_manifest_path: &Path,
+    cached_kernels_path: &Path,
+    hash: &str,
+) -> Option<Vec<KernelDesc>> {
+    let cache_manifest: CacheManifest =
+        serde_json::from_str(&std::fs::read_to_string(cache_manifest_path).ok()?).ok()?;
+    if cache_manifest.hash != hash {
+        return None;
+    }
+    let kernels: Vec<DecodedKernelDesc> =
+        bincode2::deserialize(&std::fs::read(cached_kernels_path).ok()?).ok()?;
+    Some(
+        kernels
+            .into_iter()
+            .map(|kernel| KernelDesc {
+                name: kernel.name,
+                spirv: kernel.spirv,
+                features: Features::new(kernel.features),
+                safe: kernel.safe,
+                spec_descs: kernel.spec_descs,
+                slice_descs: kernel.slice_descs,
+                push_descs: kernel.push_descs,
+            })
+            .collect(),
+    )
+}
+
+fn print_stats(crate_name: &str, build_time: Option<Duration>, kernels: &[KernelDesc]) {
+    use std::fmt::Write;
+
+    let width = kernels
+        .iter()
+        .map(|x| x.name.len())
+        .max()
+        .unwrap_or_default()
+        .max("kernel".len());
+    let mut output = if let Some(build_time) = build_time {
+        format!("{crate_name}: spirv-builder finished in {build_time:.2?}\n")
+    } else {
+        format!("{crate_name}: up to date\n")
+    };
+    writeln!(&mut output, "  {:width$}  {:>8}", "kernel", "words").unwrap();
+    let mut total = 0;
+    for kernel in kernels {
+        writeln!(
+            &mut output,
+            "  {:width$}  {:>8}",
+            kernel.name,
+            kernel.spirv.len()
+        )
+        .unwrap();
+        total += kernel.spirv.len();
+    }
+    writeln!(&mut output, "  {:width$}  {total:>8}", "total").unwrap();
+    eprint!("{output}");
 }
 
 fn write_device_source(src_dir: &Path, module_sources: &FxHashMap<String, String>) -> Result<()> {
@@ -791,6 +1279,7 @@ extern crate krnl_core;
     cleanup_files(src_dir, &files)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn kernel_post_process(
     kernels_dir: &Path,
     crate_name_ident: &str,
@@ -799,6 +1288,8 @@ fn kernel_post_process(
     entry_fns: &FxHashSet<u32>,
     debug_printf: bool,
     dump_kernels: bool,
+    spirv_target: SpirvTarget,
+    opt_level: OptLevel,
 ) -> Result<KernelDesc> {
     use rspirv::{
         binary::Assemble,
@@ -830,7 +1321,7 @@ fn kernel_post_process(
             ..spirv_module.clone()
         };
         let spirv = spirv_module.assemble();
-        let spirv = spirv_opt(&spirv, SpirvOptKind::DeadCodeElimination)?;
+        let spirv = spirv_opt(&spirv, SpirvOptKind::DeadCodeElimination, spirv_target)?;
         let mut spirv_module = rspirv::dr::load_words(&spirv).map_err(|e| Error::msg(e.to_string()))?;
         if debug_printf {
             strip_unused_debug_strings(&mut spirv_module);
@@ -1065,6 +1556,7 @@ fn kernel_post_process(
             };
             bincode2::deserialize(&hex::decode(kernel_data)?)?
         };
+        decorate_non_writable(&mut spirv_module, &kernel_desc.slice_descs);
         {
             let mut builder = rspirv::dr::Builder::new_from_module(std::mem::take(&mut spirv_module));
             let uint = builder.type_int(32, 0);
@@ -1083,9 +1575,14 @@ fn kernel_post_process(
         }
         spirv_module.entry_points.first_mut().unwrap().operands[2] =
             Operand::LiteralString("main".to_string());
-        let mut spirv_module = if !debug_printf {
+        let opt_kind = if !debug_printf {
+            opt_level.opt_kind()
+        } else {
+            None
+        };
+        let mut spirv_module = if let Some(opt_kind) = opt_kind {
             let spirv = spirv_module.assemble();
-            rspirv::dr::load_words(&spirv_opt(&spirv, SpirvOptKind::Performance)?)
+            rspirv::dr::load_words(&spirv_opt(&spirv, opt_kind, spirv_target)?)
                 .map_err(|e| Error::msg(e.to_string()))?
         } else {
             spirv_module
@@ -1226,7 +1723,7 @@ fn kernel_post_process(
             }
         });
         let spirv = spirv_module.assemble();
-        spirv_val(&spirv)?;
+        spirv_val(&spirv, spirv_target)?;
         kernel_desc.features = features;
         if dump_kernels {
             let path = kernels_dir.join(kernel_desc.name.replace("::", "/"));
@@ -1249,24 +1746,28 @@ fn kernel_post_process(
 #[derive(Clone, Copy, Debug)]
 enum SpirvOptKind {
     DeadCodeElimination,
+    Size,
     Performance,
 }
 
-fn spirv_val(spirv: &[u32]) -> Result<()> {
-    use spirv_tools::{val::Validator, TargetEnv};
-    let target_env = TargetEnv::Vulkan_1_2;
+fn spirv_val(spirv: &[u32], spirv_target: SpirvTarget) -> Result<()> {
+    use spirv_tools::val::Validator;
+    let target_env = spirv_target.target_env();
     let validator = spirv_tools::val::create(Some(target_env));
     validator.validate(spirv, None)?;
     Ok(())
 }
 
-fn spirv_opt(spirv: &[u32], kind: SpirvOptKind) -> Result<spirv_tools::binary::Binary> {
+fn spirv_opt(
+    spirv: &[u32],
+    kind: SpirvOptKind,
+    spirv_target: SpirvTarget,
+) -> Result<spirv_tools::binary::Binary> {
     use spirv_tools::{
         opt::{Optimizer, Passes},
         val::Validator,
-        TargetEnv,
     };
-    let target_env = TargetEnv::Vulkan_1_2;
+    let target_env = spirv_target.target_env();
     let validator = spirv_tools::val::create(Some(target_env));
     validator.validate(spirv, None)?;
     let mut optimizer = spirv_tools::opt::create(Some(target_env));
@@ -1286,6 +1787,9 @@ fn spirv_opt(spirv: &[u32], kind: SpirvOptKind) -> Result<spirv_tools::binary::B
                 optimizer.register_pass(pass);
             }
         }
+        SpirvOptKind::Size => {
+            optimizer.register_size_passes();
+        }
         SpirvOptKind::Performance => {
             optimizer.register_performance_passes();
         }
@@ -1293,13 +1797,69 @@ fn spirv_opt(spirv: &[u32], kind: SpirvOptKind) -> Result<spirv_tools::binary::B
     Ok(optimizer.optimize(spirv, &mut |_| (), None)?)
 }
 
+// immutable slices are decorated NonWritable, so that the driver can optimize reads
+fn decorate_non_writable(module: &mut rspirv::dr::Module, slice_descs: &[SliceDesc]) {
+    use rspirv::{
+        dr::{Instruction, Operand},
+        spirv::{Decoration, Op},
+    };
+    let mut descriptor_sets = FxHashMap::default();
+    let mut bindings = FxHashMap::default();
+    let mut decorated = FxHashSet::default();
+    for inst in module.annotations.iter() {
+        if inst.class.opcode == Op::Decorate {
+            match inst.operands.as_slice() {
+                [Operand::IdRef(id), Operand::Decoration(Decoration::DescriptorSet), Operand::LiteralInt32(set)] =>
+                {
+                    descriptor_sets.insert(*id, *set);
+                }
+                [Operand::IdRef(id), Operand::Decoration(Decoration::Binding), Operand::LiteralInt32(binding)] =>
+                {
+                    bindings.insert(*id, *binding);
+                }
+                [Operand::IdRef(id), Operand::Decoration(Decoration::NonWritable)] => {
+                    decorated.insert(*id);
+                }
+                _ => {}
+            }
+        }
+    }
+    let mut non_writable: Vec<_> = bindings
+        .into_iter()
+        .filter(|(id, binding)| {
+            !decorated.contains(id)
+                && descriptor_sets.get(id) == Some(&0)
+                && slice_descs
+                    .get(*binding as usize)
+                    .is_some_and(|x| !x.mutable)
+        })
+        .map(|(id, _)| id)
+        .collect();
+    non_writable.sort_unstable();
+    for id in non_writable {
+        module.annotations.push(Instruction::new(
+            Op::Decorate,
+            None,
+            None,
+            vec![
+                Operand::IdRef(id),
+                Operand::Decoration(Decoration::NonWritable),
+            ],
+        ));
+    }
+}
+
 fn add_spec_constant_ops(module: &mut rspirv::dr::Module) {
     use rspirv::{
         dr::{Instruction, Operand},
         spirv::Op,
     };
-    let mut constants = FxHashSet::default();
+    let mut int_types = FxHashSet::default();
+    let mut constants = FxHashMap::default();
     for inst in module.types_global_values.iter() {
+        if inst.class.opcode == Op::TypeInt {
+            int_types.extend(inst.result_id);
+        }
         if matches!(
             inst.class.opcode,
             Op::Constant
@@ -1314,13 +1874,34 @@ fn add_spec_constant_ops(module: &mut rspirv::dr::Module) {
                 | Op::SpecConstantOp
         ) {
             if let Some(result_id) = inst.result_id {
-                constants.insert(result_id);
+                constants.insert(result_id, inst.result_type);
             }
         }
     }
     for function in module.functions.iter_mut() {
         for block in function.blocks.iter_mut() {
-            block.instructions.retain(|inst| {
+            block.instructions.retain_mut(|inst| {
+                // bitcasts are not allowed in shader spec constant ops, casts between integers of
+                // the same width are equivalent to or-ing the value with itself
+                if let (Op::Bitcast, [Operand::IdRef(value)]) =
+                    (inst.class.opcode, inst.operands.as_slice())
+                {
+                    let value = *value;
+                    let from_int = constants
+                        .get(&value)
+                        .copied()
+                        .flatten()
+                        .is_some_and(|ty| int_types.contains(&ty));
+                    let to_int = inst.result_type.is_some_and(|ty| int_types.contains(&ty));
+                    if from_int && to_int {
+                        *inst = Instruction::new(
+                            Op::BitwiseOr,
+                            inst.result_type,
+                            inst.result_id,
+                            vec![Operand::IdRef(value), Operand::IdRef(value)],
+                        );
+                    }
+                }
                 if matches!(
                     inst.class.opcode,
                     Op::SConvert
@@ -1336,19 +1917,17 @@ fn add_spec_constant_ops(module: &mut rspirv::dr::Module) {
                         | Op::UMod
                         | Op::SRem
                         | Op::SMod
-                  /* | Op::ShiftRightLogical
+                        | Op::ShiftRightLogical
                         | Op::ShiftRightArithmetic
                         | Op::ShiftLeftLogical
                         | Op::BitwiseOr
+                        | Op::BitwiseXor
                         | Op::BitwiseAnd
-                        | Op::VectorShuffle
-                        | Op::CompositeExtract
-                        | Op::CompositeInsert
                         | Op::LogicalOr
                         | Op::LogicalAnd
                         | Op::LogicalNot
                         | Op::LogicalEqual
-                        | Op::LogicalNotEqual */
+                        | Op::LogicalNotEqual
                         | Op::Select
                         | Op::IEqual
                         | Op::INotEqual
@@ -1365,7 +1944,7 @@ fn add_spec_constant_ops(module: &mut rspirv::dr::Module) {
                         let mut used_constants = FxHashSet::default();
                         for operand in inst.operands.iter() {
                             if let Operand::IdRef(id) = operand {
-                                if !constants.contains(id) {
+                                if !constants.contains_key(id) {
                                     return true;
                                 }
                                 used_constants.insert(*id);
@@ -1389,7 +1968,7 @@ fn add_spec_constant_ops(module: &mut rspirv::dr::Module) {
                                                 .collect(),
                                         ),
                                     );
-                                    constants.insert(result_id);
+                                    constants.insert(result_id, inst.result_type);
                                     return false;
                                 }
                             }
@@ -1716,6 +2295,21 @@ impl Features {
             }
         })
     }
+    fn parse_names(names: &[String]) -> Result<Self> {
+        let mut output = Self::empty();
+        for name in names {
+            let upper = name.trim().to_uppercase();
+            let features = (0..32)
+                .map(|i| Self::new(1 << i))
+                .find(|x| Self::all().contains(*x) && x.name_iter().eq([upper.as_str()]));
+            if let Some(features) = features {
+                output = output.union(features);
+            } else {
+                bail!("Unknown feature {name:?}!");
+            }
+        }
+        Ok(output)
+    }
 }
 
 /*
@@ -1800,3 +2394,128 @@ struct KrnlcCache {
     version: String,
     kernels: Vec<KernelDesc>,
 }
+
+// KernelDesc skips deserializing spirv and features
+#[derive(Deserialize)]
+struct DecodedKernelDesc {
+    name: String,
+    spirv: Vec<u32>,
+    features: u32,
+    safe: bool,
+    spec_descs: Vec<SpecDesc>,
+    slice_descs: Vec<SliceDesc>,
+    push_descs: Vec<PushDesc>,
+}
+
+#[derive(Deserialize)]
+struct DecodedCache {
+    version: String,
+    kernels: Vec<DecodedKernelDesc>,
+}
+
+fn decode_cache(cache: &str) -> Result<DecodedCache> {
+    use flate2::read::GzDecoder;
+    use zero85::FromZ85;
+
+    let data = cache
+        .trim_end()
+        .split_once("\"\n")
+        .and_then(|(_, data)| data.strip_suffix("\");"))
+        .ok_or_else(|| Error::msg("invalid cache!"))?;
+    let mut bytes = Vec::new();
+    for data in data.split_ascii_whitespace() {
+        bytes.extend_from_slice(&data.from_z85().map_err(|e| Error::msg(e.to_string()))?);
+    }
+    Ok(bincode2::deserialize_from(GzDecoder::new(
+        bytes.as_slice(),
+    ))?)
+}
+
+fn inspect(cache_path: &Path) -> Result<()> {
+    let cache = decode_cache(&std::fs::read_to_string(cache_path)?)
+        .map_err(|e| e.context(format!("{cache_path:?} could not be decoded!")))?;
+    println!("krnlc {}, {} kernels", cache.version, cache.kernels.len());
+    let mut module = None;
+    for kernel in cache.kernels.iter() {
+        let (module_path, kernel_name) =
+            kernel.name.rsplit_once("::").unwrap_or(("", &kernel.name));
+        if module != Some(module_path) {
+            println!("{module_path}");
+            module.replace(module_path);
+        }
+        let safety = if kernel.safe { "safe" } else { "unsafe" };
+        println!(
+            "  {kernel_name}: {safety}, spirv {} bytes, {:?}",
+            kernel.spirv.len() * 4,
+            Features::new(kernel.features),
+        );
+        for spec_desc in kernel.spec_descs.iter() {
+            println!("    spec {}: {:?}", spec_desc.name, spec_desc.scalar_type);
+        }
+        for slice_desc in kernel.slice_descs.iter() {
+            let mutability = if slice_desc.mutable { "mut " } else { "" };
+            let item = if slice_desc.item { ", item" } else { "" };
+            println!(
+                "    slice {}: {mutability}{:?}{item}",
+                slice_desc.name, slice_desc.scalar_type
+            );
+        }
+        for push_desc in kernel.push_descs.iter() {
+            println!("    push {}: {:?}", push_desc.name, push_desc.scalar_type);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compiles tests/krnlc-tests in process, without writing krnl-cache.rs.
+    #[test]
+    fn krnlc_tests() -> Result<()> {
+        let manifest_path =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/krnlc-tests/Cargo.toml");
+        let metadata = cargo_metadata::MetadataCommand::new()
+            .manifest_path(manifest_path)
+            .exec()?;
+        let package = metadata
+            .packages
+            .iter()
+            .find(|x| x.name == "krnlc-tests")
+            .unwrap();
+        let target_dir = std::env::temp_dir().join("krnlc-tests");
+        let target_dir = target_dir.to_string_lossy();
+        let krnlc_metadata = KrnlcMetadata::new(&metadata, package)?;
+        let module_sources = cargo_expand(package, &target_dir, &krnlc_metadata, false)?;
+        assert!(!module_sources.is_empty());
+        init_lib_dir(&target_dir)?;
+        let kernels = compile(
+            package,
+            &target_dir,
+            &krnlc_metadata,
+            module_sources,
+            false,
+            false,
+            false,
+            false,
+            SpirvTarget::default(),
+            OptLevel::default(),
+        )?;
+        let kernel_names: Vec<String> = kernels.iter().map(|x| x.name.clone()).collect();
+        assert!(!kernel_names.is_empty());
+        let cache = decode_cache(&encode_cache(
+            kernels,
+            false,
+            OptLevel::default(),
+            SpirvCompression::Gzip,
+        )?)?;
+        assert_eq!(cache.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(
+            cache.kernels.iter().map(|x| &x.name).collect::<Vec<_>>(),
+            kernel_names.iter().collect::<Vec<_>>()
+        );
+        assert!(cache.kernels.iter().all(|x| !x.spirv.is_empty()));
+        Ok(())
+    }
+}
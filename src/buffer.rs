@@ -59,10 +59,12 @@ use paste::paste;
 use serde::{de::Deserializer, ser::Serializer, Deserialize, Serialize};
 use std::{
     fmt::{self, Debug},
+    future::Future,
     marker::PhantomData,
     mem::{forget, size_of},
     ops::{Bound, RangeBounds},
     sync::Arc,
+    task::Poll,
 };
 
 /// Errors.
@@ -183,6 +185,30 @@ impl RawSlice {
         }
         Some(self)
     }
+    // Offset in bytes of self within parent.
+    fn offset_in(&self, parent: &Self) -> Option<usize> {
+        let (offset, parent_offset) = match (&self.inner, &parent.inner) {
+            (RawSliceInner::Host(raw), RawSliceInner::Host(parent)) => {
+                (raw.ptr as usize, parent.ptr as usize)
+            }
+            #[cfg(feature = "device")]
+            (RawSliceInner::Device(buffer), RawSliceInner::Device(parent)) => {
+                if buffer.device() != parent.device()
+                    || buffer.vulkan_buffer() != parent.vulkan_buffer()
+                {
+                    return None;
+                }
+                (buffer.offset(), parent.offset())
+            }
+            #[cfg(feature = "device")]
+            _ => return None,
+        };
+        let start = offset.checked_sub(parent_offset)?;
+        if start.checked_add(self.len())? > parent.len() {
+            return None;
+        }
+        Some(start)
+    }
 }
 
 #[derive(Clone)]
@@ -911,10 +937,18 @@ impl<S: ScalarDataOwned> ScalarBufferBase<S> {
 }
 
 impl<S: ScalarData> ScalarBufferBase<S> {
-    /// The device.
+    /** The device.
+
+    See [`BufferBase::device`]. */
     pub fn device(&self) -> Device {
         self.data.device()
     }
+    /** Whether `self` and `other` are on the same device.
+
+    See [`BufferBase::same_device_as`]. */
+    pub fn same_device_as<S2: ScalarData>(&self, other: &ScalarBufferBase<S2>) -> bool {
+        self.device() == other.device()
+    }
     /// The scalar_type.
     pub fn scalar_type(&self) -> ScalarType {
         self.data.scalar_type()
@@ -923,6 +957,12 @@ impl<S: ScalarData> ScalarBufferBase<S> {
     pub fn len(&self) -> usize {
         self.data.len()
     }
+    /// The length in bytes.
+    ///
+    /// `len_bytes = len * scalar_type.size()`
+    pub fn len_bytes(&self) -> usize {
+        self.len() * self.scalar_type().size()
+    }
     /// Is the buffer empty.
     pub fn is_empty(&self) -> bool {
         self.len() == 0
@@ -1072,6 +1112,16 @@ impl<S: ScalarData> ScalarBufferBase<S> {
         }});
         Ok(())
     }
+    /** Fills the buffer with zeros.
+
+    See [`BufferBase::zero`]. */
+    pub fn zero(&mut self) -> Result<()>
+    where
+        S: ScalarDataMut,
+    {
+        let scalar_type = self.scalar_type();
+        self.fill(ScalarElem::zero(scalar_type))
+    }
     /** Casts to `scalar_type`.
 
     See [`BufferBase::cast`]. */
@@ -1131,7 +1181,10 @@ impl<S: ScalarData> ScalarBufferBase<S> {
     }
     /** Copies from src.
 
-    See [`BufferBase::copy_from_slice`]. */
+    See [`BufferBase::copy_from_slice`].
+
+    # Errors
+    - `src` is not the same scalar type. */
     pub fn copy_from_scalar_slice(&mut self, src: &ScalarSlice) -> Result<()>
     where
         S: ScalarDataMut,
@@ -1140,6 +1193,26 @@ impl<S: ScalarData> ScalarBufferBase<S> {
             .as_scalar_slice_mut()
             .copy_from_scalar_slice(&src.data)
     }
+    /** Copies from src on the same device.
+
+    See [`BufferBase::copy_from`].
+
+    # Errors
+    - `src` is on a different device.
+    - `src` is not the same scalar type. */
+    pub fn copy_from(&mut self, src: &ScalarSlice) -> Result<()>
+    where
+        S: ScalarDataMut,
+    {
+        if self.device() != src.device() {
+            bail!(
+                "Can not copy slice from {:?} to {:?}!",
+                src.device(),
+                self.device()
+            );
+        }
+        self.copy_from_scalar_slice(src)
+    }
     /** A subslice with `range`.
 
     See [`BufferBase::slice`]. */
@@ -1159,6 +1232,29 @@ impl<S: ScalarData> ScalarBufferBase<S> {
     }
 }
 
+impl RawSlice {
+    // The pointer and length in bytes, if on the host.
+    fn host_ptr(&self) -> Option<(*mut u8, usize)> {
+        match &self.inner {
+            RawSliceInner::Host(raw) => Some((raw.ptr, raw.len)),
+            #[cfg(feature = "device")]
+            RawSliceInner::Device(_) => None,
+        }
+    }
+}
+
+impl ScalarSlice<'_> {
+    pub(crate) fn host_ptr(&self) -> Option<(*mut u8, usize)> {
+        self.data.raw.host_ptr()
+    }
+}
+
+impl ScalarSliceMut<'_> {
+    pub(crate) fn host_ptr_mut(&self) -> Option<(*mut u8, usize)> {
+        self.data.raw.host_ptr()
+    }
+}
+
 #[cfg(feature = "device")]
 impl ScalarSlice<'_> {
     pub(crate) fn device_buffer(&self) -> Option<&DeviceBuffer> {
@@ -1390,7 +1486,8 @@ impl<T: Scalar> BufferRepr<T> {
             #[cfg(feature = "device")]
             DeviceInner::Device(device) => {
                 let width = size_of::<T>();
-                let cap = len * width;
+                // overflow is reported as DeviceBufferTooLarge
+                let cap = len.saturating_mul(width);
                 let device_buffer = unsafe { DeviceBuffer::uninit(device.clone(), cap)? };
                 let raw = RawBuffer {
                     slice: RawSlice {
@@ -1646,15 +1743,8 @@ impl<'a, T: Scalar> SliceMutRepr<'a, T> {
             }
             #[cfg(feature = "device")]
             (RawSliceInner::Device(dst), RawSliceInner::Device(src_buffer)) => {
-                if dst.device() != src_buffer.device() {
-                    return src_buffer.transfer(dst);
-                }
-                Slice { data: src.clone() }.cast_impl(&mut SliceMut::<T> {
-                    data: SliceMutRepr {
-                        raw: self.raw.clone(),
-                        _m: PhantomData,
-                    },
-                })
+                // copies on the same device are recorded as a transfer command, without host staging
+                src_buffer.transfer(dst)
             }
         }
     }
@@ -2098,6 +2188,25 @@ impl<T: Scalar, S: DataOwned<Elem = T>> BufferBase<S> {
     pub fn ones(device: Device, len: usize) -> Result<Self> {
         Self::from_elem(device, len, T::one())
     }
+    /** Create a buffer filled with 0's, checking capacity up front.
+
+    Like [`zeros()`](BufferBase::zeros), but returns an error instead of panicking if
+    `len` elements can not be allocated on the host.
+
+    # Errors
+    - [`DeviceLost`]
+    - [`DeviceBufferTooLarge`]
+    - [`OutOfDeviceMemory`]
+    - The host could not allocate `len` elements. */
+    pub fn try_new(device: Device, len: usize) -> Result<Self> {
+        if device.is_host() {
+            let mut vec = Vec::new();
+            vec.try_reserve_exact(len)?;
+            vec.resize(len, T::zero());
+            return Ok(Self::from_vec(vec));
+        }
+        Self::zeros(device, len)
+    }
     /// Create a buffer from a [`Vec`].
     pub fn from_vec(vec: Vec<T>) -> Self {
         let data = S::from_buffer(BufferRepr::from_vec(vec));
@@ -2127,10 +2236,20 @@ impl<'a, T: Scalar> SliceMut<'a, T> {
 }
 
 impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
-    /// The device.
+    /** The device.
+
+    Returns [`Device::host()`] for host buffers, otherwise the device the buffer was allocated on.
+    This is cheap, only cloning an [`Arc`](std::sync::Arc). */
     pub fn device(&self) -> Device {
         self.data.device()
     }
+    /** Whether `self` and `other` are on the same device.
+
+    Cheap check that can be used to fail early, before preparing arguments for
+    a dispatch or copy. */
+    pub fn same_device_as<S2: Data>(&self, other: &BufferBase<S2>) -> bool {
+        self.device() == other.device()
+    }
     /// The scalar type.
     pub fn scalar_type(&self) -> ScalarType {
         self.data.scalar_type()
@@ -2139,6 +2258,12 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     pub fn len(&self) -> usize {
         self.data.len()
     }
+    /// The length in bytes.
+    ///
+    /// `len_bytes = len * scalar_type.size()`
+    pub fn len_bytes(&self) -> usize {
+        self.len() * self.scalar_type().size()
+    }
     /// Is the buffer empty.
     pub fn is_empty(&self) -> bool {
         self.len() == 0
@@ -2186,6 +2311,26 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     {
         self.data.as_host_slice_mut()
     }
+    /** The underlying Vulkan buffer, for use with external Vulkan code.
+
+    Returns the raw [`vk::Buffer`](ash::vk::Buffer), with the offset and length in bytes.
+    Returns [`None`] if not on a device or empty.
+
+    # Safety
+    The buffer is not synchronized with krnl. The caller must [wait](Device::wait) for
+    previous work on the device to finish before accessing the buffer, and ensure that external
+    work has finished before the buffer is used by krnl or dropped. Immutable buffers and slices
+    must not be written to. */
+    #[cfg(feature = "device")]
+    pub unsafe fn vulkan_buffer(&self) -> Option<(ash::vk::Buffer, u64, u64)> {
+        let slice = self.data.as_slice();
+        if let RawSliceInner::Device(buffer) = &slice.raw.inner {
+            let vulkan_buffer = buffer.vulkan_buffer()?;
+            Some((vulkan_buffer, buffer.offset() as u64, buffer.len() as u64))
+        } else {
+            None
+        }
+    }
     /// Borrow as a scalar slice.
     pub fn as_scalar_slice(&self) -> ScalarSlice {
         let data = self.data.as_scalar_slice();
@@ -2244,6 +2389,39 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
             self.to_device(device)
         }
     }
+    /** Moves into the device asynchronously.
+
+    The upload is queued when called, and the returned future resolves once it has completed,
+    allowing the next buffer to be prepared while the device is busy. The future is woken by the
+    device, so it can be polled by any executor.
+
+    See [`.into_device()`](BufferBase::into_device). */
+    pub fn into_device_async(
+        self,
+        device: Device,
+    ) -> impl Future<Output = Result<Buffer<T>>> + Send + 'static
+    where
+        Self: Send + 'static,
+    {
+        let mut output = Some(self.into_device(device));
+        #[cfg(feature = "device")]
+        let epoch = match output.as_ref().map(|x| x.as_ref().map(|x| x.device())) {
+            Some(Ok(device)) => match device.inner() {
+                DeviceInner::Device(device) => Some((device.clone(), device.epoch())),
+                DeviceInner::Host => None,
+            },
+            _ => None,
+        };
+        std::future::poll_fn(move |cx| {
+            #[cfg(feature = "device")]
+            if let Some((device, epoch)) = epoch.as_ref() {
+                std::task::ready!(device.poll_epoch(*epoch, cx.waker()))?;
+            }
+            #[cfg(not(feature = "device"))]
+            let _ = cx;
+            Poll::Ready(output.take().unwrap())
+        })
+    }
     /** Moves into the device as an arc buffer.
 
     See [`.into_device()`](BufferBase::into_device). */
@@ -2259,7 +2437,11 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     # Errors
     - [`DeviceLost`]
     - [`OutOfDeviceMemory`]
-    - Could not dispatch the kernel. */
+    - Could not dispatch the kernel.
+
+    [`OutOfDeviceMemory`] is returned when allocating, before any data is transferred. Failures
+    during the transfer include the byte offset of the failed chunk as context, use
+    [`Error::downcast_ref`](anyhow::Error::downcast_ref) to check for [`DeviceLost`]. */
     pub fn to_device(&self, device: Device) -> Result<Buffer<T>> {
         let data = self.data.as_slice().to_device(device)?;
         Ok(Buffer { data })
@@ -2286,6 +2468,27 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
             self.to_device(device).map(Into::into)
         }
     }
+    /** Copies from the host into an existing slice.
+
+    Like [`.to_device()`](BufferBase::to_device), but reuses the allocation of `dst` instead of
+    allocating a new buffer, avoiding allocator churn when uploading data of the same length
+    repeatedly.
+
+    # Errors
+    - `self` is not on the host.
+    - `dst` is not the same length.
+    - [`DeviceLost`]
+
+    Failures during the transfer include the byte offset of the failed chunk as context. */
+    pub fn copy_to_device(&self, dst: &mut SliceMut<T>) -> Result<()> {
+        if !self.device().is_host() {
+            bail!(
+                "Can not copy to device from {:?}, expected host!",
+                self.device()
+            );
+        }
+        dst.copy_from_slice(&self.as_slice())
+    }
     /** Moves into a [`Vec`].
 
     See [`.into_device()`](BufferBase::into_device) */
@@ -2295,14 +2498,68 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
             Err(data) => data.as_slice().to_vec(),
         }
     }
+    /** Moves into a [`Vec`] asynchronously.
+
+    On device, the download is recorded when the future is first polled, and the future is woken
+    when the transfer completes, without blocking a thread. Use to overlap readback with other work.
+
+    See [`.into_vec()`](BufferBase::into_vec). */
+    pub fn into_vec_async(self) -> impl Future<Output = Result<Vec<T>>> + Send + 'static
+    where
+        Self: Send + 'static,
+    {
+        #[cfg(feature = "device")]
+        let download = if let RawSliceInner::Device(buffer) = &self.data.as_slice().raw.inner {
+            Some(buffer.download_async(ScalarVec(vec![T::default(); self.len()])))
+        } else {
+            None
+        };
+        async move {
+            #[cfg(feature = "device")]
+            if let Some(download) = download {
+                return Ok(download.await?.0);
+            }
+            self.into_vec()
+        }
+    }
     /** Copies to a [`Vec`].
 
+    If on the host, the data is copied directly, without any device operations. Otherwise,
+    downloads from the device.
+
     See [`.to_device()`](BufferBase::to_device). */
     pub fn to_vec(&self) -> Result<Vec<T>> {
         self.data.as_slice().to_vec()
     }
+    /** Copies `range` to a [`Vec`], without waiting for pending writes.
+
+    Intended for polling values written progressively by a running kernel, ie a progress
+    counter. If the device memory is host visible, it is read directly and may be partially
+    written. Otherwise, this is equivalent to `.slice(range).to_vec()`, which waits for
+    previous operations.
+
+    This is best effort, use [`.to_vec()`](BufferBase::to_vec) for a synchronized copy.
+
+    # Errors
+    - `range` is out of bounds.
+    - [`DeviceLost`] */
+    pub fn peek(&self, range: impl RangeBounds<usize>) -> Result<Vec<T>> {
+        let Some(slice) = self.slice(range) else {
+            bail!("Range out of bounds for buffer of length {}!", self.len());
+        };
+        #[cfg(feature = "device")]
+        if let RawSliceInner::Device(buffer) = &slice.data.raw.inner {
+            let mut vec = vec![T::default(); slice.len()];
+            buffer.peek(bytemuck::cast_slice_mut(&mut vec))?;
+            return Ok(vec);
+        }
+        slice.to_vec()
+    }
     /** Fills with `elem`.
 
+    On device, a transfer command is used if the buffer is 4 byte aligned and `elem` repeats every 4
+    bytes (ie any 8, 16, or 32 bit scalar). Otherwise a kernel is dispatched.
+
     # Errors
     - [`DeviceLost`]
     - The kernel could not be dispatched.
@@ -2337,6 +2594,15 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
                 }
                 y
             }
+            // vkCmdFillBuffer repeats a u32, so it is used when the bytes of elem repeat
+            let x: u64 = copied_bytes(elem);
+            if x as u32 == (x >> 32) as u32 {
+                if let Ok(y) = self.bitcast_mut::<u32>() {
+                    if let RawSliceInner::Device(buffer) = &y.data.raw.inner {
+                        return buffer.fill(x as u32);
+                    }
+                }
+            }
             let device = self.device();
             let features = device.info().unwrap().features();
             if features.contains(Features::INT64) {
@@ -2376,8 +2642,34 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
             }
         }
     }
+    /** Fills with zeros.
+
+    Useful for clearing an output between iterations without reallocating.
+
+    See [`.fill()`](BufferBase::fill). */
+    pub fn zero(&mut self) -> Result<()>
+    where
+        S: DataMut,
+    {
+        self.fill(T::default())
+    }
     /** Casts to `Y`.
 
+    Supports numeric conversions between all scalar types, ie f32 <-> f16 / bf16 and
+    int <-> float, with the semantics of [`Scalar::cast`](krnl_core::scalar::Scalar::cast).
+    On the host this is a loop, on a device a builtin kernel is dispatched. The
+    [`Features`] required by the kernel are checked before it is dispatched.
+
+    ```no_run
+    # use krnl::{anyhow::Result, buffer::Buffer, device::Device, half::f16};
+    # fn main() -> Result<()> {
+    # let device = Device::builder().build()?;
+    let x = Buffer::from(vec![1f32, 2., 3.]).into_device(device)?;
+    let y: Buffer<f16> = x.cast()?;
+    # Ok(())
+    # }
+    ```
+
     # Errors
     - [`DeviceLost`]
     - The kernel could not be dispatched.
@@ -2428,10 +2720,12 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     }
     /** Copies from src.
 
+    `src` may be on a different device, in which case the data is streamed through host staging
+    buffers in chunks, without allocating an intermediate `Vec`.
+
     # Errors
     - `src` is not the same length.
     - [`DeviceLost`]
-    - The kernel could not be dispatched.
     */
     pub fn copy_from_slice(&mut self, src: &Slice<T>) -> Result<()>
     where
@@ -2439,6 +2733,28 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     {
         self.data.as_slice_mut().copy_from_slice(&src.data)
     }
+    /** Copies from src on the same device.
+
+    Like [`.copy_from_slice()`](BufferBase::copy_from_slice), but never stages through the host.
+
+    # Errors
+    - `src` is on a different device.
+    - `src` is not the same length.
+    - [`DeviceLost`]
+    */
+    pub fn copy_from(&mut self, src: &Slice<T>) -> Result<()>
+    where
+        S: DataMut,
+    {
+        if self.device() != src.device() {
+            bail!(
+                "Can not copy slice from {:?} to {:?}!",
+                src.device(),
+                self.device()
+            );
+        }
+        self.copy_from_slice(src)
+    }
     /** A subslice with `range`.
 
     Returns None if range is out of bounds.
@@ -2448,6 +2764,40 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
         let data = self.data.as_slice().slice(range)?;
         Some(Slice { data })
     }
+    /** The offset of `self` within `parent`.
+
+    Returns None if `self` is not a subslice of `parent`. */
+    pub fn offset_in(&self, parent: &Slice<T>) -> Option<usize> {
+        let offset = self
+            .data
+            .as_slice()
+            .raw
+            .offset_in(&parent.data.as_slice().raw)?;
+        Some(offset / size_of::<T>())
+    }
+    /** Offsets and lengths of `batches` within `self`.
+
+    Returns a buffer on the same device with `[offset, len]` for each batch. This allows
+    dispatching a kernel once over many subslices, with the buffer and `self` as
+    [global](crate::kernel#global-buffers) arguments, instead of once per subslice.
+
+    # Errors
+    - A batch is not a subslice of `self`.
+    - See [`.to_device()`](Self::to_device). */
+    pub fn batch_ranges(&self, batches: &[Slice<T>]) -> Result<Buffer<u32>> {
+        let parent = self.as_slice();
+        let mut ranges = Vec::with_capacity(2 * batches.len());
+        for (i, batch) in batches.iter().enumerate() {
+            let Some(offset) = batch.offset_in(&parent) else {
+                bail!("Batch {i} is not a subslice!");
+            };
+            let (Ok(offset), Ok(len)) = (u32::try_from(offset), u32::try_from(batch.len())) else {
+                bail!("Batch {i} is out of range!");
+            };
+            ranges.extend([offset, len]);
+        }
+        Buffer::from(ranges).into_device(self.device())
+    }
     /** A mutable subslice with `range`.
 
     Returns None if range is out of bounds.
@@ -2541,6 +2891,111 @@ impl<S: Data> Debug for BufferBase<S> {
     }
 }
 
+/** Double buffering for streaming data to a device.
+
+Data for the next iteration is uploaded into the idle buffer, while kernels operate on the
+active buffer. Uploading into the idle buffer does not wait for dispatches on the active buffer to
+finish, the data is staged and the upload returns once the copy is queued. The device executes
+transfers and kernels in the order they are submitted, so the copy itself does not overlap with
+kernels that were dispatched before it.
+
+```no_run
+# use krnl::{anyhow::Result, device::Device, buffer::PingPong};
+# fn main() -> Result<()> {
+# let device = Device::host();
+# let batches: Vec<Vec<f32>> = Vec::new();
+let mut buffers = PingPong::<f32>::new(device, 1024)?;
+for batch in batches.iter() {
+    buffers.upload(batch)?;
+    buffers.swap();
+    let x = buffers.active();
+    // dispatch kernels with x
+}
+# Ok(())
+# }
+```
+*/
+pub struct PingPong<T: Scalar> {
+    buffers: [Buffer<T>; 2],
+    active: usize,
+}
+
+impl<T: Scalar> PingPong<T> {
+    /** Creates two zeroed buffers with `len` on `device`.
+
+    # Errors
+    See [`Buffer::zeros()`]. */
+    pub fn new(device: Device, len: usize) -> Result<Self> {
+        let buffers = [
+            Buffer::zeros(device.clone(), len)?,
+            Buffer::zeros(device, len)?,
+        ];
+        Ok(Self { buffers, active: 0 })
+    }
+    /// The device.
+    pub fn device(&self) -> Device {
+        self.buffers[0].device()
+    }
+    /// The length of each buffer.
+    pub fn len(&self) -> usize {
+        self.buffers[0].len()
+    }
+    /// Is the length 0.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Borrows the active buffer.
+    pub fn active(&self) -> Slice<T> {
+        self.buffers[self.active].as_slice()
+    }
+    /// Mutably borrows the active buffer.
+    pub fn active_mut(&mut self) -> SliceMut<T> {
+        self.buffers[self.active].as_slice_mut()
+    }
+    /// Borrows the idle buffer.
+    pub fn idle(&self) -> Slice<T> {
+        self.buffers[1 - self.active].as_slice()
+    }
+    /// Mutably borrows the idle buffer.
+    pub fn idle_mut(&mut self) -> SliceMut<T> {
+        self.buffers[1 - self.active].as_slice_mut()
+    }
+    /** Copies `data` into the idle buffer.
+
+    See [`.copy_from_slice()`](BufferBase::copy_from_slice).
+
+    # Errors
+    - `data` is not the same length.
+    - [`DeviceLost`] */
+    pub fn upload(&mut self, data: &[T]) -> Result<()> {
+        self.idle_mut().copy_from_slice(&Slice::from(data))
+    }
+    /// Swaps the active and idle buffers.
+    pub fn swap(&mut self) {
+        self.active = 1 - self.active;
+    }
+    /// Returns the buffers, active first.
+    pub fn into_buffers(self) -> [Buffer<T>; 2] {
+        let [a, b] = self.buffers;
+        if self.active == 0 {
+            [a, b]
+        } else {
+            [b, a]
+        }
+    }
+}
+
+impl<T: Scalar> Debug for PingPong<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PingPong")
+            .field("device", &self.device())
+            .field("scalar_type", &T::SCALAR_TYPE)
+            .field("len", &self.len())
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<S1: Data> Serialize for BufferBase<S1> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -2608,6 +3063,21 @@ mod kernels {
     });
 }
 
+// Downloads into a Vec<T> as bytes.
+#[cfg(feature = "device")]
+struct ScalarVec<T>(Vec<T>);
+
+#[cfg(feature = "device")]
+impl<T: Scalar> AsMut<[u8]> for ScalarVec<T> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(&mut self.0)
+    }
+}
+
+// Vec<T> is Unpin regardless of T.
+#[cfg(feature = "device")]
+impl<T> Unpin for ScalarVec<T> {}
+
 #[cfg(all(test, feature = "serde"))]
 mod tests {
     use super::*;
@@ -19,11 +19,20 @@ dbg!(device.info());
 use crate::kernel::{KernelDesc, KernelKey};
 use anyhow::Result;
 use std::{
+    backtrace::Backtrace,
     fmt::{self, Debug},
+    path::PathBuf,
     sync::Arc,
+    time::Duration,
 };
 #[cfg(feature = "device")]
-use std::{ops::Range, sync::atomic::AtomicBool};
+use std::{
+    future::Future,
+    ops::Range,
+    pin::Pin,
+    sync::atomic::AtomicBool,
+    task::{Poll, Waker},
+};
 
 #[cfg(all(not(target_family = "wasm"), feature = "device"))]
 mod vulkan_engine;
@@ -66,6 +75,22 @@ pub mod error {
         pub(super) devices: usize,
     }
 
+    /// No device matches the [name](super::builder::DeviceBuilder::name) or
+    /// [uuid](super::builder::DeviceBuilder::uuid) of the builder.
+    #[cfg(any(doc, feature = "device"))]
+    #[derive(Clone, Debug, thiserror::Error)]
+    #[cfg_attr(
+        feature = "device",
+        error("No device matches {selector}, found {devices:?}!")
+    )]
+    #[cfg_attr(not(feature = "device"), error("unreachable!"))]
+    pub struct DeviceNotFound {
+        #[cfg(feature = "device")]
+        pub(super) selector: String,
+        #[cfg(feature = "device")]
+        pub(super) devices: Vec<String>,
+    }
+
     /// The Device was lost.
     #[derive(Clone, Copy, Debug, thiserror::Error)]
     pub struct DeviceLost(
@@ -94,6 +119,9 @@ pub mod builder {
 
     impl DeviceBuilder {
         /// Index of the device, defaults to 0.
+        ///
+        /// When selecting by [`.name()`](Self::name) or [`.uuid()`](Self::uuid), the index
+        /// selects among the matching devices.
         pub fn index(self, index: usize) -> Self {
             #[cfg(feature = "device")]
             {
@@ -107,12 +135,239 @@ pub mod builder {
                 self
             }
         }
+        /// Selects devices whose name contains `name`, defaults to None.
+        ///
+        /// Indices may change when drivers are updated or devices are added, selecting by name is
+        /// stable. See [`DeviceInfo::name()`].
+        ///
+        /// # Errors
+        /// [`.build()`](DeviceBuilder::build) will fail with [`DeviceNotFound`] if no device matches.
+        pub fn name(self, name: impl Into<String>) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.name = Some(name.into());
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = name;
+                self
+            }
+        }
+        /// Selects the device with `uuid`, defaults to None.
+        ///
+        /// The uuid is the `deviceUUID` of the device, which is unique to each device, including
+        /// identical devices on the same machine. Devices that do not report a `deviceUUID`, ie
+        /// Vulkan 1.0 drivers, use the `pipelineCacheUUID` instead, which is shared by identical
+        /// devices. See [`DeviceInfo::uuid()`].
+        ///
+        /// # Errors
+        /// [`.build()`](DeviceBuilder::build) will fail with [`DeviceNotFound`] if no device matches.
+        pub fn uuid(self, uuid: [u8; 16]) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.uuid = Some(uuid);
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = uuid;
+                self
+            }
+        }
+        /// Number of frames in flight, defaults to 2.
+        ///
+        /// Work is recorded into a frame while previous frames execute on the device. More frames
+        /// allow deeper pipelining of many small transfers and dispatches, fewer frames use less
+        /// memory. Each frame holds a command pool, a descriptor pool, and keeps the buffers it uses
+        /// alive until it is finished.
+        ///
+        /// Clamped to at least 2.
+        pub fn frames(self, frames: usize) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.frames = frames.max(2);
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = frames;
+                self
+            }
+        }
+        /// Number of staging buffers used for transfers between host and device, defaults to 2.
+        ///
+        /// Uploads and downloads are split into chunks, each copied through a staging buffer.
+        /// Downloads keep up to `staging_buffers` chunks in flight, copying each chunk to the host
+        /// once its device copy has finished. Uploads only wait for a staging buffer to be reused, so
+        /// the host copy of one chunk may overlap the device copy of the previous chunk, but the
+        /// upload returns once the last chunk is recorded. More staging buffers may increase
+        /// throughput for large downloads.
+        ///
+        /// Each staging buffer is 32 MB of host visible memory, allocated when the device is created.
+        ///
+        /// Clamped to at least 2.
+        pub fn staging_buffers(self, staging_buffers: usize) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.staging_buffers = staging_buffers.max(2);
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = staging_buffers;
+                self
+            }
+        }
+        /// Include software devices, defaults to true.
+        ///
+        /// Software implementations like [lavapipe](https://docs.mesa3d.org/drivers/llvmpipe.html)
+        /// allow running kernels without a GPU, ie in CI. When false, software devices are skipped
+        /// and [`.index()`](Self::index) selects among the remaining devices.
+        pub fn allow_software(self, allow_software: bool) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.allow_software = allow_software;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = allow_software;
+                self
+            }
+        }
+        /// Expected number of kernels, defaults to 0.
+        ///
+        /// Kernels are compiled once per device and cached. Reserving capacity avoids rehashing the
+        /// cache when many kernels are built, potentially from multiple threads.
+        ///
+        /// Building the same kernel concurrently from multiple threads compiles it only once, other
+        /// threads block until it is inserted into the cache.
+        pub fn kernel_cache_capacity(self, kernel_cache_capacity: usize) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.kernel_cache_capacity = kernel_cache_capacity;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = kernel_cache_capacity;
+                self
+            }
+        }
+        /// Directory to persist compiled kernels, defaults to None.
+        ///
+        /// Kernels are compiled by the driver when they are built, which is repeated for each process.
+        /// With a directory, the driver's pipeline cache is loaded when the device is created and saved
+        /// when the device is dropped, if any kernels were built, so that later runs can skip
+        /// compilation. The cache is specific to the device and driver version, a driver update will
+        /// start a new cache.
+        ///
+        /// # Errors
+        /// [`.build()`](DeviceBuilder::build) will fail if the directory can't be created.
+        pub fn pipeline_cache_dir(self, pipeline_cache_dir: impl Into<PathBuf>) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.pipeline_cache_dir = Some(pipeline_cache_dir.into());
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = pipeline_cache_dir;
+                self
+            }
+        }
+        /// Priority of the compute queue, between 0.0 and 1.0, defaults to 1.0.
+        ///
+        /// Lower priorities allow other work on the device, ie rendering, to be scheduled ahead of
+        /// kernels. How priorities are applied is implementation dependent.
+        ///
+        /// # Errors
+        /// [`.build()`](DeviceBuilder::build) will fail if `queue_priority` is not in the range 0.0 ..= 1.0.
+        pub fn queue_priority(self, queue_priority: f32) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.queue_priority = queue_priority;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = queue_priority;
+                self
+            }
+        }
+        /// Interval between polls of the worker thread while work is running, defaults to 0.
+        ///
+        /// The worker thread submits work to the device and waits for it to finish. When nothing is
+        /// running it blocks until work is queued. While work is running it waits for the oldest
+        /// frame to finish, checking for new work every `poll_interval`, or every 50 microseconds if
+        /// it is 0. A longer interval wakes the worker less often, adding up to `poll_interval` of
+        /// latency before new work is submitted.
+        pub fn poll_interval(self, poll_interval: Duration) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.poll_interval = poll_interval;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = poll_interval;
+                self
+            }
+        }
+        /// Enables the validation layer with [DebugPrintf](crate::kernel#debugprintf), defaults to false.
+        ///
+        /// This is an alternative to enabling the layer via environment variables, ie `VK_INSTANCE_LAYERS`.
+        /// Kernels must also be compiled with `krnlc --debug-printf`.
+        ///
+        /// Creating the device will fail if the validation layer is not installed.
+        pub fn enable_debug_printf(self) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.enable_debug_printf = true;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                self
+            }
+        }
+        /// Restricts the features of the device to `features`, defaults to [`Features::all()`].
+        ///
+        /// Features not in `features` are not enabled, and are not reported by [`DeviceInfo::features()`].
+        /// This can be used to simulate a less capable device, ie to test that kernels fail to build
+        /// when features are missing.
+        pub fn mask_features(self, features: Features) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.optimal_features =
+                    this.options.optimal_features.intersection(features);
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = features;
+                self
+            }
+        }
         /// Creates a device.
         ///
         /// # Errors
         ///
         /// - [DeviceUnavailable]
         /// - [DeviceIndexOutOfRange]
+        /// - [DeviceNotFound]
         /// - The device could not be created.
         pub fn build(self) -> Result<Device> {
             #[cfg(feature = "device")]
@@ -127,6 +382,43 @@ pub mod builder {
                 Err(DeviceUnavailable.into())
             }
         }
+        /// Creates all devices.
+        ///
+        /// Options other than [`.index()`](Self::index) apply to each device. Devices that can't
+        /// be created are skipped silently, the returned devices may not be contiguous in index.
+        /// Use [`Device::list()`] and [`.index()`](Self::index) to inspect why a device could not be
+        /// created.
+        ///
+        /// # Errors
+        ///
+        /// - [DeviceUnavailable]
+        pub fn build_all(self) -> Result<Vec<Device>> {
+            #[cfg(feature = "device")]
+            {
+                let mut devices = Vec::new();
+                for index in 0.. {
+                    let options = DeviceOptions {
+                        index,
+                        ..self.options.clone()
+                    };
+                    match RawDevice::new(options) {
+                        Ok(raw) => devices.push(Device {
+                            inner: DeviceInner::Device(raw),
+                        }),
+                        Err(e) if e.is::<DeviceIndexOutOfRange>() || e.is::<DeviceNotFound>() => {
+                            break
+                        }
+                        Err(e) if e.is::<DeviceUnavailable>() => return Err(e),
+                        Err(_) => (),
+                    }
+                }
+                Ok(devices)
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                Err(DeviceUnavailable.into())
+            }
+        }
     }
 }
 use builder::*;
@@ -136,15 +428,35 @@ trait DeviceEngine {
     type DeviceBuffer: DeviceEngineBuffer<Engine = Self>;
     type Kernel: DeviceEngineKernel<Engine = Self, DeviceBuffer = Self::DeviceBuffer>;
     fn new(options: DeviceOptions) -> Result<Arc<Self>>;
+    fn list() -> Result<Vec<AvailableDevice>>;
     fn id(&self) -> DeviceId;
     fn info(&self) -> &Arc<DeviceInfo>;
     fn wait(&self) -> Result<(), DeviceLost>;
+    fn epoch(&self) -> u64;
+    fn wait_epoch(&self, epoch: u64) -> Result<(), DeviceLost>;
+    fn poll_epoch(&self, epoch: u64, waker: &Waker) -> Poll<Result<(), DeviceLost>>;
+    fn live_allocations(&self) -> Vec<LiveAllocation>;
+    fn clear_kernel_cache(&self);
+    fn begin_batch(&self);
+    fn end_batch(&self);
+    fn memory_usage(&self) -> MemoryUsage;
 }
 
 #[cfg(feature = "device")]
+#[derive(Clone)]
 struct DeviceOptions {
     index: usize,
+    name: Option<String>,
+    uuid: Option<[u8; 16]>,
     optimal_features: Features,
+    frames: usize,
+    staging_buffers: usize,
+    enable_debug_printf: bool,
+    kernel_cache_capacity: usize,
+    pipeline_cache_dir: Option<PathBuf>,
+    allow_software: bool,
+    poll_interval: Duration,
+    queue_priority: f32,
 }
 
 #[cfg(feature = "device")]
@@ -153,27 +465,41 @@ trait DeviceEngineBuffer: Sized {
     unsafe fn uninit(engine: Arc<Self::Engine>, len: usize) -> Result<Self>;
     fn upload(&self, data: &[u8]) -> Result<()>;
     fn download(&self, data: &mut [u8]) -> Result<()>;
+    fn download_async<D: AsMut<[u8]> + Unpin + Send + 'static>(
+        self: &Arc<Self>,
+        data: D,
+    ) -> Pin<Box<dyn Future<Output = Result<D>> + Send>>;
+    fn peek(&self, data: &mut [u8]) -> Result<()>;
     fn transfer(&self, dst: &Self) -> Result<()>;
+    fn fill(&self, data: u32) -> Result<()>;
     fn engine(&self) -> &Arc<Self::Engine>;
     fn offset(&self) -> usize;
     fn len(&self) -> usize;
     fn slice(self: &Arc<Self>, range: Range<usize>) -> Option<Arc<Self>>;
+    fn vulkan_buffer(&self) -> Option<ash::vk::Buffer>;
 }
 
 #[cfg(feature = "device")]
 trait DeviceEngineKernel: Sized {
     type Engine;
     type DeviceBuffer;
+    type DescriptorSet;
     fn cached(
         engine: Arc<Self::Engine>,
         key: KernelKey,
         desc_fn: impl FnOnce() -> Result<Arc<KernelDesc>>,
     ) -> Result<Arc<Self>>;
+    fn descriptor_set(
+        &self,
+        buffers: &[Arc<Self::DeviceBuffer>],
+    ) -> Result<Arc<Self::DescriptorSet>>;
     unsafe fn dispatch(
         &self,
-        groups: u32,
+        groups: [u32; 3],
         buffers: &[Arc<Self::DeviceBuffer>],
+        descriptor_set: Option<&Arc<Self::DescriptorSet>>,
         push_consts: Vec<u8>,
+        cancelled: Option<&AtomicBool>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) -> Result<()>;
     fn engine(&self) -> &Arc<Self::Engine>;
@@ -213,10 +539,49 @@ impl Device {
             #[cfg(feature = "device")]
             options: DeviceOptions {
                 index: 0,
+                name: None,
+                uuid: None,
                 optimal_features: Features::all(),
+                frames: 2,
+                staging_buffers: 2,
+                enable_debug_printf: false,
+                kernel_cache_capacity: 0,
+                pipeline_cache_dir: None,
+                allow_software: true,
+                poll_interval: Duration::ZERO,
+                queue_priority: 1.,
             },
         }
     }
+    /** Lists the devices that can be built, without creating them.
+
+    The [`index`](AvailableDevice::index) of each device can be passed to
+    [`DeviceBuilder::index()`](builder::DeviceBuilder::index).
+
+    # Errors
+
+    - [DeviceUnavailable]
+
+    **Example**
+    ```no_run
+    # use krnl::{anyhow::Result, device::Device};
+    # fn main() -> Result<()> {
+    for device in Device::list()? {
+        println!("{}: {} {:?}", device.index(), device.name(), device.device_type());
+    }
+    # Ok(())
+    # }
+    ``` */
+    pub fn list() -> Result<Vec<AvailableDevice>> {
+        #[cfg(feature = "device")]
+        {
+            Engine::list()
+        }
+        #[cfg(not(feature = "device"))]
+        {
+            Err(DeviceUnavailable.into())
+        }
+    }
     /// Is the host.
     pub fn is_host(&self) -> bool {
         self.inner.is_host()
@@ -253,6 +618,200 @@ impl Device {
             DeviceInner::Device(raw) => raw.wait(),
         }
     }
+    /** Buffers allocated on the device that have not been dropped.
+
+    Allocations are only tracked in debug builds, otherwise this returns an empty Vec. Backtraces
+    are captured with [`Backtrace::capture()`](std::backtrace::Backtrace::capture), set `RUST_BACKTRACE=1`
+    to enable them.
+
+    This can be used to find leaks, ie when device memory usage keeps growing. The host returns
+    an empty Vec. */
+    pub fn live_allocations(&self) -> Vec<LiveAllocation> {
+        match self.inner() {
+            DeviceInner::Host => Vec::new(),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.live_allocations(),
+        }
+    }
+    /** Clears the kernel cache.
+
+    Kernels are cached per device, keyed by kernel, threads and spec constants. Kernels that are
+    built with many different specializations, ie when autotuning, can accumulate compiled pipelines.
+    This drops the cached pipelines, reclaiming memory once kernels that still use them are dropped.
+
+    Subsequent calls to `.build()` will recompile the kernel. If host, this does nothing. */
+    pub fn clear_kernel_cache(&self) {
+        match self.inner() {
+            DeviceInner::Host => (),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.clear_kernel_cache(),
+        }
+    }
+    /** Records work in `f` into as few submissions as possible.
+
+    Kernels and transfers are recorded into frames, which are normally submitted as soon as the device
+    is idle. Within a batch, the frame is held until it is full or waited on, so a sequence of dependent
+    kernels can be submitted once, with barriers between them. Batches may be nested or used from
+    multiple threads. A batch only holds back work from threads that are in a batch, work from other
+    threads submits the frame as usual.
+
+    If host, this just calls `f`.
+
+    **Example**
+    ```no_run
+    # use krnl::{anyhow::Result, buffer::Buffer, device::Device};
+    # fn main() -> Result<()> {
+    let device = Device::builder().build()?;
+    let x = Buffer::<f32>::zeros(device.clone(), 1024)?;
+    let z = device.batch(|| -> Result<_> {
+        let y = x.cast::<u32>()?;
+        y.cast::<f32>()
+    })?;
+    # Ok(())
+    # }
+    ``` */
+    pub fn batch<R>(&self, f: impl FnOnce() -> R) -> R {
+        match self.inner() {
+            DeviceInner::Host => f(),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.batch(f),
+        }
+    }
+    /** Device memory usage.
+
+    Unlike [`.live_allocations()`](Device::live_allocations), this is tracked in release builds.
+    Returns [`None`] if host. */
+    pub fn memory_usage(&self) -> Option<MemoryUsage> {
+        match self.inner() {
+            DeviceInner::Host => None,
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => Some(raw.memory_usage()),
+        }
+    }
+}
+
+/// Device memory usage, see [`Device::memory_usage()`].
+#[derive(Clone, Copy, Debug)]
+pub struct MemoryUsage {
+    allocated: u64,
+    reserved: Option<u64>,
+    capacity: u64,
+}
+
+impl MemoryUsage {
+    #[cfg(feature = "device")]
+    pub(crate) fn new(allocated: u64, reserved: Option<u64>, capacity: u64) -> Self {
+        Self {
+            allocated,
+            reserved,
+            capacity,
+        }
+    }
+    /// Bytes allocated for buffers that have not been dropped.
+    pub fn allocated(&self) -> u64 {
+        self.allocated
+    }
+    /** Bytes of device memory used by the process, including memory reserved by the allocator
+    and staging buffers.
+
+    Requires VK_EXT_memory_budget, otherwise [`None`]. */
+    pub fn reserved(&self) -> Option<u64> {
+        self.reserved
+    }
+    /// The size of the device local memory heaps in bytes.
+    pub fn capacity(&self) -> u64 {
+        self.capacity
+    }
+}
+
+/// The type of a device, see [`AvailableDevice::device_type()`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceType {
+    /// A discrete gpu.
+    DiscreteGpu,
+    /// A gpu integrated with the cpu.
+    IntegratedGpu,
+    /// A virtual gpu, ie in a virtual machine.
+    VirtualGpu,
+    /// A software implementation running on the cpu.
+    Cpu,
+    /// Other.
+    Other,
+}
+
+/// A device that can be built, see [`Device::list()`].
+#[derive(Clone, Debug)]
+pub struct AvailableDevice {
+    index: usize,
+    name: String,
+    uuid: [u8; 16],
+    device_type: DeviceType,
+    features: Features,
+    memory_heaps: Vec<u64>,
+    device_local_memory: u64,
+}
+
+impl AvailableDevice {
+    /// The index to pass to [`DeviceBuilder::index()`](builder::DeviceBuilder::index), when
+    /// not selecting by name or uuid.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// The name of the device.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The `deviceUUID` of the device.
+    ///
+    /// See [`DeviceBuilder::uuid()`](builder::DeviceBuilder::uuid).
+    pub fn uuid(&self) -> [u8; 16] {
+        self.uuid
+    }
+    /// The type of the device.
+    pub fn device_type(&self) -> DeviceType {
+        self.device_type
+    }
+    /** Features supported by the device.
+
+    A device built with the default optimal features will have these features, see
+    [`DeviceInfo::features()`]. */
+    pub fn features(&self) -> Features {
+        self.features
+    }
+    /// The sizes of the memory heaps in bytes.
+    pub fn memory_heaps(&self) -> &[u64] {
+        &self.memory_heaps
+    }
+    /// The size of the device local memory heaps in bytes.
+    pub fn device_local_memory(&self) -> u64 {
+        self.device_local_memory
+    }
+}
+
+/// A buffer allocation, see [`Device::live_allocations()`].
+#[derive(Clone, Debug)]
+pub struct LiveAllocation {
+    bytes: usize,
+    backtrace: Arc<Backtrace>,
+}
+
+impl LiveAllocation {
+    #[cfg(feature = "device")]
+    pub(crate) fn new(bytes: usize) -> Self {
+        Self {
+            bytes,
+            backtrace: Arc::new(Backtrace::capture()),
+        }
+    }
+    /// The size of the allocation in bytes.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+    /// Where the buffer was allocated.
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
 }
 
 /// See [`Device::host()`].
@@ -325,6 +884,38 @@ impl RawDevice {
     pub(crate) fn wait(&self) -> Result<(), DeviceLost> {
         self.engine.wait()
     }
+    // The epoch of the most recently queued work.
+    pub(crate) fn epoch(&self) -> u64 {
+        self.engine.epoch()
+    }
+    pub(crate) fn wait_epoch(&self, epoch: u64) -> Result<(), DeviceLost> {
+        self.engine.wait_epoch(epoch)
+    }
+    pub(crate) fn poll_epoch(&self, epoch: u64, waker: &Waker) -> Poll<Result<(), DeviceLost>> {
+        self.engine.poll_epoch(epoch, waker)
+    }
+    pub(crate) fn live_allocations(&self) -> Vec<LiveAllocation> {
+        self.engine.live_allocations()
+    }
+    pub(crate) fn clear_kernel_cache(&self) {
+        self.engine.clear_kernel_cache();
+    }
+    pub(crate) fn memory_usage(&self) -> MemoryUsage {
+        self.engine.memory_usage()
+    }
+    pub(crate) fn batch<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct BatchGuard<'a>(&'a Engine);
+
+        impl Drop for BatchGuard<'_> {
+            fn drop(&mut self) {
+                self.0.end_batch();
+            }
+        }
+
+        self.engine.begin_batch();
+        let _guard = BatchGuard(&self.engine);
+        f()
+    }
 }
 
 #[cfg(feature = "device")]
@@ -389,9 +980,21 @@ impl DeviceBuffer {
     pub(crate) fn download(&self, data: &mut [u8]) -> Result<()> {
         self.inner.download(data)
     }
+    pub(crate) fn download_async<D: AsMut<[u8]> + Unpin + Send + 'static>(
+        &self,
+        data: D,
+    ) -> impl Future<Output = Result<D>> + Send + 'static {
+        self.inner.download_async(data)
+    }
+    pub(crate) fn peek(&self, data: &mut [u8]) -> Result<()> {
+        self.inner.peek(data)
+    }
     pub(crate) fn transfer(&self, dst: &Self) -> Result<()> {
         self.inner.transfer(&dst.inner)
     }
+    pub(crate) fn fill(&self, data: u32) -> Result<()> {
+        self.inner.fill(data)
+    }
     pub(crate) fn offset(&self) -> usize {
         self.inner.offset()
     }
@@ -407,6 +1010,9 @@ impl DeviceBuffer {
         let inner = self.inner.slice(range)?;
         Some(Self { inner })
     }
+    pub(crate) fn vulkan_buffer(&self) -> Option<ash::vk::Buffer> {
+        self.inner.vulkan_buffer()
+    }
 }
 
 /** Features supported by a device.
@@ -509,9 +1115,13 @@ impl Features {
     pub const SUBGROUP_QUAD: Self = Self::new(1 << 23);
 
     #[inline]
-    const fn new(bits: u32) -> Self {
+    pub(crate) const fn new(bits: u32) -> Self {
         Self { bits }
     }
+    #[inline]
+    pub(crate) const fn bits(&self) -> u32 {
+        self.bits
+    }
     /// No features.
     #[inline]
     pub const fn empty() -> Self {
@@ -549,6 +1159,11 @@ impl Features {
     pub const fn union(self, other: Self) -> Self {
         Self::new(self.bits | other.bits)
     }
+    /// Features in both `self` and `other`.
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self::new(self.bits & other.bits)
+    }
     fn name_iter(&self) -> impl Iterator<Item = &str> {
         macro_rules! features {
             ($($f:ident),*) => {
@@ -797,25 +1412,84 @@ impl Features {
 pub struct DeviceInfo {
     index: usize,
     name: String,
+    uuid: [u8; 16],
     device_id: u32,
     vendor_id: u32,
+    driver_version: u32,
+    api_version: (u32, u32, u32),
     max_groups: u32,
+    max_groups_3d: [u32; 3],
     max_threads: u32,
+    max_push_constants_size: u32,
+    max_group_memory_size: u32,
     min_subgroup_threads: u32,
     max_subgroup_threads: u32,
+    compute_queues: usize,
+    transfer_queues: usize,
     features: Features,
     debug_printf: bool,
 }
 
 impl DeviceInfo {
-    /// Max groups per kernel dispatch.
+    /// The index of the device.
+    ///
+    /// When selected by [name](builder::DeviceBuilder::name) or
+    /// [uuid](builder::DeviceBuilder::uuid), this is the index among the matching devices.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// The name of the device.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The `deviceUUID` of the device.
+    ///
+    /// See [`DeviceBuilder::uuid()`](builder::DeviceBuilder::uuid).
+    pub fn uuid(&self) -> [u8; 16] {
+        self.uuid
+    }
+    /// The device id.
+    pub fn device_id(&self) -> u32 {
+        self.device_id
+    }
+    /// The vendor id.
+    pub fn vendor_id(&self) -> u32 {
+        self.vendor_id
+    }
+    /// The driver version.
+    ///
+    /// The encoding is vendor specific.
+    pub fn driver_version(&self) -> u32 {
+        self.driver_version
+    }
+    /// The Vulkan version supported by the device, as (major, minor, patch).
+    pub fn api_version(&self) -> (u32, u32, u32) {
+        self.api_version
+    }
+    /// Max groups per kernel dispatch, from `maxComputeWorkGroupCount`.
     pub fn max_groups(&self) -> u32 {
         self.max_groups
     }
-    /// Max threads per group.
+    /// Max groups per kernel dispatch in each dimension, from `maxComputeWorkGroupCount`.
+    pub fn max_groups_3d(&self) -> [u32; 3] {
+        self.max_groups_3d
+    }
+    /// Max threads per group, from `maxComputeWorkGroupSize` and `maxComputeWorkGroupInvocations`.
     pub fn max_threads(&self) -> u32 {
         self.max_threads
     }
+    /// Max size of push constants in bytes.
+    ///
+    /// At least 128.
+    pub fn max_push_constants_size(&self) -> u32 {
+        self.max_push_constants_size
+    }
+    /// Max size of group memory in bytes, from `maxComputeSharedMemorySize`.
+    ///
+    /// At least 16384.
+    pub fn max_group_memory_size(&self) -> u32 {
+        self.max_group_memory_size
+    }
     /// Min threads per subgroup.
     ///
     /// Power of 2 between 1 and 128.
@@ -828,6 +1502,18 @@ impl DeviceInfo {
     pub fn max_subgroup_threads(&self) -> u32 {
         self.max_subgroup_threads
     }
+    /// Number of queues in the compute queue family.
+    ///
+    /// Kernels and transfers are currently submitted to one of these queues.
+    pub fn compute_queues(&self) -> usize {
+        self.compute_queues
+    }
+    /// Number of queues in dedicated transfer queue families, without compute or graphics.
+    ///
+    /// Currently unused, transfers are recorded on the compute queue.
+    pub fn transfer_queues(&self) -> usize {
+        self.transfer_queues
+    }
     /// Device features.
     pub fn features(&self) -> Features {
         self.features
@@ -836,8 +1522,10 @@ impl DeviceInfo {
     pub fn default_threads(&self) -> u32 {
         256.min(self.max_threads)
     }
-    #[allow(dead_code)]
-    pub(crate) fn debug_printf(&self) -> bool {
+    /// Whether [DebugPrintf](crate::kernel#debugprintf) is enabled.
+    ///
+    /// See [`DeviceBuilder::enable_debug_printf()`](builder::DeviceBuilder::enable_debug_printf).
+    pub fn debug_printf(&self) -> bool {
         self.debug_printf
     }
 }
@@ -859,18 +1547,27 @@ impl RawKernel {
             inner: <Engine as DeviceEngine>::Kernel::cached(device.engine, key, desc_fn)?,
         })
     }
+    pub(crate) fn descriptor_set(&self, buffers: &[DeviceBuffer]) -> Result<RawDescriptorSet> {
+        Ok(RawDescriptorSet {
+            inner: self.inner.descriptor_set(cast_device_buffers(buffers))?,
+        })
+    }
     pub(crate) unsafe fn dispatch(
         &self,
-        groups: u32,
+        groups: [u32; 3],
         buffers: &[DeviceBuffer],
+        descriptor_set: Option<&RawDescriptorSet>,
         push_consts: Vec<u8>,
+        cancelled: Option<&AtomicBool>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) -> Result<()> {
         unsafe {
             self.inner.dispatch(
                 groups,
                 cast_device_buffers(buffers),
+                descriptor_set.map(|x| &x.inner),
                 push_consts,
+                cancelled,
                 debug_printf_panic,
             )
         }
@@ -884,3 +1581,9 @@ impl RawKernel {
         self.inner.desc()
     }
 }
+
+#[cfg(feature = "device")]
+#[derive(Clone)]
+pub(crate) struct RawDescriptorSet {
+    inner: Arc<<<Engine as DeviceEngine>::Kernel as DeviceEngineKernel>::DescriptorSet>,
+}
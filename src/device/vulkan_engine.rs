@@ -1,21 +1,33 @@
 use super::{
-    error::{DeviceIndexOutOfRange, DeviceUnavailable, OutOfDeviceMemory},
-    DeviceEngine, DeviceEngineBuffer, DeviceEngineKernel, DeviceId, DeviceInfo, DeviceLost,
-    DeviceOptions, Features, KernelDesc, KernelKey,
+    error::{DeviceIndexOutOfRange, DeviceNotFound, DeviceUnavailable, OutOfDeviceMemory},
+    AvailableDevice, DeviceEngine, DeviceEngineBuffer, DeviceEngineKernel, DeviceId, DeviceInfo,
+    DeviceLost, DeviceOptions, DeviceType, Features, KernelDesc, KernelKey, LiveAllocation,
+    MemoryUsage as DeviceMemoryUsage,
 };
 
-use anyhow::{Error, Result};
+use anyhow::{bail, Context as _, Error, Result};
 use ash::vk::Handle;
 use crossbeam_channel::{Receiver, Sender};
 use dashmap::DashMap;
-use parking_lot::Mutex;
+#[cfg(debug_assertions)]
+use fxhash::FxHashMap;
+use parking_lot::{Condvar, Mutex};
 use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    future::Future,
+    hash::{Hash, Hasher},
     mem::MaybeUninit,
     ops::Range,
+    path::PathBuf,
+    pin::Pin,
     sync::{
-        atomic::{AtomicBool, AtomicU64, Ordering},
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
         Arc,
     },
+    task::{ready, Context, Poll, Waker},
+    thread::JoinHandle,
+    time::Duration,
 };
 use vulkano::{
     buffer::{Buffer, BufferCreateInfo, BufferUsage, Subbuffer},
@@ -27,15 +39,17 @@ use vulkano::{
     descriptor_set::{
         layout::{DescriptorSetLayout, DescriptorType},
         pool::{DescriptorPool, DescriptorPoolCreateInfo, DescriptorSetAllocateInfo},
+        sys::UnsafeDescriptorSet,
         WriteDescriptorSet,
     },
     device::{
-        Device, DeviceCreateInfo, DeviceOwned, Queue, QueueCreateInfo, QueueFlags, QueueGuard,
+        physical::PhysicalDeviceType, Device, DeviceCreateInfo, DeviceOwned, Queue,
+        QueueCreateInfo, QueueFlags, QueueGuard,
     },
     instance::{
         debug::{
             DebugUtilsMessageSeverity, DebugUtilsMessageType, DebugUtilsMessenger,
-            DebugUtilsMessengerCreateInfo,
+            DebugUtilsMessengerCreateInfo, ValidationFeatureEnable,
         },
         Instance, InstanceCreateInfo, InstanceExtensions, Version,
     },
@@ -44,12 +58,12 @@ use vulkano::{
         AllocationCreateInfo, GenericMemoryAllocatorCreateInfo, MemoryUsage,
         StandardMemoryAllocator,
     },
-    pipeline::{ComputePipeline, Pipeline, PipelineBindPoint},
+    pipeline::{cache::PipelineCache, ComputePipeline, Pipeline, PipelineBindPoint},
     shader::{
         DescriptorBindingRequirements, DescriptorRequirements, ShaderExecution, ShaderInterface,
         ShaderModule, ShaderStages,
     },
-    sync::semaphore::Semaphore,
+    sync::{semaphore::Semaphore, AccessFlags, DependencyInfo, MemoryBarrier, PipelineStages},
     VulkanObject,
 };
 
@@ -61,11 +75,26 @@ pub struct Engine {
     frame_outer: Mutex<FrameOuter>,
     host_buffer_sender: Sender<HostBuffer>,
     host_buffer_receiver: Receiver<HostBuffer>,
+    staging_buffers: usize,
     kernels: DashMap<KernelKey, KernelInner>,
+    pipeline_cache: EnginePipelineCache,
     memory_allocator: Arc<StandardMemoryAllocator>,
     queue: Arc<Queue>,
     engine_exited: Arc<AtomicBool>,
     worker_exited: Arc<AtomicBool>,
+    worker_handle: Option<JoinHandle<()>>,
+    batches: Arc<AtomicUsize>,
+    flush: Arc<AtomicBool>,
+    submitted: Arc<Signal>,
+    queued: Arc<Signal>,
+    wakers: Arc<Mutex<Vec<(u64, Waker)>>>,
+    pending_wakers: Arc<Mutex<Vec<(u64, Waker)>>>,
+    host_buffer_wakers: Mutex<Vec<Waker>>,
+    allocated: Arc<AtomicU64>,
+    #[cfg(debug_assertions)]
+    allocations: Arc<Mutex<FxHashMap<u64, LiveAllocation>>>,
+    #[cfg(debug_assertions)]
+    next_allocation_id: AtomicU64,
     _instance: Arc<Instance>,
 }
 
@@ -74,26 +103,63 @@ impl Engine {
         &self,
         src: Subbuffer<[u8]>,
         dst: Subbuffer<[u8]>,
-        host_buffer: &mut HostBuffer,
+        host_buffer: Option<&mut HostBuffer>,
+        src_device_buffer: Option<&DeviceBuffer>,
         dst_device_buffer: Option<&DeviceBuffer>,
     ) -> Result<()> {
         let mut frame_outer = self.frame_outer.lock();
-        unsafe { frame_outer.transfer(&self.epoch, src, dst, host_buffer, dst_device_buffer) }
+        unsafe {
+            frame_outer.transfer(
+                &self.epoch,
+                src,
+                dst,
+                host_buffer,
+                src_device_buffer,
+                dst_device_buffer,
+            )?;
+        }
+        drop(frame_outer);
+        self.flush_unbatched();
+        Ok(())
     }
+    unsafe fn fill(
+        &self,
+        dst: Subbuffer<[u8]>,
+        data: u32,
+        dst_device_buffer: &DeviceBuffer,
+    ) -> Result<()> {
+        let mut frame_outer = self.frame_outer.lock();
+        unsafe {
+            frame_outer.fill(&self.epoch, dst, data, dst_device_buffer)?;
+        }
+        drop(frame_outer);
+        self.flush_unbatched();
+        Ok(())
+    }
+    #[allow(clippy::too_many_arguments)]
     unsafe fn compute(
         &self,
         kernel_desc: &Arc<KernelDesc>,
         pipeline: &Arc<ComputePipeline>,
-        groups: u32,
+        groups: [u32; 3],
         buffers: &[Arc<DeviceBuffer>],
+        descriptor_set: Option<&Arc<DescriptorSet>>,
         push_consts: &[u8],
+        cancelled: Option<&AtomicBool>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) -> Result<()> {
+        let is_cancelled = || cancelled.is_some_and(|x| x.load(Ordering::SeqCst));
         let mut frame_outer = self.frame_outer.lock();
-        let new_descriptors: u32 = buffers.len().try_into().unwrap();
+        let new_descriptors: u32 = if descriptor_set.is_some() || push_descriptor(pipeline) {
+            0
+        } else {
+            buffers.len().try_into().unwrap()
+        };
         if frame_outer.kernels >= Frame::MAX_KERNELS
             || frame_outer.descriptors + new_descriptors > Frame::MAX_DESCRIPTORS
         {
+            self.flush.store(true, Ordering::SeqCst);
+            self.queued.notify();
             loop {
                 if frame_outer.empty.load(Ordering::SeqCst) {
                     break;
@@ -101,9 +167,17 @@ impl Engine {
                 if self.worker_exited.load(Ordering::SeqCst) {
                     return Err(DeviceLost(self.id()).into());
                 }
-                std::hint::spin_loop();
+                if is_cancelled() {
+                    return Ok(());
+                }
+                self.submitted
+                    .wait(|| frame_outer.empty.load(Ordering::SeqCst));
             }
         }
+        // last chance to cancel, the kernel is queued below
+        if is_cancelled() {
+            return Ok(());
+        }
         unsafe {
             frame_outer.compute(
                 kernel_desc,
@@ -111,32 +185,85 @@ impl Engine {
                 pipeline,
                 groups,
                 buffers,
+                descriptor_set,
                 push_consts,
                 debug_printf_panic,
-            )
+            )?;
+        }
+        drop(frame_outer);
+        self.flush_unbatched();
+        Ok(())
+    }
+    // Batches only hold the ready frame for the threads in them, work queued by other threads
+    // submits it.
+    fn flush_unbatched(&self) {
+        if self.batches.load(Ordering::SeqCst) > 0 && !self.in_batch() {
+            self.flush.store(true, Ordering::SeqCst);
+            self.queued.notify();
+        }
+    }
+    fn in_batch(&self) -> bool {
+        let engine = self as *const Self as usize;
+        BATCHES.with(|batches| batches.borrow().contains(&engine))
+    }
+    // Submits the ready frame if it is held by a batch and has epoch.
+    fn flush(&self, epoch: u64) {
+        if self.pending.load(Ordering::SeqCst) < epoch {
+            self.flush.store(true, Ordering::SeqCst);
+            self.queued.notify();
         }
     }
     fn wait_pending(&self, epoch: u64) -> Result<(), DeviceLost> {
+        self.flush(epoch);
         while self.pending.load(Ordering::SeqCst) < epoch {
             if self.worker_exited.load(Ordering::SeqCst) {
                 return Err(DeviceLost(self.id()));
             }
-            std::hint::spin_loop();
+            self.submitted
+                .wait(|| self.pending.load(Ordering::SeqCst) >= epoch);
         }
         Ok(())
     }
-    fn wait_epoch(&self, epoch: u64) -> Result<(), DeviceLost> {
-        loop {
-            let result = unsafe { wait_semaphore(self.queue.device(), &self.semaphore, epoch) };
-            match result {
-                ash::vk::Result::SUCCESS => return Ok(()),
-                ash::vk::Result::TIMEOUT => (),
-                _ => return Err(DeviceLost(self.id())),
-            }
-            if self.worker_exited.load(Ordering::SeqCst) {
-                return Err(DeviceLost(self.id()));
-            }
-            std::hint::spin_loop();
+    // Like wait_pending, but the waker is woken by the worker when the frame is submitted.
+    fn poll_pending(&self, epoch: u64, waker: &Waker) -> Poll<Result<(), DeviceLost>> {
+        if self.pending.load(Ordering::SeqCst) >= epoch {
+            return Poll::Ready(Ok(()));
+        }
+        self.flush(epoch);
+        {
+            let mut pending_wakers = self.pending_wakers.lock();
+            pending_wakers.retain(|(_, x)| !x.will_wake(waker));
+            pending_wakers.push((epoch, waker.clone()));
+        }
+        if self.pending.load(Ordering::SeqCst) >= epoch {
+            Poll::Ready(Ok(()))
+        } else if self.worker_exited.load(Ordering::SeqCst) {
+            Poll::Ready(Err(DeviceLost(self.id())))
+        } else {
+            Poll::Pending
+        }
+    }
+    // The waker is woken when a staging buffer is returned.
+    fn poll_host_buffer(&self, waker: &Waker) -> Poll<HostBuffer> {
+        if let Ok(host_buffer) = self.host_buffer_receiver.try_recv() {
+            return Poll::Ready(host_buffer);
+        }
+        {
+            let mut host_buffer_wakers = self.host_buffer_wakers.lock();
+            host_buffer_wakers.retain(|x| !x.will_wake(waker));
+            host_buffer_wakers.push(waker.clone());
+        }
+        // registered before checking again, so a buffer returned concurrently still wakes it
+        if let Ok(host_buffer) = self.host_buffer_receiver.try_recv() {
+            Poll::Ready(host_buffer)
+        } else {
+            Poll::Pending
+        }
+    }
+    fn send_host_buffer(&self, host_buffer: HostBuffer) {
+        self.host_buffer_sender.send(host_buffer).unwrap();
+        for waker in std::mem::take(&mut *self.host_buffer_wakers.lock()) {
+            waker.wake();
         }
     }
 }
@@ -144,9 +271,13 @@ impl Engine {
 impl Drop for Engine {
     fn drop(&mut self) {
         self.engine_exited.store(true, Ordering::SeqCst);
-        while !self.worker_exited.load(Ordering::SeqCst) {}
+        self.queued.notify();
+        let worker_result = self.worker_handle.take().map(JoinHandle::join);
         let result = self.queue.with(|mut x| x.wait_idle());
         if !std::thread::panicking() {
+            if let Some(Err(panic)) = worker_result {
+                std::panic::resume_unwind(panic);
+            }
             result.unwrap();
         }
     }
@@ -158,9 +289,30 @@ impl DeviceEngine for Engine {
     fn new(options: DeviceOptions) -> anyhow::Result<std::sync::Arc<Self>> {
         let DeviceOptions {
             index,
+            name,
+            uuid,
             optimal_features,
+            frames,
+            staging_buffers,
+            enable_debug_printf,
+            kernel_cache_capacity,
+            pipeline_cache_dir,
+            allow_software,
+            poll_interval,
+            queue_priority,
         } = options;
+        if !(0. ..=1.).contains(&queue_priority) {
+            bail!("Queue priority {queue_priority} must be between 0.0 and 1.0!");
+        }
         let library = VulkanLibrary::new().map_err(|e| Error::new(DeviceUnavailable).context(e))?;
+        const VALIDATION_LAYER: &str = "VK_LAYER_KHRONOS_validation";
+        if enable_debug_printf
+            && !library
+                .layer_properties()?
+                .any(|layer| layer.name() == VALIDATION_LAYER)
+        {
+            bail!("Unable to enable DebugPrintf, {VALIDATION_LAYER} is not installed!");
+        }
         let debug_printf = Arc::new(AtomicBool::default());
         let debug_printf2 = debug_printf.clone();
         let debug_create_info = DebugUtilsMessengerCreateInfo {
@@ -181,13 +333,27 @@ impl DeviceEngine for Engine {
                 }
             }))
         };
-        let instance_create_info = InstanceCreateInfo {
-            enabled_extensions: InstanceExtensions {
-                ext_debug_utils: true,
-                ..Default::default()
-            },
-            enumerate_portability: true,
-            ..InstanceCreateInfo::application_from_cargo_toml()
+        let instance_create_info = if enable_debug_printf {
+            InstanceCreateInfo {
+                enabled_extensions: InstanceExtensions {
+                    ext_debug_utils: true,
+                    ext_validation_features: true,
+                    ..Default::default()
+                },
+                enabled_layers: vec![VALIDATION_LAYER.to_string()],
+                enabled_validation_features: vec![ValidationFeatureEnable::DebugPrintf],
+                enumerate_portability: true,
+                ..InstanceCreateInfo::application_from_cargo_toml()
+            }
+        } else {
+            InstanceCreateInfo {
+                enabled_extensions: InstanceExtensions {
+                    ext_debug_utils: true,
+                    ..Default::default()
+                },
+                enumerate_portability: true,
+                ..InstanceCreateInfo::application_from_cargo_toml()
+            }
         };
         let instance = unsafe {
             Instance::with_debug_utils_messengers(
@@ -196,17 +362,61 @@ impl DeviceEngine for Engine {
                 [debug_create_info],
             )?
         };
-        let debug_printf = debug_printf.load(Ordering::SeqCst);
-        let mut physical_devices = instance.enumerate_physical_devices()?;
+        let debug_printf = enable_debug_printf || debug_printf.load(Ordering::SeqCst);
+        let physical_devices: Vec<_> = instance
+            .enumerate_physical_devices()?
+            .filter(|x| allow_software || x.properties().device_type != PhysicalDeviceType::Cpu)
+            .collect();
+        let matches = |properties: &vulkano::device::Properties| {
+            name.as_ref()
+                .map_or(true, |name| properties.device_name.contains(name.as_str()))
+                && uuid.map_or(true, |uuid| device_uuid(properties) == uuid)
+        };
+        let mut physical_devices = if name.is_some() || uuid.is_some() {
+            let (matching, other): (Vec<_>, Vec<_>) = physical_devices
+                .into_iter()
+                .partition(|x| matches(x.properties()));
+            if matching.is_empty() {
+                let mut selector = Vec::new();
+                if let Some(name) = name.as_ref() {
+                    selector.push(format!("name {name:?}"));
+                }
+                if let Some(uuid) = uuid.as_ref() {
+                    selector.push(format!("uuid {}", uuid_string(uuid)));
+                }
+                let devices = other
+                    .iter()
+                    .map(|x| {
+                        let properties = x.properties();
+                        format!(
+                            "{} ({})",
+                            properties.device_name,
+                            uuid_string(&device_uuid(properties))
+                        )
+                    })
+                    .collect();
+                return Err(DeviceNotFound {
+                    selector: selector.join(" and "),
+                    devices,
+                }
+                .into());
+            }
+            matching
+        } else {
+            physical_devices
+        };
         let devices = physical_devices.len();
-        let physical_device = if let Some(physical_device) = physical_devices.nth(index) {
-            physical_device
+        let physical_device = if index < devices {
+            physical_devices.swap_remove(index)
         } else {
             return Err(DeviceIndexOutOfRange { index, devices }.into());
         };
         let name = physical_device.properties().device_name.clone();
+        let uuid = device_uuid(physical_device.properties());
         let optimal_device_extensions = vulkano::device::DeviceExtensions {
             khr_vulkan_memory_model: true,
+            khr_push_descriptor: true,
+            ext_memory_budget: true,
             ext_subgroup_size_control: true,
             ..vulkano::device::DeviceExtensions::empty()
         };
@@ -246,9 +456,22 @@ impl DeviceEngine for Engine {
             })
             .map(|x| x as u32)
             .unwrap();
+        let queue_family_properties = physical_device.queue_family_properties();
+        let compute_queues = queue_family_properties[compute_family as usize].queue_count;
+        // families supporting transfers, but not compute or graphics
+        let transfer_queues = queue_family_properties
+            .iter()
+            .filter(|x| {
+                x.queue_flags.contains(QueueFlags::TRANSFER)
+                    && !x
+                        .queue_flags
+                        .intersects(QueueFlags::COMPUTE | QueueFlags::GRAPHICS)
+            })
+            .map(|x| x.queue_count)
+            .sum::<u32>();
         let queue_create_infos = vec![QueueCreateInfo {
             queue_family_index: compute_family,
-            queues: vec![1f32],
+            queues: vec![queue_priority],
             ..Default::default()
         }];
         let (device, mut queues) = Device::new(
@@ -272,8 +495,9 @@ impl DeviceEngine for Engine {
                 ..Default::default()
             },
         )?);
-        let (host_buffer_sender, host_buffer_receiver) = crossbeam_channel::bounded(2);
-        for _ in 0..2 {
+        let (host_buffer_sender, host_buffer_receiver) =
+            crossbeam_channel::bounded(staging_buffers);
+        for _ in 0..staging_buffers {
             let buffer_info = BufferCreateInfo {
                 usage: BufferUsage::TRANSFER_SRC | BufferUsage::TRANSFER_DST,
                 ..Default::default()
@@ -296,7 +520,8 @@ impl DeviceEngine for Engine {
                 })
                 .unwrap();
         }
-        let kernels = DashMap::default();
+        let kernels = DashMap::with_capacity(kernel_cache_capacity);
+        let pipeline_cache = EnginePipelineCache::new(&device, pipeline_cache_dir)?;
         let properties = device.physical_device().properties();
         let (min_subgroup_threads, max_subgroup_threads) = if device_features.subgroup_size_control
         {
@@ -308,85 +533,54 @@ impl DeviceEngine for Engine {
             (1, 128)
         };
 
-        let mut features = Features::empty();
-        if device_features.shader_int8 {
-            features = features.union(Features::INT8);
-        }
-        if device_features.shader_int16 {
-            features = features.union(Features::INT16);
-        }
-        if device_features.shader_int64 {
-            features = features.union(Features::INT64);
-        }
-        if device_features.shader_float16 {
-            features = features.union(Features::FLOAT16);
-        }
-        if device_features.shader_float64 {
-            features = features.union(Features::FLOAT64);
-        }
-        if device_features.storage_buffer8_bit_access {
-            features = features.union(Features::BUFFER8);
-        }
-        if device_features.storage_buffer16_bit_access {
-            features = features.union(Features::BUFFER16);
-        }
-        if device_features.storage_push_constant8 {
-            features = features.union(Features::PUSH_CONSTANT8);
-        }
-        if device_features.storage_push_constant16 {
-            features = features.union(Features::PUSH_CONSTANT16);
-        }
-        if let Some(subgroup_features) = properties.subgroup_supported_operations {
-            use vulkano::device::physical::SubgroupFeatures;
-
-            if subgroup_features.contains(SubgroupFeatures::BASIC) {
-                features = features.union(Features::SUBGROUP_BASIC);
-            }
-            if subgroup_features.contains(SubgroupFeatures::VOTE) {
-                features = features.union(Features::SUBGROUP_VOTE);
-            }
-            if subgroup_features.contains(SubgroupFeatures::ARITHMETIC) {
-                features = features.union(Features::SUBGROUP_ARITHMETIC);
-            }
-            if subgroup_features.contains(SubgroupFeatures::BALLOT) {
-                features = features.union(Features::SUBGROUP_BALLOT);
-            }
-            if subgroup_features.contains(SubgroupFeatures::SHUFFLE) {
-                features = features.union(Features::SUBGROUP_SHUFFLE);
-            }
-            if subgroup_features.contains(SubgroupFeatures::SHUFFLE_RELATIVE) {
-                features = features.union(Features::SUBGROUP_SHUFFLE_RELATIVE);
-            }
-            if subgroup_features.contains(SubgroupFeatures::CLUSTERED) {
-                features = features.union(Features::SUBGROUP_CLUSTERED);
-            }
-            if subgroup_features.contains(SubgroupFeatures::QUAD) {
-                features = features.union(Features::SUBGROUP_QUAD);
-            }
-        }
+        let features = krnl_features(&device_features, properties);
+        // kernels requiring features that were not enabled will fail to build
+        let features = features.intersection(optimal_features);
         let info = Arc::new(DeviceInfo {
             index,
             name,
+            uuid,
             device_id: properties.device_id,
             vendor_id: properties.vendor_id,
+            driver_version: properties.driver_version,
+            api_version: (
+                properties.api_version.major,
+                properties.api_version.minor,
+                properties.api_version.patch,
+            ),
             max_groups: properties.max_compute_work_group_count[0],
-            max_threads: properties.max_compute_work_group_size[0],
+            max_groups_3d: properties.max_compute_work_group_count,
+            // threads per group are 1d, groups may be 3d, so threads are limited by both the x
+            // dimension and the total
+            max_threads: properties.max_compute_work_group_size[0]
+                .min(properties.max_compute_work_group_invocations),
+            max_push_constants_size: properties.max_push_constants_size,
+            max_group_memory_size: properties.max_compute_shared_memory_size,
             min_subgroup_threads,
             max_subgroup_threads,
+            compute_queues: compute_queues.try_into().unwrap(),
+            transfer_queues: transfer_queues.try_into().unwrap(),
             features,
             debug_printf,
         });
-        let mut worker = Worker::new(queue.clone(), index)?;
+        let mut worker = Worker::new(queue.clone(), index, frames, poll_interval)?;
         let semaphore = worker.semaphore.clone();
         let epoch = AtomicU64::default();
         let pending = worker.pending.clone();
         let frame_outer = Mutex::new(FrameOuter::new(
             worker.ready_frame.clone(),
             worker.empty.clone(),
+            worker.queued.clone(),
         ));
         let engine_exited = worker.engine_exited.clone();
         let worker_exited = worker.worker_exited.clone();
-        std::thread::spawn(move || worker.run());
+        let batches = worker.batches.clone();
+        let flush = worker.flush.clone();
+        let submitted = worker.submitted.clone();
+        let queued = worker.queued.clone();
+        let wakers = worker.wakers.clone();
+        let pending_wakers = worker.pending_wakers.clone();
+        let worker_handle = Some(std::thread::spawn(move || worker.run()));
         Ok(Arc::new(Self {
             info,
             semaphore,
@@ -395,14 +589,74 @@ impl DeviceEngine for Engine {
             frame_outer,
             host_buffer_sender,
             host_buffer_receiver,
+            staging_buffers,
             kernels,
+            pipeline_cache,
             memory_allocator,
             engine_exited,
             worker_exited,
+            worker_handle,
+            batches,
+            flush,
+            submitted,
+            queued,
+            wakers,
+            pending_wakers,
+            host_buffer_wakers: Mutex::default(),
+            allocated: Arc::default(),
             queue,
+            #[cfg(debug_assertions)]
+            allocations: Arc::default(),
+            #[cfg(debug_assertions)]
+            next_allocation_id: AtomicU64::default(),
             _instance: instance,
         }))
     }
+    fn list() -> Result<Vec<AvailableDevice>> {
+        let library = VulkanLibrary::new().map_err(|e| Error::new(DeviceUnavailable).context(e))?;
+        let instance = Instance::new(
+            library,
+            InstanceCreateInfo {
+                enumerate_portability: true,
+                ..InstanceCreateInfo::application_from_cargo_toml()
+            },
+        )?;
+        // indices match the default builder, which allows software devices
+        let devices = instance
+            .enumerate_physical_devices()?
+            .enumerate()
+            .map(|(index, physical_device)| {
+                let properties = physical_device.properties();
+                let device_type = match properties.device_type {
+                    PhysicalDeviceType::DiscreteGpu => DeviceType::DiscreteGpu,
+                    PhysicalDeviceType::IntegratedGpu => DeviceType::IntegratedGpu,
+                    PhysicalDeviceType::VirtualGpu => DeviceType::VirtualGpu,
+                    PhysicalDeviceType::Cpu => DeviceType::Cpu,
+                    _ => DeviceType::Other,
+                };
+                let features = krnl_features(physical_device.supported_features(), properties);
+                let memory_heaps = &physical_device.memory_properties().memory_heaps;
+                let device_local_memory = memory_heaps
+                    .iter()
+                    .filter(|x| {
+                        x.flags
+                            .intersects(vulkano::memory::MemoryHeapFlags::DEVICE_LOCAL)
+                    })
+                    .map(|x| x.size)
+                    .sum();
+                AvailableDevice {
+                    index,
+                    name: properties.device_name.clone(),
+                    uuid: device_uuid(properties),
+                    device_type,
+                    features,
+                    memory_heaps: memory_heaps.iter().map(|x| x.size).collect(),
+                    device_local_memory,
+                }
+            })
+            .collect();
+        Ok(devices)
+    }
     fn id(&self) -> DeviceId {
         let index = self.info.index;
         let handle = self.queue.device().handle().as_raw().try_into().unwrap();
@@ -412,8 +666,130 @@ impl DeviceEngine for Engine {
         &self.info
     }
     fn wait(&self) -> Result<(), DeviceLost> {
-        let epoch = self.epoch.load(Ordering::SeqCst);
-        self.wait_epoch(epoch)
+        self.wait_epoch(self.epoch())
+    }
+    fn epoch(&self) -> u64 {
+        self.epoch.load(Ordering::SeqCst)
+    }
+    fn wait_epoch(&self, epoch: u64) -> Result<(), DeviceLost> {
+        self.flush(epoch);
+        loop {
+            let result = unsafe {
+                wait_semaphore(self.queue.device(), &self.semaphore, epoch, WAIT_TIMEOUT_NS)
+            };
+            match result {
+                ash::vk::Result::SUCCESS => return Ok(()),
+                ash::vk::Result::TIMEOUT => (),
+                _ => return Err(DeviceLost(self.id())),
+            }
+            if self.worker_exited.load(Ordering::SeqCst) {
+                return Err(DeviceLost(self.id()));
+            }
+        }
+    }
+    // The waker is woken by the worker when the frame with epoch finishes.
+    fn poll_epoch(&self, epoch: u64, waker: &Waker) -> Poll<Result<(), DeviceLost>> {
+        self.flush(epoch);
+        {
+            // registered before checking, so a frame finishing concurrently still wakes it
+            let mut wakers = self.wakers.lock();
+            wakers.retain(|(_, x)| !x.will_wake(waker));
+            wakers.push((epoch, waker.clone()));
+        }
+        let result = unsafe { wait_semaphore(self.queue.device(), &self.semaphore, epoch, 0) };
+        match result {
+            ash::vk::Result::SUCCESS => Poll::Ready(Ok(())),
+            ash::vk::Result::TIMEOUT if !self.worker_exited.load(Ordering::SeqCst) => Poll::Pending,
+            _ => Poll::Ready(Err(DeviceLost(self.id()))),
+        }
+    }
+    fn live_allocations(&self) -> Vec<LiveAllocation> {
+        #[cfg(debug_assertions)]
+        {
+            self.allocations.lock().values().cloned().collect()
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            Vec::new()
+        }
+    }
+    fn clear_kernel_cache(&self) {
+        // Built kernels hold their own references to the pipeline.
+        self.kernels.clear();
+    }
+    fn begin_batch(&self) {
+        let engine = self as *const Self as usize;
+        BATCHES.with(|batches| batches.borrow_mut().push(engine));
+        self.batches.fetch_add(1, Ordering::SeqCst);
+    }
+    fn end_batch(&self) {
+        let engine = self as *const Self as usize;
+        BATCHES.with(|batches| {
+            let mut batches = batches.borrow_mut();
+            if let Some(index) = batches.iter().rposition(|x| *x == engine) {
+                batches.remove(index);
+            }
+        });
+        self.batches.fetch_sub(1, Ordering::SeqCst);
+        self.queued.notify();
+    }
+    fn memory_usage(&self) -> DeviceMemoryUsage {
+        let device = self.queue.device();
+        let physical_device = device.physical_device();
+        let memory_heaps = &physical_device.memory_properties().memory_heaps;
+        let device_local = |i: usize| {
+            memory_heaps[i]
+                .flags
+                .intersects(vulkano::memory::MemoryHeapFlags::DEVICE_LOCAL)
+        };
+        let capacity = (0..memory_heaps.len())
+            .filter(|i| device_local(*i))
+            .map(|i| memory_heaps[i].size)
+            .sum();
+        let instance = device.instance();
+        // vkGetPhysicalDeviceMemoryProperties2 is core in 1.1, otherwise provided by
+        // VK_KHR_get_physical_device_properties2
+        let get_physical_device_memory_properties2 =
+            if instance.api_version() >= vulkano::Version::V1_1 {
+                Some(instance.fns().v1_1.get_physical_device_memory_properties2)
+            } else if instance
+                .enabled_extensions()
+                .khr_get_physical_device_properties2
+            {
+                Some(
+                    instance
+                        .fns()
+                        .khr_get_physical_device_properties2
+                        .get_physical_device_memory_properties2_khr,
+                )
+            } else {
+                None
+            };
+        let reserved = if let Some(get_physical_device_memory_properties2) =
+            get_physical_device_memory_properties2
+                .filter(|_| device.enabled_extensions().ext_memory_budget)
+        {
+            let mut budget = ash::vk::PhysicalDeviceMemoryBudgetPropertiesEXT::default();
+            {
+                let mut properties =
+                    ash::vk::PhysicalDeviceMemoryProperties2::builder().push_next(&mut budget);
+                unsafe {
+                    get_physical_device_memory_properties2(
+                        physical_device.handle(),
+                        &mut *properties,
+                    );
+                }
+            }
+            Some(
+                (0..memory_heaps.len())
+                    .filter(|i| device_local(*i))
+                    .map(|i| budget.heap_usage[i])
+                    .sum(),
+            )
+        } else {
+            None
+        };
+        DeviceMemoryUsage::new(self.allocated.load(Ordering::SeqCst), reserved, capacity)
     }
 }
 
@@ -448,13 +824,19 @@ unsafe fn queue_submit(
     epoch: u64,
 ) -> Result<(), ash::vk::Result> {
     let command_buffers = &[command_buffer.handle()];
+    // previous frame may still be executing
+    let wait_semaphore_values = &[epoch - 1];
     let signal_semaphore_values = &[epoch];
     let mut semaphore_submit_info = ash::vk::TimelineSemaphoreSubmitInfo::builder()
+        .wait_semaphore_values(wait_semaphore_values)
         .signal_semaphore_values(signal_semaphore_values);
-    let signal_semaphores = &[semaphore.handle()];
+    let semaphores = &[semaphore.handle()];
+    let wait_dst_stage_mask = &[ash::vk::PipelineStageFlags::ALL_COMMANDS];
     let submit_info = ash::vk::SubmitInfo::builder()
         .command_buffers(command_buffers)
-        .signal_semaphores(signal_semaphores)
+        .wait_semaphores(semaphores)
+        .wait_dst_stage_mask(wait_dst_stage_mask)
+        .signal_semaphores(semaphores)
         .push_next(&mut semaphore_submit_info);
     let device = queue.device();
     unsafe {
@@ -469,13 +851,61 @@ unsafe fn queue_submit(
     Ok(())
 }
 
-unsafe fn wait_semaphore(device: &Device, semaphore: &Semaphore, value: u64) -> ash::vk::Result {
+thread_local! {
+    // The engines that the current thread is in a batch on, by address.
+    static BATCHES: RefCell<Vec<usize>> = RefCell::default();
+}
+
+// Threads block for at most this long before checking if the worker has exited.
+const WAIT_TIMEOUT_NS: u64 = 1_000_000;
+// The idle worker waits on pending frames for at most this long before checking for new work.
+const IDLE_POLL_NS: u64 = 50_000;
+
+unsafe fn wait_semaphore(
+    device: &Device,
+    semaphore: &Semaphore,
+    value: u64,
+    timeout_ns: u64,
+) -> ash::vk::Result {
     let semaphores = &[semaphore.handle()];
     let values = &[value];
     let semaphore_wait_info = ash::vk::SemaphoreWaitInfo::builder()
         .semaphores(semaphores)
         .values(values);
-    unsafe { (device.fns().v1_2.wait_semaphores)(device.handle(), &*semaphore_wait_info, 0) }
+    unsafe {
+        (device.fns().v1_2.wait_semaphores)(device.handle(), &*semaphore_wait_info, timeout_ns)
+    }
+}
+
+// Notified when state another thread is waiting on changes, so that it blocks instead of spinning.
+// The worker notifies `submitted` when a frame is submitted, and the engine notifies `queued` when
+// the ready frame may be submitted.
+#[derive(Default)]
+struct Signal {
+    mutex: Mutex<()>,
+    condvar: Condvar,
+}
+
+impl Signal {
+    // Blocks until notified or the timeout, unless ready.
+    fn wait(&self, ready: impl Fn() -> bool) {
+        let mut guard = self.mutex.lock();
+        if !ready() {
+            self.condvar
+                .wait_for(&mut guard, Duration::from_nanos(WAIT_TIMEOUT_NS));
+        }
+    }
+    // Blocks until notified, unless ready.
+    fn wait_notified(&self, ready: impl Fn() -> bool) {
+        let mut guard = self.mutex.lock();
+        if !ready() {
+            self.condvar.wait(&mut guard);
+        }
+    }
+    fn notify(&self) {
+        let _guard = self.mutex.lock();
+        self.condvar.notify_all();
+    }
 }
 
 struct HostBuffer {
@@ -496,15 +926,17 @@ impl Drop for HostBuffer {
 struct FrameOuter {
     frame: Arc<Mutex<Frame>>,
     empty: Arc<AtomicBool>,
+    queued: Arc<Signal>,
     kernels: u32,
     descriptors: u32,
 }
 
 impl FrameOuter {
-    fn new(frame: Arc<Mutex<Frame>>, empty: Arc<AtomicBool>) -> Self {
+    fn new(frame: Arc<Mutex<Frame>>, empty: Arc<AtomicBool>, queued: Arc<Signal>) -> Self {
         Self {
             frame,
             empty,
+            queued,
             kernels: 0,
             descriptors: 0,
         }
@@ -514,7 +946,8 @@ impl FrameOuter {
         epoch: &AtomicU64,
         src: Subbuffer<[u8]>,
         dst: Subbuffer<[u8]>,
-        host_buffer: &mut HostBuffer,
+        host_buffer: Option<&mut HostBuffer>,
+        src_device_buffer: Option<&DeviceBuffer>,
         dst_device_buffer: Option<&DeviceBuffer>,
     ) -> Result<()> {
         let mut frame = self.frame.lock();
@@ -526,9 +959,33 @@ impl FrameOuter {
             }
             epoch.store(frame.epoch, Ordering::SeqCst);
             self.empty.store(false, Ordering::SeqCst);
+            self.queued.notify();
         }
         unsafe {
-            frame.transfer(src, dst, host_buffer, dst_device_buffer);
+            frame.transfer(src, dst, host_buffer, src_device_buffer, dst_device_buffer);
+        }
+        Ok(())
+    }
+    unsafe fn fill(
+        &mut self,
+        epoch: &AtomicU64,
+        dst: Subbuffer<[u8]>,
+        data: u32,
+        dst_device_buffer: &DeviceBuffer,
+    ) -> Result<()> {
+        let mut frame = self.frame.lock();
+        if frame.command_buffer_builder.is_none() {
+            self.kernels = 0;
+            self.descriptors = 0;
+            unsafe {
+                frame.begin()?;
+            }
+            epoch.store(frame.epoch, Ordering::SeqCst);
+            self.empty.store(false, Ordering::SeqCst);
+            self.queued.notify();
+        }
+        unsafe {
+            frame.fill(dst, data, dst_device_buffer);
         }
         Ok(())
     }
@@ -538,12 +995,17 @@ impl FrameOuter {
         kernel_desc: &Arc<KernelDesc>,
         epoch: &AtomicU64,
         pipeline: &Arc<ComputePipeline>,
-        groups: u32,
+        groups: [u32; 3],
         buffers: &[Arc<DeviceBuffer>],
+        descriptor_set: Option<&Arc<DescriptorSet>>,
         push_consts: &[u8],
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) -> Result<()> {
-        let new_descriptors: u32 = buffers.len().try_into().unwrap();
+        let new_descriptors: u32 = if descriptor_set.is_some() || push_descriptor(pipeline) {
+            0
+        } else {
+            buffers.len().try_into().unwrap()
+        };
         let mut frame = self.frame.lock();
         if frame.command_buffer_builder.is_none() {
             self.kernels = 0;
@@ -553,6 +1015,7 @@ impl FrameOuter {
             }
             epoch.store(frame.epoch, Ordering::SeqCst);
             self.empty.store(false, Ordering::SeqCst);
+            self.queued.notify();
         }
         unsafe {
             frame.compute(
@@ -560,6 +1023,7 @@ impl FrameOuter {
                 pipeline,
                 groups,
                 buffers,
+                descriptor_set,
                 push_consts,
                 debug_printf_panic,
             );
@@ -575,10 +1039,13 @@ struct Frame {
     _command_pool: CommandPool,
     command_pool_alloc: CommandPoolAlloc,
     command_buffer_builder: Option<UnsafeCommandBufferBuilder>,
+    command_buffer: Option<UnsafeCommandBuffer>,
     descriptor_pool: DescriptorPool,
     buffers: Vec<Subbuffer<[u8]>>,
+    descriptor_sets: Vec<Arc<DescriptorSet>>,
     epoch: u64,
     debug_kernel_desc_panic: Option<(Arc<KernelDesc>, Arc<AtomicBool>)>,
+    debug_messenger: Option<DebugUtilsMessenger>,
 }
 
 impl Frame {
@@ -622,10 +1089,13 @@ impl Frame {
             _command_pool: command_pool,
             command_pool_alloc,
             command_buffer_builder,
+            command_buffer: None,
             descriptor_pool,
             buffers,
+            descriptor_sets: Vec::new(),
             epoch,
             debug_kernel_desc_panic: None,
+            debug_messenger: None,
         })
     }
     unsafe fn begin(&mut self) -> Result<()> {
@@ -653,7 +1123,8 @@ impl Frame {
         &mut self,
         src: Subbuffer<[u8]>,
         dst: Subbuffer<[u8]>,
-        host_buffer: &mut HostBuffer,
+        host_buffer: Option<&mut HostBuffer>,
+        src_device_buffer: Option<&DeviceBuffer>,
         dst_device_buffer: Option<&DeviceBuffer>,
     ) {
         let builder = self.command_buffer_builder.as_mut().unwrap();
@@ -661,50 +1132,116 @@ impl Frame {
             builder.copy_buffer(&CopyBufferInfo::buffers(src.clone(), dst.clone()));
         }
         self.buffers.extend_from_slice(&[src, dst]);
-        host_buffer.epoch = self.epoch;
-        if let Some(dst_device_buffer) = dst_device_buffer {
-            dst_device_buffer.epoch.store(self.epoch, Ordering::SeqCst);
+        if let Some(host_buffer) = host_buffer {
+            host_buffer.epoch = self.epoch;
+        }
+        // the source is recorded as well, so that host writes wait for the copy to read it
+        for device_buffer in src_device_buffer.into_iter().chain(dst_device_buffer) {
+            device_buffer.epoch.store(self.epoch, Ordering::SeqCst);
         }
     }
+    unsafe fn fill(&mut self, dst: Subbuffer<[u8]>, data: u32, dst_device_buffer: &DeviceBuffer) {
+        let builder = self.command_buffer_builder.as_mut().unwrap();
+        // the offset and size are a multiple of 4, checked in DeviceBuffer::fill
+        unsafe {
+            builder.fill_buffer(&dst.clone().reinterpret_unchecked::<[u32]>(), data);
+        }
+        self.buffers.push(dst);
+        dst_device_buffer.epoch.store(self.epoch, Ordering::SeqCst);
+    }
+    #[allow(clippy::too_many_arguments)]
     unsafe fn compute(
         &mut self,
         kernel_desc: &Arc<KernelDesc>,
         pipeline: &Arc<ComputePipeline>,
-        groups: u32,
+        groups: [u32; 3],
         buffers: &[Arc<DeviceBuffer>],
+        descriptor_set: Option<&Arc<DescriptorSet>>,
         push_consts: &[u8],
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) {
         let builder = self.command_buffer_builder.as_mut().unwrap();
+        // buffers written earlier in this frame must be visible to the kernel
+        if buffers
+            .iter()
+            .any(|x| x.epoch.load(Ordering::SeqCst) == self.epoch)
+        {
+            let memory_barrier = MemoryBarrier {
+                src_stages: PipelineStages::COMPUTE_SHADER | PipelineStages::ALL_TRANSFER,
+                src_access: AccessFlags::SHADER_WRITE | AccessFlags::TRANSFER_WRITE,
+                dst_stages: PipelineStages::COMPUTE_SHADER,
+                dst_access: AccessFlags::SHADER_READ | AccessFlags::SHADER_WRITE,
+                ..MemoryBarrier::default()
+            };
+            unsafe {
+                builder.pipeline_barrier(&DependencyInfo {
+                    memory_barriers: [memory_barrier].into_iter().collect(),
+                    ..DependencyInfo::default()
+                });
+            }
+        }
         unsafe {
             builder.bind_pipeline_compute(pipeline);
         }
         let pipeline_layout = pipeline.layout();
-        if !buffers.is_empty() {
+        if let Some(descriptor_set) = descriptor_set {
+            match &descriptor_set.inner {
+                DescriptorSetInner::Allocated { descriptor_set, .. } => unsafe {
+                    builder.bind_descriptor_sets(
+                        PipelineBindPoint::Compute,
+                        pipeline_layout,
+                        0,
+                        [descriptor_set],
+                        [],
+                    );
+                },
+                DescriptorSetInner::Push(write_descriptor_set) => unsafe {
+                    builder.push_descriptor_set(
+                        PipelineBindPoint::Compute,
+                        pipeline_layout,
+                        0,
+                        [write_descriptor_set],
+                    );
+                },
+            }
+            self.descriptor_sets.push(descriptor_set.clone());
+        } else if !buffers.is_empty() {
             let descriptor_set_layout = pipeline_layout.set_layouts().first().unwrap();
             let write_descriptor_set = WriteDescriptorSet::buffer_array(
                 0,
                 0,
                 buffers.iter().map(|x| x.inner.as_ref().unwrap().clone()),
             );
-            unsafe {
-                let mut descriptor_set = self
-                    .descriptor_pool
-                    .allocate_descriptor_sets([DescriptorSetAllocateInfo {
-                        layout: descriptor_set_layout,
-                        variable_descriptor_count: 0,
-                    }])
-                    .unwrap()
-                    .next()
-                    .unwrap();
-                descriptor_set.write(descriptor_set_layout, [&write_descriptor_set]);
-                builder.bind_descriptor_sets(
-                    PipelineBindPoint::Compute,
-                    pipeline_layout,
-                    0,
-                    &[descriptor_set],
-                    [],
-                );
+            if descriptor_set_layout.push_descriptor() {
+                // skips allocating from the frame descriptor pool
+                unsafe {
+                    builder.push_descriptor_set(
+                        PipelineBindPoint::Compute,
+                        pipeline_layout,
+                        0,
+                        [&write_descriptor_set],
+                    );
+                }
+            } else {
+                unsafe {
+                    let mut descriptor_set = self
+                        .descriptor_pool
+                        .allocate_descriptor_sets([DescriptorSetAllocateInfo {
+                            layout: descriptor_set_layout,
+                            variable_descriptor_count: 0,
+                        }])
+                        .unwrap()
+                        .next()
+                        .unwrap();
+                    descriptor_set.write(descriptor_set_layout, [&write_descriptor_set]);
+                    builder.bind_descriptor_sets(
+                        PipelineBindPoint::Compute,
+                        pipeline_layout,
+                        0,
+                        &[descriptor_set],
+                        [],
+                    );
+                }
             }
         }
         if !push_consts.is_empty() {
@@ -719,7 +1256,7 @@ impl Frame {
             }
         }
         unsafe {
-            builder.dispatch([groups, 1, 1]);
+            builder.dispatch(groups);
         }
         self.buffers
             .extend(buffers.iter().map(|x| x.inner.as_ref().unwrap().clone()));
@@ -734,8 +1271,11 @@ impl Frame {
         }
     }
     unsafe fn finish(&mut self) {
+        self.command_buffer.take();
         self.buffers.clear();
+        self.descriptor_sets.clear();
         self.debug_kernel_desc_panic.take();
+        self.debug_messenger.take();
     }
 }
 
@@ -755,20 +1295,36 @@ struct Worker {
     empty: Arc<AtomicBool>,
     pending: Arc<AtomicU64>,
     ready_frame: Arc<Mutex<Frame>>,
-    pending_frame: Frame,
+    free_frames: Vec<Frame>,
+    pending_frames: VecDeque<Frame>,
     engine_exited: Arc<AtomicBool>,
     worker_exited: Arc<AtomicBool>,
+    batches: Arc<AtomicUsize>,
+    flush: Arc<AtomicBool>,
+    submitted: Arc<Signal>,
+    queued: Arc<Signal>,
+    wakers: Arc<Mutex<Vec<(u64, Waker)>>>,
+    pending_wakers: Arc<Mutex<Vec<(u64, Waker)>>>,
+    poll_interval: Duration,
 }
 
 impl Worker {
-    fn new(queue: Arc<Queue>, index: usize) -> Result<Self> {
+    fn new(
+        queue: Arc<Queue>,
+        index: usize,
+        frames: usize,
+        poll_interval: Duration,
+    ) -> Result<Self> {
         let semaphore = Arc::new(new_semaphore(queue.device())?);
         let empty = Arc::new(AtomicBool::new(true));
         let pending = Arc::new(AtomicU64::default());
         let mut ready_frame = Frame::new(queue.clone())?;
         ready_frame.epoch = 1;
         let ready_frame = Arc::new(Mutex::new(ready_frame));
-        let pending_frame = Frame::new(queue.clone())?;
+        let free_frames = (1..frames)
+            .map(|_| Frame::new(queue.clone()))
+            .collect::<Result<_>>()?;
+        let pending_frames = VecDeque::with_capacity(frames);
         let engine_exited = Arc::new(AtomicBool::default());
         let worker_exited = Arc::new(AtomicBool::default());
         Ok(Self {
@@ -778,42 +1334,129 @@ impl Worker {
             empty,
             pending,
             ready_frame,
-            pending_frame,
+            free_frames,
+            pending_frames,
             engine_exited,
             worker_exited,
+            batches: Arc::default(),
+            flush: Arc::default(),
+            submitted: Arc::default(),
+            queued: Arc::default(),
+            wakers: Arc::default(),
+            pending_wakers: Arc::default(),
+            poll_interval,
         })
     }
+    fn finish_frames(&mut self, block: bool) {
+        while let Some(frame) = self.pending_frames.front() {
+            let timeout_ns = if block { WAIT_TIMEOUT_NS } else { 0 };
+            loop {
+                let result = unsafe {
+                    wait_semaphore(
+                        self.queue.device(),
+                        &self.semaphore,
+                        frame.epoch,
+                        timeout_ns,
+                    )
+                };
+                match result {
+                    ash::vk::Result::SUCCESS => break,
+                    ash::vk::Result::TIMEOUT if !block => return,
+                    ash::vk::Result::TIMEOUT => (),
+                    _ => result.result().unwrap(),
+                }
+            }
+            let mut frame = self.pending_frames.pop_front().unwrap();
+            unsafe {
+                frame.finish();
+            }
+            self.wake(frame.epoch);
+            self.free_frames.push(frame);
+            if block {
+                return;
+            }
+        }
+    }
+    fn wake(&self, epoch: u64) {
+        Self::wake_epoch(&self.wakers, epoch);
+    }
+    fn wake_pending(&self, epoch: u64) {
+        Self::wake_epoch(&self.pending_wakers, epoch);
+    }
+    fn wake_epoch(wakers: &Mutex<Vec<(u64, Waker)>>, epoch: u64) {
+        wakers.lock().retain(|(x, waker)| {
+            if *x <= epoch {
+                waker.wake_by_ref();
+                false
+            } else {
+                true
+            }
+        });
+    }
     fn run(&mut self) {
         let id = DeviceId {
             index: self.index,
             handle: self.queue.device().handle().as_raw().try_into().unwrap(),
         };
         loop {
-            while self.empty.load(Ordering::SeqCst) {
+            // batches hold the ready frame until it is full or waited on
+            while self.empty.load(Ordering::SeqCst)
+                || (self.batches.load(Ordering::SeqCst) > 0 && !self.flush.load(Ordering::SeqCst))
+            {
                 if self.engine_exited.load(Ordering::SeqCst) {
                     return;
                 }
-                std::hint::spin_loop();
+                self.finish_frames(false);
+                if let Some(frame) = self.pending_frames.front() {
+                    // wakes when the oldest frame finishes, new work is picked up after the timeout
+                    let timeout_ns = if self.poll_interval.is_zero() {
+                        IDLE_POLL_NS
+                    } else {
+                        self.poll_interval.as_nanos().try_into().unwrap_or(u64::MAX)
+                    };
+                    let result = unsafe {
+                        wait_semaphore(
+                            self.queue.device(),
+                            &self.semaphore,
+                            frame.epoch,
+                            timeout_ns,
+                        )
+                    };
+                    if !matches!(result, ash::vk::Result::SUCCESS | ash::vk::Result::TIMEOUT) {
+                        result.result().unwrap();
+                    }
+                } else {
+                    // nothing to finish, so block until the engine queues work or exits
+                    self.queued.wait_notified(|| {
+                        self.engine_exited.load(Ordering::SeqCst)
+                            || !(self.empty.load(Ordering::SeqCst)
+                                || (self.batches.load(Ordering::SeqCst) > 0
+                                    && !self.flush.load(Ordering::SeqCst)))
+                    });
+                }
             }
+            if self.free_frames.is_empty() {
+                self.finish_frames(true);
+            }
+            let mut frame = self.free_frames.pop().unwrap();
             {
                 let mut ready_frame = self.ready_frame.lock();
-                self.pending_frame.epoch = ready_frame.epoch + 1;
+                frame.epoch = ready_frame.epoch + 1;
                 self.empty.store(true, Ordering::SeqCst);
-                std::mem::swap(&mut *ready_frame, &mut self.pending_frame);
+                self.flush.store(false, Ordering::SeqCst);
+                std::mem::swap(&mut *ready_frame, &mut frame);
             }
-            self.pending
-                .store(self.pending_frame.epoch, Ordering::SeqCst);
-            let command_buffer = self
-                .pending_frame
+            self.pending.store(frame.epoch, Ordering::SeqCst);
+            self.submitted.notify();
+            self.wake_pending(frame.epoch);
+            let command_buffer = frame
                 .command_buffer_builder
                 .take()
                 .unwrap()
                 .build()
                 .unwrap();
-            let _messenger = if let Some((kernel_desc, panicked)) =
-                self.pending_frame.debug_kernel_desc_panic.take()
-            {
-                Some(
+            if let Some((kernel_desc, panicked)) = frame.debug_kernel_desc_panic.take() {
+                frame.debug_messenger.replace(
                     unsafe {
                         DebugUtilsMessenger::new(
                             self.queue.device().instance().clone(),
@@ -839,37 +1482,20 @@ impl Worker {
                         )
                     }
                     .unwrap(),
-                )
-            } else {
-                None
-            };
+                );
+            }
             self.queue.with(|mut guard| unsafe {
                 queue_submit(
                     &self.queue,
                     &mut guard,
                     &command_buffer,
                     &self.semaphore,
-                    self.pending_frame.epoch,
+                    frame.epoch,
                 )
                 .unwrap();
             });
-            loop {
-                let result = unsafe {
-                    wait_semaphore(
-                        self.queue.device(),
-                        &self.semaphore,
-                        self.pending_frame.epoch,
-                    )
-                };
-                match result {
-                    ash::vk::Result::SUCCESS => break,
-                    ash::vk::Result::TIMEOUT => std::hint::spin_loop(),
-                    _ => result.result().unwrap(),
-                }
-            }
-            unsafe {
-                self.pending_frame.finish();
-            }
+            frame.command_buffer.replace(command_buffer);
+            self.pending_frames.push_back(frame);
         }
     }
 }
@@ -878,6 +1504,10 @@ impl Drop for Worker {
     fn drop(&mut self) {
         let _ = self.queue.with(|mut guard| guard.wait_idle());
         self.worker_exited.store(true, Ordering::SeqCst);
+        // waiting threads and pending futures will see that the worker has exited
+        self.submitted.notify();
+        self.wake(u64::MAX);
+        self.wake_pending(u64::MAX);
     }
 }
 
@@ -904,6 +1534,53 @@ pub(super) struct DeviceBuffer {
     offset: usize,
     len: usize,
     epoch: Arc<AtomicU64>,
+    _allocated: Option<Arc<AllocatedGuard>>,
+    #[cfg(debug_assertions)]
+    _allocation: Option<Arc<AllocationGuard>>,
+}
+
+// Subtracts the allocation from the engine when the buffer and all slices are dropped.
+struct AllocatedGuard {
+    bytes: u64,
+    allocated: Arc<AtomicU64>,
+}
+
+impl AllocatedGuard {
+    fn new(engine: &Engine, bytes: u64) -> Self {
+        let allocated = engine.allocated.clone();
+        allocated.fetch_add(bytes, Ordering::SeqCst);
+        Self { bytes, allocated }
+    }
+}
+
+impl Drop for AllocatedGuard {
+    fn drop(&mut self) {
+        self.allocated.fetch_sub(self.bytes, Ordering::SeqCst);
+    }
+}
+
+// Removes the allocation from the engine when the buffer and all slices are dropped.
+#[cfg(debug_assertions)]
+struct AllocationGuard {
+    id: u64,
+    allocations: Arc<Mutex<FxHashMap<u64, LiveAllocation>>>,
+}
+
+#[cfg(debug_assertions)]
+impl AllocationGuard {
+    fn new(engine: &Engine, bytes: usize) -> Self {
+        let id = engine.next_allocation_id.fetch_add(1, Ordering::SeqCst);
+        let allocations = engine.allocations.clone();
+        allocations.lock().insert(id, LiveAllocation::new(bytes));
+        Self { id, allocations }
+    }
+}
+
+#[cfg(debug_assertions)]
+impl Drop for AllocationGuard {
+    fn drop(&mut self) {
+        self.allocations.lock().remove(&self.id);
+    }
 }
 
 impl DeviceBuffer {
@@ -972,12 +1649,22 @@ impl DeviceEngineBuffer for DeviceBuffer {
         } else {
             None
         };
+        let _allocated = inner
+            .as_ref()
+            .map(|x| Arc::new(AllocatedGuard::new(&engine, x.size())));
+        #[cfg(debug_assertions)]
+        let _allocation = inner
+            .as_ref()
+            .map(|x| Arc::new(AllocationGuard::new(&engine, x.size() as usize)));
         Ok(Self {
             inner,
             engine,
             offset: 0,
             len,
             epoch: Arc::new(AtomicU64::new(0)),
+            _allocated,
+            #[cfg(debug_assertions)]
+            _allocation,
         })
     }
     fn upload(&self, data: &[u8]) -> Result<()> {
@@ -1005,13 +1692,23 @@ impl DeviceEngineBuffer for DeviceBuffer {
             let size = chunk.len() as u64;
             let buffer_slice = buffer.clone().slice(offset..offset + size);
             let host_slice = host_buffer.inner.clone().slice(0..size);
-            engine.wait_epoch(host_buffer.epoch)?;
-            host_slice.write().unwrap().copy_from_slice(chunk);
-            engine.wait_pending(buffer_epoch)?;
-            unsafe {
-                engine.transfer(host_slice, buffer_slice, &mut host_buffer, Some(self))?;
-            }
-            engine.host_buffer_sender.send(host_buffer).unwrap();
+            let result = (|| {
+                engine.wait_epoch(host_buffer.epoch)?;
+                host_slice.write()?.copy_from_slice(chunk);
+                engine.wait_pending(buffer_epoch)?;
+                unsafe {
+                    engine.transfer(
+                        host_slice,
+                        buffer_slice,
+                        Some(&mut host_buffer),
+                        None,
+                        Some(self),
+                    )
+                }
+            })();
+            // return the staging buffer even on failure, otherwise later transfers would block
+            engine.send_host_buffer(host_buffer);
+            result.with_context(|| format!("Upload failed at byte {offset} of {}!", self.len))?;
             offset += size;
         }
         Ok(())
@@ -1037,43 +1734,126 @@ impl DeviceEngineBuffer for DeviceBuffer {
         }
         struct HostCopy<'a> {
             chunk: &'a mut [u8],
+            offset: u64,
             host_buffer: HostBuffer,
             host_slice: Subbuffer<[u8]>,
         }
-        let mut host_copy: Option<HostCopy> = None;
+        fn finish_host_copy(engine: &Engine, host_copy: HostCopy, len: usize) -> Result<()> {
+            let HostCopy {
+                chunk,
+                offset,
+                host_buffer,
+                host_slice,
+            } = host_copy;
+            let result = (|| {
+                engine.wait_epoch(host_buffer.epoch)?;
+                chunk.copy_from_slice(&host_slice.read()?);
+                Ok::<_, Error>(())
+            })();
+            engine.send_host_buffer(host_buffer);
+            result.with_context(|| format!("Download failed at byte {offset} of {len}!"))
+        }
+        // returns staging buffers after a failure, otherwise later transfers would block
+        fn release_host_copies<'a>(
+            engine: &Engine,
+            host_copies: impl IntoIterator<Item = HostCopy<'a>>,
+        ) {
+            for host_copy in host_copies {
+                engine.send_host_buffer(host_copy.host_buffer);
+            }
+        }
+        let mut host_copies = VecDeque::with_capacity(engine.staging_buffers);
         let mut offset = 0;
-        for chunk in data.chunks_mut(Self::HOST_BUFFER_SIZE).chain([[].as_mut()]) {
-            let prev_host_copy = host_copy.take();
-            if !chunk.is_empty() {
-                let mut host_buffer = engine.host_buffer_receiver.recv().unwrap();
+        for chunk in data.chunks_mut(Self::HOST_BUFFER_SIZE) {
+            // only block on a staging buffer when none are held, other transfers may hold the rest
+            let mut host_buffer = loop {
+                if host_copies.len() < engine.staging_buffers {
+                    if let Ok(host_buffer) = engine.host_buffer_receiver.try_recv() {
+                        break host_buffer;
+                    }
+                }
+                if let Some(host_copy) = host_copies.pop_front() {
+                    if let Err(e) = finish_host_copy(engine, host_copy, self.len) {
+                        release_host_copies(engine, host_copies);
+                        return Err(e);
+                    }
+                } else {
+                    break engine.host_buffer_receiver.recv().unwrap();
+                }
+            };
+            let size = chunk.len() as u64;
+            let buffer_slice = buffer.clone().slice(offset..offset + size);
+            let host_slice = host_buffer.inner.clone().slice(0..size);
+            let result = (|| {
                 engine.wait_epoch(host_buffer.epoch)?;
-                let size = chunk.len() as u64;
-                let buffer_slice = buffer.clone().slice(offset..offset + size);
-                let host_slice = host_buffer.inner.clone().slice(0..size);
                 engine.wait_pending(buffer_epoch)?;
                 unsafe {
-                    engine.transfer(buffer_slice, host_slice.clone(), &mut host_buffer, None)?;
+                    engine.transfer(
+                        buffer_slice,
+                        host_slice.clone(),
+                        Some(&mut host_buffer),
+                        None,
+                        None,
+                    )
                 }
-                host_copy.replace(HostCopy {
-                    chunk,
-                    host_buffer,
-                    host_slice,
-                });
-                offset += size;
+            })();
+            if let Err(e) = result {
+                engine.send_host_buffer(host_buffer);
+                release_host_copies(engine, host_copies);
+                return Err(e.context(format!("Download failed at byte {offset} of {}!", self.len)));
             }
-            if let Some(prev_host_copy) = prev_host_copy {
-                let HostCopy {
-                    chunk,
-                    host_buffer,
-                    host_slice,
-                } = prev_host_copy;
-                engine.wait_epoch(host_buffer.epoch)?;
-                chunk.copy_from_slice(&host_slice.read().unwrap());
-                engine.host_buffer_sender.send(host_buffer).unwrap();
+            host_copies.push_back(HostCopy {
+                chunk,
+                offset,
+                host_buffer,
+                host_slice,
+            });
+            offset += size;
+        }
+        let mut host_copies = host_copies.into_iter();
+        while let Some(host_copy) = host_copies.next() {
+            if let Err(e) = finish_host_copy(engine, host_copy, self.len) {
+                release_host_copies(engine, host_copies);
+                return Err(e);
             }
         }
         Ok(())
     }
+    fn download_async<D: AsMut<[u8]> + Unpin + Send + 'static>(
+        self: &Arc<Self>,
+        data: D,
+    ) -> Pin<Box<dyn Future<Output = Result<D>> + Send>> {
+        let buffer = self.inner.as_ref().map(|buffer| {
+            buffer
+                .clone()
+                .slice(self.offset as u64..(self.offset + self.len) as u64)
+        });
+        Box::pin(DownloadFuture {
+            device_buffer: self.clone(),
+            buffer: buffer.filter(|_| self.len > 0),
+            buffer_epoch: self.epoch.load(Ordering::SeqCst),
+            data: Some(data),
+            offset: 0,
+            host_buffer: None,
+            host_copy: None,
+        })
+    }
+    fn peek(&self, data: &mut [u8]) -> Result<()> {
+        debug_assert_eq!(data.len(), self.len);
+        if !self.host_visible() {
+            return self.download(data);
+        }
+        let buffer = if let Some(buffer) = self.inner.as_ref() {
+            buffer
+                .clone()
+                .slice(self.offset as u64..(self.offset + self.len) as u64)
+        } else {
+            return Ok(());
+        };
+        // does not wait for the buffer epoch, so writes may be in progress
+        data.copy_from_slice(&buffer.read().context("Peek failed!")?);
+        Ok(())
+    }
     fn transfer(&self, dst: &Self) -> Result<()> {
         debug_assert_eq!(dst.len, self.len);
         if self.len == 0 {
@@ -1095,6 +1875,13 @@ impl DeviceEngineBuffer for DeviceBuffer {
         let buffer1_epoch = self.epoch.load(Ordering::SeqCst);
         let engine2 = &dst.engine;
         let buffer2_epoch = dst.epoch.load(Ordering::SeqCst);
+        // Each engine creates its own instance, so buffers can only be copied directly on the
+        // same engine. Otherwise the copy is staged through host buffers.
+        if Arc::ptr_eq(engine1, engine2) {
+            engine1.wait_pending(buffer1_epoch)?;
+            engine1.wait_pending(buffer2_epoch)?;
+            return unsafe { engine1.transfer(buffer1, buffer2, None, Some(self), Some(dst)) };
+        }
         if self.host_visible() && dst.host_visible() {
             engine1.wait_epoch(buffer1_epoch)?;
             engine2.wait_epoch(buffer2_epoch)?;
@@ -1134,7 +1921,8 @@ impl DeviceEngineBuffer for DeviceBuffer {
                     engine1.transfer(
                         buffer_slice1,
                         host_slice1.clone(),
-                        &mut host_buffer1,
+                        Some(&mut host_buffer1),
+                        None,
                         None,
                     )?;
                 }
@@ -1161,18 +1949,42 @@ impl DeviceEngineBuffer for DeviceBuffer {
                     .write()
                     .unwrap()
                     .copy_from_slice(&host_slice1.read().unwrap());
-                engine1.host_buffer_sender.send(host_buffer1).unwrap();
+                engine1.send_host_buffer(host_buffer1);
                 engine2.wait_pending(buffer2_epoch)?;
                 unsafe {
-                    engine2.transfer(host_slice2, buffer_slice2, &mut host_buffer2, Some(dst))?;
+                    engine2.transfer(
+                        host_slice2,
+                        buffer_slice2,
+                        Some(&mut host_buffer2),
+                        None,
+                        Some(dst),
+                    )?;
                 }
-                engine2.host_buffer_sender.send(host_buffer2).unwrap();
+                engine2.send_host_buffer(host_buffer2);
             } else if size == 0 {
                 break;
             }
         }
         Ok(())
     }
+    fn fill(&self, data: u32) -> Result<()> {
+        // vkCmdFillBuffer requires the offset and size to be a multiple of 4
+        debug_assert_eq!(self.offset % 4, 0);
+        debug_assert_eq!(self.len % 4, 0);
+        if self.len == 0 {
+            return Ok(());
+        }
+        let buffer = if let Some(buffer) = self.inner.as_ref() {
+            buffer
+                .clone()
+                .slice(self.offset as u64..(self.offset + self.len) as u64)
+        } else {
+            return Ok(());
+        };
+        let engine = &self.engine;
+        engine.wait_pending(self.epoch.load(Ordering::SeqCst))?;
+        unsafe { engine.fill(buffer, data, self) }
+    }
     fn offset(&self) -> usize {
         self.offset
     }
@@ -1195,6 +2007,255 @@ impl DeviceEngineBuffer for DeviceBuffer {
             ..Self::clone(self)
         }))
     }
+    fn vulkan_buffer(&self) -> Option<ash::vk::Buffer> {
+        self.inner.as_ref().map(|x| x.buffer().handle())
+    }
+}
+
+// Downloads in chunks through one staging buffer, polling the epoch of each transfer.
+struct DownloadFuture<D> {
+    device_buffer: Arc<DeviceBuffer>,
+    buffer: Option<Subbuffer<[u8]>>,
+    buffer_epoch: u64,
+    data: Option<D>,
+    offset: usize,
+    host_buffer: Option<HostBuffer>,
+    host_copy: Option<Range<usize>>,
+}
+
+impl<D: AsMut<[u8]>> DownloadFuture<D> {
+    fn poll_download(&mut self, cx: &mut Context) -> Poll<Result<()>> {
+        let engine = &self.device_buffer.engine;
+        let Some(buffer) = self.buffer.as_ref() else {
+            return Poll::Ready(Ok(()));
+        };
+        let data = self.data.as_mut().unwrap().as_mut();
+        debug_assert_eq!(data.len(), self.device_buffer.len);
+        if self.device_buffer.host_visible() {
+            ready!(engine.poll_epoch(self.buffer_epoch, cx.waker()))?;
+            data.copy_from_slice(&buffer.read().unwrap());
+            return Poll::Ready(Ok(()));
+        }
+        loop {
+            if let Some(host_buffer) = self.host_buffer.as_ref() {
+                ready!(engine.poll_epoch(host_buffer.epoch, cx.waker()))?;
+                if let Some(range) = self.host_copy.take() {
+                    let host_slice = host_buffer.inner.clone().slice(0..range.len() as u64);
+                    data[range].copy_from_slice(&host_slice.read()?);
+                }
+            }
+            if self.offset == data.len() {
+                return Poll::Ready(Ok(()));
+            }
+            if self.host_buffer.is_none() {
+                // all staging buffers are in use, woken when one is returned
+                let host_buffer = ready!(engine.poll_host_buffer(cx.waker()));
+                self.host_buffer.replace(host_buffer);
+                continue;
+            }
+            ready!(engine.poll_pending(self.buffer_epoch, cx.waker()))?;
+            let host_buffer = self.host_buffer.as_mut().unwrap();
+            let start = self.offset;
+            let end = data.len().min(start + DeviceBuffer::HOST_BUFFER_SIZE);
+            let buffer_slice = buffer.clone().slice(start as u64..end as u64);
+            let host_slice = host_buffer.inner.clone().slice(0..(end - start) as u64);
+            unsafe {
+                engine.transfer(buffer_slice, host_slice, Some(host_buffer), None, None)?;
+            }
+            self.host_copy.replace(start..end);
+            self.offset = end;
+        }
+    }
+}
+
+impl<D: AsMut<[u8]> + Unpin> Future for DownloadFuture<D> {
+    type Output = Result<D>;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let offset = self.offset;
+        let result = ready!(self.poll_download(cx));
+        if let Some(host_buffer) = self.host_buffer.take() {
+            self.device_buffer.engine.send_host_buffer(host_buffer);
+        }
+        let len = self.device_buffer.len;
+        Poll::Ready(
+            result
+                .map(|_| self.data.take().unwrap())
+                .with_context(|| format!("Download failed at byte {offset} of {len}!")),
+        )
+    }
+}
+
+impl<D> Drop for DownloadFuture<D> {
+    fn drop(&mut self) {
+        // return the staging buffer, otherwise later transfers would block
+        if let Some(host_buffer) = self.host_buffer.take() {
+            self.device_buffer.engine.send_host_buffer(host_buffer);
+        }
+    }
+}
+
+// The krnl features supported by `device_features`.
+fn krnl_features(
+    device_features: &vulkano::device::Features,
+    properties: &vulkano::device::Properties,
+) -> Features {
+    let mut features = Features::empty();
+    if device_features.shader_int8 {
+        features = features.union(Features::INT8);
+    }
+    if device_features.shader_int16 {
+        features = features.union(Features::INT16);
+    }
+    if device_features.shader_int64 {
+        features = features.union(Features::INT64);
+    }
+    if device_features.shader_float16 {
+        features = features.union(Features::FLOAT16);
+    }
+    if device_features.shader_float64 {
+        features = features.union(Features::FLOAT64);
+    }
+    if device_features.storage_buffer8_bit_access {
+        features = features.union(Features::BUFFER8);
+    }
+    if device_features.storage_buffer16_bit_access {
+        features = features.union(Features::BUFFER16);
+    }
+    if device_features.storage_push_constant8 {
+        features = features.union(Features::PUSH_CONSTANT8);
+    }
+    if device_features.storage_push_constant16 {
+        features = features.union(Features::PUSH_CONSTANT16);
+    }
+    if let Some(subgroup_features) = properties.subgroup_supported_operations {
+        use vulkano::device::physical::SubgroupFeatures;
+
+        if subgroup_features.contains(SubgroupFeatures::BASIC) {
+            features = features.union(Features::SUBGROUP_BASIC);
+        }
+        if subgroup_features.contains(SubgroupFeatures::VOTE) {
+            features = features.union(Features::SUBGROUP_VOTE);
+        }
+        if subgroup_features.contains(SubgroupFeatures::ARITHMETIC) {
+            features = features.union(Features::SUBGROUP_ARITHMETIC);
+        }
+        if subgroup_features.contains(SubgroupFeatures::BALLOT) {
+            features = features.union(Features::SUBGROUP_BALLOT);
+        }
+        if subgroup_features.contains(SubgroupFeatures::SHUFFLE) {
+            features = features.union(Features::SUBGROUP_SHUFFLE);
+        }
+        if subgroup_features.contains(SubgroupFeatures::SHUFFLE_RELATIVE) {
+            features = features.union(Features::SUBGROUP_SHUFFLE_RELATIVE);
+        }
+        if subgroup_features.contains(SubgroupFeatures::CLUSTERED) {
+            features = features.union(Features::SUBGROUP_CLUSTERED);
+        }
+        if subgroup_features.contains(SubgroupFeatures::QUAD) {
+            features = features.union(Features::SUBGROUP_QUAD);
+        }
+    }
+    features
+}
+
+// The pipeline cache uuid is shared by identical devices, it is only used if the driver does not
+// report a device uuid.
+fn device_uuid(properties: &vulkano::device::Properties) -> [u8; 16] {
+    properties
+        .device_uuid
+        .unwrap_or(properties.pipeline_cache_uuid)
+}
+
+fn uuid_string(uuid: &[u8; 16]) -> String {
+    uuid.iter().map(|x| format!("{x:02x}")).collect()
+}
+
+// Pipelines compiled by the driver, shared by all kernels so that the driver can reuse
+// compiled code between specializations. Persisted to `DeviceBuilder::pipeline_cache_dir`.
+//
+// The driver looks up pipelines by shader and layout, so one file per device covers all
+// kernels. The file name includes the device name and driver version, so a driver update
+// starts a new cache.
+struct EnginePipelineCache {
+    cache: Arc<PipelineCache>,
+    path: Option<PathBuf>,
+    // set when a pipeline is created, the cache is only written if it may have changed
+    modified: AtomicBool,
+}
+
+impl EnginePipelineCache {
+    fn new(device: &Arc<Device>, dir: Option<PathBuf>) -> Result<Self> {
+        let Some(dir) = dir else {
+            return Ok(Self {
+                cache: PipelineCache::empty(device.clone())?,
+                path: None,
+                modified: AtomicBool::default(),
+            });
+        };
+        let properties = device.physical_device().properties();
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Unable to create pipeline cache dir {dir:?}!"))?;
+        let mut hasher = fxhash::FxHasher64::default();
+        properties.device_name.hash(&mut hasher);
+        properties.driver_version.hash(&mut hasher);
+        properties.pipeline_cache_uuid.hash(&mut hasher);
+        let path = dir.join(format!("krnl-pipeline-cache-{:016x}.bin", hasher.finish()));
+        // VkPipelineCacheHeaderVersionOne, fields are little endian
+        let header_matches = |data: &[u8]| {
+            let read_u32 = |i: usize| {
+                data.get(i..i + 4)
+                    .map(|x| u32::from_le_bytes(x.try_into().unwrap()))
+            };
+            read_u32(4) == Some(1)
+                && read_u32(8) == Some(properties.vendor_id)
+                && read_u32(12) == Some(properties.device_id)
+                && data.get(16..32) == Some(properties.pipeline_cache_uuid.as_slice())
+        };
+        let data = std::fs::read(&path).ok().filter(|x| header_matches(x));
+        let cache = if let Some(data) = data {
+            unsafe { PipelineCache::with_data(device.clone(), &data)? }
+        } else {
+            PipelineCache::empty(device.clone())?
+        };
+        Ok(Self {
+            cache,
+            path: Some(path),
+            modified: AtomicBool::default(),
+        })
+    }
+    fn set_modified(&self) {
+        self.modified.store(true, Ordering::SeqCst);
+    }
+    // Best effort, failing to write the cache should not prevent the engine from being dropped.
+    fn save(&self) {
+        static TMP_ID: AtomicU64 = AtomicU64::new(0);
+
+        let Some(path) = self.path.as_ref() else {
+            return;
+        };
+        if !self.modified.swap(false, Ordering::SeqCst) {
+            return;
+        }
+        let Ok(data) = self.cache.get_data() else {
+            return;
+        };
+        // written to a temporary file first, so other processes never read a partial cache
+        // unique per engine, engines of the same device in one process share the path
+        let tmp_path = path.with_extension(format!(
+            "bin.{}.{}.tmp",
+            std::process::id(),
+            TMP_ID.fetch_add(1, Ordering::SeqCst)
+        ));
+        if std::fs::write(&tmp_path, data).is_err() || std::fs::rename(&tmp_path, path).is_err() {
+            std::fs::remove_file(&tmp_path).ok();
+        }
+    }
+}
+
+impl Drop for EnginePipelineCache {
+    fn drop(&mut self) {
+        self.save();
+    }
 }
 
 #[derive(Clone)]
@@ -1282,9 +2343,18 @@ impl KernelInner {
                 (binding.try_into().unwrap(), descriptor_set_layout_binding)
             })
             .collect();
+        // VK_KHR_push_descriptor limits the number of bindings
+        let push_descriptor = device.enabled_extensions().khr_push_descriptor
+            && !desc.slice_descs.is_empty()
+            && desc.slice_descs.len()
+                <= device
+                    .physical_device()
+                    .properties()
+                    .max_push_descriptors
+                    .unwrap_or_default() as usize;
         let descriptor_set_layout_create_info = DescriptorSetLayoutCreateInfo {
             bindings,
-            push_descriptor: device.enabled_features().descriptor_buffer_push_descriptors,
+            push_descriptor,
             ..DescriptorSetLayoutCreateInfo::default()
         };
         let descriptor_set_layout =
@@ -1295,7 +2365,7 @@ impl KernelInner {
             ..PipelineLayoutCreateInfo::default()
         };
         let pipeline_layout = PipelineLayout::new(device.clone(), pipeline_layout_create_info)?;
-        let cache = None;
+        let cache = Some(engine.pipeline_cache.cache.clone());
         let compute_pipeline = ComputePipeline::with_pipeline_layout(
             device.clone(),
             shader_module.entry_point(entry_point).unwrap(),
@@ -1303,6 +2373,23 @@ impl KernelInner {
             pipeline_layout,
             cache,
         )?;
+        engine.pipeline_cache.set_modified();
+        if device
+            .physical_device()
+            .instance()
+            .enabled_extensions()
+            .ext_debug_utils
+        {
+            // Name objects for profilers / debuggers, ie `foo<threads=2, N=4>`.
+            // Naming is best effort, it should not prevent the kernel from being built.
+            let name = desc.name.as_ref();
+            device
+                .set_debug_utils_object_name(&*shader_module, Some(name))
+                .ok();
+            device
+                .set_debug_utils_object_name(&*compute_pipeline, Some(name))
+                .ok();
+        }
         Ok(Self {
             desc,
             compute_pipeline,
@@ -1319,6 +2406,7 @@ pub(super) struct Kernel {
 impl DeviceEngineKernel for Kernel {
     type Engine = Engine;
     type DeviceBuffer = DeviceBuffer;
+    type DescriptorSet = DescriptorSet;
     fn cached(
         engine: Arc<Self::Engine>,
         key: KernelKey,
@@ -1329,6 +2417,7 @@ impl DeviceEngineKernel for Kernel {
             compute_pipeline,
         } = engine
             .kernels
+            // the entry holds the shard lock, so the kernel is only compiled once
             .entry(key)
             .or_try_insert_with(|| KernelInner::new(&engine, desc_fn()?))?
             .clone();
@@ -1341,24 +2430,38 @@ impl DeviceEngineKernel for Kernel {
     fn engine(&self) -> &Arc<Self::Engine> {
         &self.engine
     }
+    fn descriptor_set(&self, buffers: &[Arc<Self::DeviceBuffer>]) -> Result<Arc<DescriptorSet>> {
+        DescriptorSet::new(&self.compute_pipeline, buffers).map(Arc::new)
+    }
     unsafe fn dispatch(
         &self,
-        groups: u32,
+        groups: [u32; 3],
         buffers: &[Arc<Self::DeviceBuffer>],
+        descriptor_set: Option<&Arc<DescriptorSet>>,
         push_consts: Vec<u8>,
+        cancelled: Option<&AtomicBool>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) -> Result<()> {
         let engine = &self.engine;
-        if let Some(epoch) = buffers.iter().map(|x| x.epoch.load(Ordering::SeqCst)).max() {
-            engine.wait_pending(epoch)?;
-        }
+        // Buffers written in the ready frame are synchronized with a barrier, earlier frames are
+        // ordered by the semaphore.
+        // too many buffers for the frame descriptor pool, allocate a dedicated descriptor set
+        let dedicated_descriptor_set =
+            if descriptor_set.is_none() && buffers.len() > Frame::MAX_DESCRIPTORS as usize {
+                Some(self.descriptor_set(buffers)?)
+            } else {
+                None
+            };
+        let descriptor_set = descriptor_set.or(dedicated_descriptor_set.as_ref());
         unsafe {
             engine.compute(
                 &self.desc,
                 &self.compute_pipeline,
                 groups,
                 buffers,
+                descriptor_set,
                 &push_consts,
+                cancelled,
                 debug_printf_panic,
             )
         }
@@ -1367,3 +2470,76 @@ impl DeviceEngineKernel for Kernel {
         &self.desc
     }
 }
+
+fn push_descriptor(pipeline: &ComputePipeline) -> bool {
+    pipeline
+        .layout()
+        .set_layouts()
+        .first()
+        .is_some_and(|x| x.push_descriptor())
+}
+
+// Allocated from its own pool, so that it can be bound by many frames.
+pub(super) struct DescriptorSet {
+    inner: DescriptorSetInner,
+}
+
+enum DescriptorSetInner {
+    Allocated {
+        descriptor_set: UnsafeDescriptorSet,
+        // DescriptorPool is !Sync, it is only kept alive and never accessed
+        _descriptor_pool: Mutex<DescriptorPool>,
+    },
+    // Pushed with VK_KHR_push_descriptor when dispatched.
+    Push(WriteDescriptorSet),
+}
+
+impl DescriptorSet {
+    fn new(pipeline: &Arc<ComputePipeline>, buffers: &[Arc<DeviceBuffer>]) -> Result<Self> {
+        if push_descriptor(pipeline) {
+            let write_descriptor_set = WriteDescriptorSet::buffer_array(
+                0,
+                0,
+                buffers.iter().map(|x| x.inner.as_ref().unwrap().clone()),
+            );
+            return Ok(Self {
+                inner: DescriptorSetInner::Push(write_descriptor_set),
+            });
+        }
+        let device = pipeline.device();
+        let descriptors: u32 = buffers.len().try_into().unwrap();
+        let descriptor_pool = DescriptorPool::new(
+            device.clone(),
+            DescriptorPoolCreateInfo {
+                max_sets: 1,
+                pool_sizes: [(DescriptorType::StorageBuffer, descriptors)]
+                    .into_iter()
+                    .collect(),
+                ..Default::default()
+            },
+        )?;
+        let descriptor_set_layout = pipeline.layout().set_layouts().first().unwrap();
+        let write_descriptor_set = WriteDescriptorSet::buffer_array(
+            0,
+            0,
+            buffers.iter().map(|x| x.inner.as_ref().unwrap().clone()),
+        );
+        let descriptor_set = unsafe {
+            let mut descriptor_set = descriptor_pool
+                .allocate_descriptor_sets([DescriptorSetAllocateInfo {
+                    layout: descriptor_set_layout,
+                    variable_descriptor_count: 0,
+                }])?
+                .next()
+                .unwrap();
+            descriptor_set.write(descriptor_set_layout, [&write_descriptor_set]);
+            descriptor_set
+        };
+        Ok(Self {
+            inner: DescriptorSetInner::Allocated {
+                descriptor_set,
+                _descriptor_pool: Mutex::new(descriptor_pool),
+            },
+        })
+    }
+}
@@ -83,7 +83,10 @@ fn main() -> Result<()> {
 Compile with `krnlc` or `krnlc -p my-crate`.
 
 1. Runs the equivalent of [`cargo expand`](https://docs.rs/cargo-expand) to locate all modules.
-2. Generates a device crate under \<target-dir\>/krnlc/crates/\<my-crate\>.
+2. Generates a device crate under \<target-dir\>/krnlc/crates/\<my-crate\>. The crate is kept after compiling,
+   for inspecting the generated source when a kernel fails to compile. Use `krnlc -v` to print its path.
+   krnlc does not write or check a `CACHEDIR.TAG`, so the target directory (`--target-dir`) can be shared
+   between crates and krnlc versions.
 3. Compiles the device crate with [spirv-builder](https://docs.rs/crate/spirv-builder).
 4. Processes the output, validates and optimizes with [spirv-tools](https://docs.rs/spirv-tools).
 5. Writes out to "krnl-cache.rs", which is imported by [`module`](#modules) and [`kernel`](#kernels) macros.
@@ -99,6 +102,33 @@ abZy8000000@}Rn2BDn}/7A)@mn.pk3NcX{F^z.@l>4nckBq6ms3D<md5goETn#<^Op/(BnRwlm5HBw3
 If the version of krnlc is incompatible with the krnl version, [`module`](#modules)
 will emit a compiler error.
 
+List the kernels in a cache, including features and arguments, with `krnlc inspect krnl-cache.rs`.
+
+Kernels are embedded in the binary compressed with gzip. Use `krnlc --compression zstd` for
+smaller binaries, which requires the `zstd` feature of krnl to decode them, or `--compression none`
+to skip decompression when kernels are loaded.
+
+Kernels target Vulkan 1.2 by default. Use `krnlc --spirv-target vulkan1.1` to target older devices,
+kernels requiring a newer SPIR-V version than a device supports will fail to [build](#kernel-builder).
+
+Kernels are optimized for performance by default. Use `krnlc --opt-level none` to skip optimizing each kernel
+with spirv-opt while iterating, or `--opt-level size` for smaller kernels. The device crate is always compiled
+in the release profile.
+
+Packages are only recompiled when their modules, the sources of path dependencies, the device crate's
+"Cargo.lock", or krnlc options change. The hash of the inputs is stored in
+"target/krnlc/crates/<crate>/cache-manifest.json", delete it to force a rebuild.
+
+Use `krnlc --stats` to print the size of each kernel in SPIR-V words, and the time spent compiling.
+
+Packages are compiled in parallel, use `krnlc -j 1` to compile one at a time, ie to limit memory usage.
+Each device crate is compiled into its own target directory, \<target-dir\>/krnlc/targets/\<my-crate\>,
+so dependencies like krnl-core are compiled once per package.
+
+Kernels that can never run on the target devices can be rejected when compiling, with
+`--forbid-features FLOAT64,INT64` or `--require-features INT8,BUFFER8`, which fails if a kernel
+requires other [features](crate::device::Features).
+
 ## Toolchains
 To locate [modules](#modules), krnlc will use the nightly toolchain. Install it with:
 ```text
@@ -137,10 +167,27 @@ foo = { default-features = false, features = ["foo"] }
 bar = {}
 # private dependency
 baz = { path = "baz" }
+# workspace member, resolved from the workspace
+qux = {}
 ```
 
 [krnl-core](krnl_core) is automatically included as a dependency.
 
+The generated device crate can be customized, ie to tune the device build. The contents of `manifest`
+and `config` are appended to the generated "Cargo.toml" and ".cargo/config.toml" respectively.
+Tables generated by krnlc, ie `[dependencies]` or `[build]`, should not be repeated.
+```toml
+[package.metadata.krnlc.device-crate]
+manifest = """
+[profile.release]
+overflow-checks = false
+"""
+config = """
+[env]
+FOO = "foo"
+"""
+```
+
 # Modules
 The `module` macro declares a shared host and device scope that is visible to [krnlc](#krnlc).
 The [spirv](#spirv) arch will be used by krnlc when compiling modules for the device.
@@ -160,6 +207,9 @@ mod kernels {
 ```
 Modules mut be within a module hierarchy, not within fn's or impl blocks.
 
+`build_all(device)` is generated for each module, building all of its kernels that don't require
+specialization. This can be used to build kernels on startup, instead of on first use.
+
 ## Attributes
 Additonal options can be passed via attributes:
 ```no_run
@@ -169,10 +219,11 @@ Additonal options can be passed via attributes:
 // Does not compile the module with krnlc, used for krnl's docs.
 #[krnl(no_build)]
  // Override path to krnl when it isn't a dependency.
-#[krnl(crate=foo::krnl)]
+#[krnl(crate=crate::foo::krnl)]
 mod kernels {
     /* .. */
 }
+# fn main() {}
 ```
 
 ## Imports
@@ -251,12 +302,55 @@ if let Some(x) = x.as_host_slice() {
     let y: Vec<f32> = x.iter().copied().map(scale_to_f32_impl).collect();
     Ok(Buffer::from(y))
 } else {
-    let mut y = Buffer::zeros(x.device(), x.len())?;
-    scale_to_f32::builder()?
-        .build(x.device())?
-        .dispatch(x, y.as_slice_mut())?;
-    Ok(y)
+    scale_to_f32::run(x)
+}
+# }
+# }
+```
+
+Item kernels with exactly one input and one output item, like `scale_to_f32`, have a `run` fn
+that builds the kernel with default threads, allocates the output and dispatches. This is
+equivalent to:
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::{macros::kernel, buffer::{Buffer, Slice}, anyhow::Result};
+# #[kernel]
+# fn scale_to_f32(#[item] x: u8, #[item] y: &mut f32) {
+#     *y = x as f32 / 255.;
+# }
+# fn foo(x: Slice<u8>) -> Result<Buffer<f32>> {
+let mut y = Buffer::zeros(x.device(), x.len())?;
+scale_to_f32::builder()?
+    .build(x.device())?
+    .dispatch(x, y.as_slice_mut())?;
+Ok(y)
+# }
+# }
+```
+On the host, `run` executes the host implementation of [`#[kernel(host)]`](#host) kernels, and
+otherwise returns an error.
+
+Dispatching an item kernel over empty item buffers is a no-op, unless groups are provided with
+[`.with_groups(..)`](#dispatch). Empty [global](#global-buffers) buffers are an error.
+
+# Reductions
+`#[kernel(reduce)]` declares a reduction with an associative and commutative fn combining two elements.
+The kernel has methods `.reduce(x)`, returning a buffer with a single element, and `.reduce_partial(x)`,
+returning one element per group. Each thread combines a strided subset of `x`, and then each group combines
+the values of its threads in [group memory](#group-buffers), so elements are not combined in order.
+Reductions can't be dispatched on the host.
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::{macros::kernel, buffer::{Buffer, Slice}, anyhow::Result};
+#[kernel(reduce)]
+fn sum(a: f32, b: f32) -> f32 {
+    a + b
 }
+
+# fn foo(x: Slice<f32>) -> Result<Buffer<f32>> {
+sum::builder()?
+    .build(x.device())?
+    .reduce(x)
 # }
 # }
 ```
@@ -268,6 +362,14 @@ provided to [`.dispatch(..)`](#dispatch), and do not require rebuilding the kern
 At least 128 bytes of push constants can be used, depending on the device. Each [item](#items) or
 [global](#global-buffers)  argument requires 8 bytes of push constants.
 
+The push constant layout is fixed when the kernel is compiled by [krnlc](#krnlc), so push constants
+can't be arrays, including arrays with a length defined by a [SpecConstant](#specialization), and are
+rejected by the `kernel` macro. Pass variable length data, like coefficients, as a
+[global](#global-buffers) Slice instead. The size of push
+constants is checked against the device limit when the kernel is built. Kernels requiring more than
+128 bytes are rejected by the `kernel` macro at compile time, since they may not be supported by all
+devices.
+
 # Groups, Subgroups, and Threads
 Kernels without [items](#items) have an implicit [Kernel](krnl_core::kernel::Kernel) argument that uniquely
 identifies the group, subgroup, and thread.
@@ -282,6 +384,22 @@ It may range between [min_subgroup_threads](crate::device::DeviceInfo::min_subgr
 `min_subgroup_threads` and `max_subgroup_threads`, each subgroup in a group will have `subgroup_threads`
 threads, unless `threads` per group is not an exact multiple, where the last subgroup will have the remainder of threads.
 
+Groups are limited to [max_groups](crate::device::DeviceInfo::max_groups) per dispatch, and global threads are 32 bit.
+Dispatches are not split into tiles automatically. While Vulkan can offset the group id of a dispatch
+(`vkCmdDispatchBase`), the offset group id and therefore `kernel.global_id()` are still 32 bit, so tiling can't
+extend a dispatch beyond `u32::MAX` threads. Instead, kernels processing more elements than global threads can
+loop with a stride of `kernel.global_threads()`, like [item kernels](#items), which cover all items even when
+groups are clamped to `max_groups`.
+
+Groups may be 3d, via `.with_groups_3d(..)` or `.with_global_threads_3d(..)`, up to
+[max_groups_3d](crate::device::DeviceInfo::max_groups_3d). Threads per group are always 1d, so the y and z
+dimensions of `kernel.global_id_3d()` are the group id. Item kernels only support 1d groups.
+
+Kernels declared with [`#[kernel(host)]`](#host) can also execute on the host, where each thread is its
+own subgroup. To test kernels using subgroup operations without a GPU, use a software device like lavapipe, see
+[`DeviceBuilder::allow_software`](crate::device::builder::DeviceBuilder::allow_software). Note that lavapipe
+may not support all subgroup [features](crate::device::Features), check [`DeviceInfo::features`](crate::device::DeviceInfo::features).
+
 # Global Buffers
 Visible to all threads. [Slice](krnl_core::buffer::Slice) binds to [Slice](crate::buffer::Slice), [UnsafeSlice](krnl_core::buffer::UnsafeSlice) binds
 to [SliceMut](crate::buffer::SliceMut), provided to [`.dispatch(..)`](#dispatch).
@@ -296,6 +414,10 @@ to [global buffers](#global-buffers).
 The maximum amount of memory that can be used for group buffers depends on the device. Kernels
 exceeding this will fail to [build](#kernel-builder).
 
+The length of a group buffer can be a spec constant, ie `UnsafeSlice<f32, N>`. After
+specializing, `.with_group_buffer_len("N", len)` sets the spec constant by name, which is useful
+when tuning the length at runtime.
+
 Barriers should be used as necessary to synchronize access.
 ```no_run
 # #[krnl::macros::module] #[krnl(no_build)] mod kernels {
@@ -306,10 +428,7 @@ fn group_sum(
     #[group] x_group: UnsafeSlice<f32, 64>,
     #[global] y: UnsafeSlice<f32>,
 ) {
-    use krnl_core::{
-        buffer::UnsafeIndex,
-        spirv_std::arch::workgroup_memory_barrier_with_group_sync as group_barrier
-    };
+    use krnl_core::buffer::UnsafeIndex;
 
     let global_id = kernel.global_id();
     let group_id = kernel.group_id();
@@ -318,7 +437,7 @@ fn group_sum(
         *x_group.unsafe_index_mut(thread_id) = x[global_id];
         // Barriers are used to synchronize access to group memory.
         // This call must be reached by all active threads in the group!
-        group_barrier();
+        kernel.group_barrier();
     }
     if thread_id == 0 {
         let mut acc = 0f32;
@@ -357,8 +476,43 @@ pub mod saxpy {
     impl KernelBuilder {
         /// Threads per group.
         ///
-        /// Defaults to [`DeviceInfo::default_threads()`](DeviceInfo::default_threads).
+        /// Defaults to [`kernel::default_threads()`](kernel::default_threads) if set,
+        /// otherwise [`DeviceInfo::default_threads()`](DeviceInfo::default_threads).
         pub fn with_threads(self, threads: u32) -> Self;
+        /// Clamp threads to [`DeviceInfo::max_threads()`](DeviceInfo::max_threads).
+        ///
+        /// By default, building a kernel with threads greater than max_threads is an
+        /// error. If `clamp_threads` is true, threads are clamped silently instead.
+        /// Use [`Kernel::threads()`](Kernel::threads) to get the effective threads.
+        pub fn clamp_threads(self, clamp_threads: bool) -> Self;
+        /// Checks that the kernel is supported on `device`, without building it.
+        ///
+        /// # Errors
+        /// - `device` is the host.
+        /// - `device` doesn't have required features.
+        /// - Threads, push constants, or group memory exceed the limits of `device`.
+        pub fn supported_on(&self, device: &Device) -> Result<()>;
+        /// The SPIR-V capabilities declared by the kernel, ie "GroupNonUniformArithmetic".
+        ///
+        /// Capabilities are checked when the kernel is built, most require one of the
+        /// [`features`](Features). Kernels declaring a capability that krnl does not support
+        /// fail to build.
+        ///
+        /// # Errors
+        /// - The SPIR-V could not be loaded.
+        /// - Without the device feature.
+        pub fn required_capabilities(&self) -> Result<Vec<String>>;
+        /// The SPIR-V extensions declared by the kernel, ie "SPV_KHR_vulkan_memory_model".
+        ///
+        /// # Errors
+        /// - The SPIR-V could not be loaded.
+        /// - Without the device feature.
+        pub fn required_extensions(&self) -> Result<Vec<String>>;
+        /// Encodes the kernel, including its SPIR-V and spec constants, independent of any device.
+        ///
+        /// The kernel can be loaded in another process with
+        /// [`DynKernelBuilder::from_bytes()`](kernel::DynKernelBuilder::from_bytes).
+        pub fn to_bytes(&self) -> Vec<u8>;
         /// Builds the kernel for `device`.
         ///
         /// The kernel is cached, so subsequent calls to `.build()` with identical
@@ -385,6 +539,48 @@ pub mod saxpy {
         ///
         /// For item kernels, if not provided, is inferred based on item arguments.
         pub fn with_groups(self, groups: u32) -> Kernel<WithGroups<true>>;
+        /// Global threads to dispatch in 3 dimensions.
+        ///
+        /// Threads per group are 1d, so only the x dimension is rounded up to the
+        /// next multiple of threads.
+        pub fn with_global_threads_3d(self, global_threads: [u32; 3]) -> Kernel<WithGroups<true>>;
+        /// Groups to dispatch in 3 dimensions.
+        ///
+        /// Item kernels only support 1d groups.
+        pub fn with_groups_3d(self, groups: [u32; 3]) -> Kernel<WithGroups<true>>;
+        /// Cancels dispatches when `cancel_token` is cancelled.
+        ///
+        /// See [`CancelToken`](kernel::CancelToken).
+        pub fn with_cancel_token(self, cancel_token: CancelToken) -> Self;
+        /// Whether groups are inferred from item arguments.
+        ///
+        /// Otherwise groups must be provided with [`.with_groups()`](Self::with_groups) or
+        /// [`.with_global_threads()`](Self::with_global_threads).
+        pub fn is_item_kernel(&self) -> bool;
+        /// The name of the kernel, including threads and spec constants.
+        ///
+        /// ie `"crate::kernels::saxpy<threads=128>"`.
+        pub fn name(&self) -> &str;
+        /// The features required by the kernel.
+        pub fn features(&self) -> Features;
+        /// The spec constants, in declaration order.
+        pub fn spec_descs(&self) -> &[SpecDesc];
+        /// The slice arguments, both items and globals, in declaration order.
+        pub fn slice_descs(&self) -> &[SliceDesc];
+        /// The push constant arguments, sorted by descending size, the order they are packed in.
+        pub fn push_descs(&self) -> &[PushDesc];
+        /// The number of slice arguments, both items and globals.
+        pub fn slice_count(&self) -> usize;
+        /// The number of push constants.
+        pub fn push_count(&self) -> usize;
+        /// Disassembles the specialized SPIR-V, for debugging.
+        ///
+        /// Variable names are preserved by krnlc.
+        ///
+        /// # Errors
+        /// - krnl was built without the device feature.
+        /// - The SPIR-V could not be loaded.
+        pub fn disassemble(&self) -> Result<String>;
     }
 
     impl Kernel<WithGroups<true>> {
@@ -398,6 +594,53 @@ pub mod saxpy {
         /// - [`DeviceLost`].
         /// - The kernel could not be queued.
         pub fn dispatch(&self, alpha: f32, x: Slice<f32>, y: SliceMut<f32>) -> Result<()>;
+        /// Dispatches the kernel with owned buffers.
+        ///
+        /// Like [`.dispatch()`](Self::dispatch), but takes ownership of the buffers,
+        /// so that a dispatch can be built in one scope and executed in another, ie
+        /// on a worker thread. The buffers are returned once the kernel is queued.
+        ///
+        /// # Errors
+        /// - [`DeviceLost`].
+        /// - The kernel could not be queued.
+        pub fn dispatch_owned(&self, alpha: f32, x: Buffer<f32>, y: Buffer<f32>) -> Result<(Buffer<f32>, Buffer<f32>)>;
+        /// Dispatches the kernel without waiting for it to finish.
+        ///
+        /// Like [`.dispatch()`](Self::dispatch), but returns a [`DispatchHandle`](kernel::DispatchHandle)
+        /// that can be waited on. With debug printf, panics are reported by
+        /// [`DispatchHandle::wait()`](kernel::DispatchHandle::wait) instead.
+        ///
+        /// # Errors
+        /// - [`DeviceLost`].
+        /// - The kernel could not be queued.
+        pub fn dispatch_async(&self, alpha: f32, x: Slice<f32>, y: SliceMut<f32>) -> Result<DispatchHandle>;
+        /// Prepares a dispatch with slice arguments.
+        ///
+        /// The slices are validated and bound once, and the returned
+        /// [`PreparedDispatch`] can be dispatched repeatedly with different
+        /// push constants.
+        ///
+        /// # Errors
+        /// - [`DeviceLost`].
+        /// - The slices are invalid, see [`.dispatch()`](Self::dispatch).
+        pub fn prepare<'a>(&self, x: Slice<'a, f32>, y: SliceMut<'a, f32>) -> Result<PreparedDispatch<'a>>;
+    }
+
+    /// Prepared dispatch.
+    ///
+    /// See [`Kernel::prepare()`](Kernel::prepare).
+    pub struct PreparedDispatch<'a> { /* .. */ }
+
+    impl PreparedDispatch<'_> {
+        /// Dispatches the kernel.
+        ///
+        /// Like [`Kernel::dispatch()`](Kernel::dispatch), but reuses the slices
+        /// bound by [`Kernel::prepare()`](Kernel::prepare).
+        ///
+        /// # Errors
+        /// - [`DeviceLost`].
+        /// - The kernel could not be queued.
+        pub fn dispatch(&self, alpha: f32) -> Result<()>;
     }
 }
 # fn main() {}
@@ -474,6 +717,31 @@ in parallel, untill all groups have finished.
 
 Synchronization is automatically performed as necessary between kernels and when transfering buffers
 to and from devices. [`Device::wait()`](crate::device::Device::wait) can be used to explicitly wait for prior operations to complete.
+`.dispatch_async(..)` returns a [`DispatchHandle`] to wait on a specific dispatch instead. With debug printf,
+`.dispatch(..)` waits for the kernel to finish in order to report panics, while `.dispatch_async(..)`
+defers this to [`DispatchHandle::wait()`]. Panics are not reported if the handle is dropped without waiting.
+
+## Host
+`#[kernel(host)]` additionally compiles the kernel for the host, so that it can be built for
+[`Device::host()`](crate::device::Device::host) and dispatched with host buffers. Each thread of a group runs
+on an OS thread, which is reused by later dispatches, and groups are executed one after another. `.dispatch(..)` returns once all groups have
+finished. The body must compile for the host, so items imported only for the spirv arch must be imported
+within the kernel. Reductions can not be executed on the host.
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::{macros::kernel, buffer::{Slice, SliceMut}, device::Device, anyhow::Result};
+#[kernel(host)]
+fn saxpy(alpha: f32, #[item] x: f32, #[item] y: &mut f32) {
+    *y += alpha * x;
+}
+
+# fn foo(alpha: f32, x: Slice<f32>, y: SliceMut<f32>) -> Result<()> {
+saxpy::builder()?
+    .build(Device::host())?
+    .dispatch(alpha, x, y)
+# }
+# }
+```
 
 # SPIR-V
 [Binary intermediate representation](https://www.khronos.org/spir) for graphics shaders that can be used with [Vulkan](https://www.vulkan.org).
@@ -508,6 +776,7 @@ debug info, significantly increasing the size of both the cache and kernels at r
 
 The [DebugPrintf Validation Layer](https://github.com/KhronosGroup/Vulkan-ValidationLayers/blob/main/docs/debug_printf.md)
 must be active when the [device](crate::device::Device) is created or DebugPrintf instructions will be removed.
+It can be enabled with [`DeviceBuilder::enable_debug_printf()`](crate::device::builder::DeviceBuilder::enable_debug_printf).
 
 ```text
 [Device(0@7f6f3c9724d0) crate::kernels::foo<threads=1>] Validation Information: [ UNASSIGNED-DEBUG-PRINTF ]
@@ -547,37 +816,356 @@ Note: The validation layer can be configured to redirect messages to stdout. Thi
 and returning an error in case of a panic.
 */
 
+#[cfg(feature = "device")]
+use crate::device::RawDevice;
 use crate::{
     device::{Device, DeviceInner, Features},
     scalar::{ScalarElem, ScalarType},
 };
 use anyhow::{bail, Result};
-#[cfg(feature = "device")]
 use dry::macro_wrap;
+use parking_lot::RwLock;
 #[cfg(feature = "device")]
 use rspirv::{binary::Assemble, dr::Operand};
-use std::{borrow::Cow, sync::Arc};
 #[cfg(feature = "device")]
+use std::hash::Hash;
 use std::{
+    borrow::Cow,
     collections::HashMap,
-    hash::Hash,
-    sync::atomic::{AtomicBool, Ordering},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, OnceLock,
+    },
 };
 
+fn default_threads_registry() -> &'static RwLock<HashMap<String, u32>> {
+    static DEFAULT_THREADS: OnceLock<RwLock<HashMap<String, u32>>> = OnceLock::new();
+    DEFAULT_THREADS.get_or_init(Default::default)
+}
+
+/** Sets the default threads for a kernel.
+
+`kernel_path` is the module path of the kernel, ie "my_crate::kernels::foo", so kernels with the same
+name in different modules have separate defaults. Each kernel module also has a `set_default_threads()`
+fn for its kernel. Kernels built without `.with_threads()` will use `threads` instead of
+[`DeviceInfo::default_threads()`](crate::device::DeviceInfo::default_threads). [`None`] removes the override.
+
+This allows loading tuned threads, for example from a config file, without changing
+each build site. Kernels that are already built are not affected. */
+pub fn set_default_threads(kernel_path: impl Into<String>, threads: Option<u32>) {
+    let kernel_path = kernel_path.into();
+    let mut registry = default_threads_registry().write();
+    if let Some(threads) = threads {
+        registry.insert(kernel_path, threads);
+    } else {
+        registry.remove(&kernel_path);
+    }
+}
+
+/// The default threads for a kernel.
+///
+/// See [`set_default_threads()`].
+pub fn default_threads(kernel_path: &str) -> Option<u32> {
+    default_threads_registry().read().get(kernel_path).copied()
+}
+
+/** A token for cancelling dispatches before they are queued.
+
+Dispatches block until they are queued, for example while waiting on previous work that
+uses the same slices. A dispatch with a cancelled token is skipped, returning `Ok(())` without
+modifying its slices. Dispatches that are already queued are not affected.
+
+Tokens can be cloned, which is equivalent to [`Arc::clone()`]. */
+#[derive(Clone, Default, Debug)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Creates a new token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Cancels dispatches using the token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+    /// Whether the token is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/** A handle to a queued dispatch.
+
+Returned by `.dispatch_async(..)`, which queues the kernel without waiting for it to finish.
+Waiting on the handle is equivalent to [`Device::wait()`](crate::device::Device::wait), but only
+up to the dispatch. Dropping the handle without waiting does not cancel the dispatch.
+
+With debug printf, panics of the kernel are only reported by [`.wait()`](Self::wait). Dropping the
+handle without waiting discards them, use `.dispatch(..)` to always report panics. */
+#[must_use]
+pub struct DispatchHandle {
+    #[cfg(feature = "device")]
+    inner: Option<RawDispatchHandle>,
+}
+
+#[cfg(feature = "device")]
+struct RawDispatchHandle {
+    device: RawDevice,
+    epoch: u64,
+    kernel_name: Cow<'static, str>,
+    debug_printf_panic: Option<Arc<AtomicBool>>,
+}
+
+impl DispatchHandle {
+    /// Blocks until the kernel has finished.
+    ///
+    /// # Errors
+    /// - [`DeviceLost`](crate::device::error::DeviceLost).
+    /// - The kernel panicked, with debug printf enabled.
+    pub fn wait(self) -> Result<()> {
+        #[cfg(feature = "device")]
+        if let Some(inner) = self.inner {
+            let RawDispatchHandle {
+                device,
+                epoch,
+                kernel_name,
+                debug_printf_panic,
+            } = inner;
+            device.wait_epoch(epoch)?;
+            if let Some(debug_printf_panic) = debug_printf_panic {
+                while Arc::strong_count(&debug_printf_panic) > 1 {
+                    std::thread::yield_now();
+                }
+                if debug_printf_panic.load(Ordering::SeqCst) {
+                    bail!("Kernel `{kernel_name}` panicked!");
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/** A kernel loaded at runtime.
+
+Kernels declared with `#[kernel]` can be encoded with `.to_bytes()` of their builder, ie to
+ship them to another process, and loaded with [`DynKernelBuilder::from_bytes()`]. The types of
+arguments are only known at runtime, and are checked when the kernel is dispatched.
+
+Like kernels embedded in the binary, loaded kernels live for the rest of the program. Loading the
+same bytes again returns the same kernel, which reuses the kernels already built for each device.
+```no_run
+# use krnl::{anyhow::Result, buffer::{Slice, SliceMut}, device::Device, kernel::DynKernelBuilder};
+# fn foo(bytes: &[u8], device: Device, x: Slice<f32>, y: SliceMut<f32>) -> Result<()> {
+let kernel = DynKernelBuilder::from_bytes(bytes)?.build(device)?;
+// Safety: the kernel is a safe kernel with an item slice `x` and a mutable item slice `y`.
+unsafe { kernel.dispatch(&[x.into(), y.into()], &[]) }
+# }
+``` */
+#[derive(Clone)]
+pub struct DynKernelBuilder {
+    inner: __private::KernelBuilder,
+}
+
+impl DynKernelBuilder {
+    /// Loads a kernel encoded with `.to_bytes()`.
+    ///
+    /// # Errors
+    /// - The bytes are invalid.
+    /// - The kernel was encoded by a different version of krnl.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let inner = __private::KernelBuilder::from_bytes(bytes).map_err(anyhow::Error::msg)?;
+        Ok(Self { inner })
+    }
+    /// Encodes the kernel, see [`.from_bytes()`](Self::from_bytes).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.inner.to_bytes()
+    }
+    /// The name of the kernel.
+    pub fn name(&self) -> &str {
+        self.inner.name()
+    }
+    /// The spec constants, in declaration order.
+    pub fn spec_descs(&self) -> &[SpecDesc] {
+        self.inner.spec_descs()
+    }
+    /// Specializes the kernel with `spec_consts`, in the order of [`.spec_descs()`](Self::spec_descs).
+    ///
+    /// Spec constants are checked when the kernel is built.
+    pub fn specialize(self, spec_consts: &[ScalarElem]) -> Self {
+        Self {
+            inner: self.inner.specialize(spec_consts),
+        }
+    }
+    /// Threads per group.
+    ///
+    /// Defaults to [`DeviceInfo::default_threads()`](crate::device::DeviceInfo::default_threads).
+    pub fn with_threads(self, threads: u32) -> Self {
+        Self {
+            inner: self.inner.with_threads(threads),
+        }
+    }
+    /// Builds the kernel for `device`.
+    ///
+    /// # Errors
+    /// - The spec constants do not match [`.spec_descs()`](Self::spec_descs).
+    /// - `device` is the host.
+    /// - The kernel is not supported on `device`.
+    pub fn build(&self, device: Device) -> Result<DynKernel> {
+        Ok(DynKernel {
+            inner: self.inner.build(device)?,
+            groups: false,
+        })
+    }
+}
+
+/// A kernel built from a [`DynKernelBuilder`].
+#[derive(Clone)]
+pub struct DynKernel {
+    inner: __private::Kernel,
+    groups: bool,
+}
+
+impl DynKernel {
+    /// The name of the kernel.
+    pub fn name(&self) -> &str {
+        self.inner.name()
+    }
+    /// Threads per group.
+    pub fn threads(&self) -> u32 {
+        self.inner.threads()
+    }
+    /// The slice arguments, both items and globals, in declaration order.
+    pub fn slice_descs(&self) -> &[SliceDesc] {
+        self.inner.slice_descs()
+    }
+    /// The push constant arguments, sorted by descending size, the order they are packed in.
+    pub fn push_descs(&self) -> &[PushDesc] {
+        self.inner.push_descs()
+    }
+    /// Dispatches the kernel with `groups`.
+    ///
+    /// Required if the kernel has no item arguments.
+    pub fn with_groups(self, groups: u32) -> Self {
+        self.with_groups_3d([groups, 1, 1])
+    }
+    /// Dispatches the kernel with 3d `groups`.
+    pub fn with_groups_3d(self, groups: [u32; 3]) -> Self {
+        Self {
+            inner: self.inner.with_groups_3d(groups),
+            groups: true,
+        }
+    }
+    /// Dispatches the kernel.
+    ///
+    /// `slices` are passed in the order of [`.slice_descs()`](Self::slice_descs), and
+    /// `push_consts` in the order of [`.push_descs()`](Self::push_descs), not the order they
+    /// are declared in. See [`.dispatch()`](crate::kernel#dispatch).
+    ///
+    /// # Safety
+    /// The kernel may be unsafe, its safety requirements must be upheld.
+    ///
+    /// # Errors
+    /// - The arguments do not match [`.slice_descs()`](Self::slice_descs) and [`.push_descs()`](Self::push_descs).
+    /// - The kernel has no items, and groups were not provided.
+    /// - See [`.dispatch()`](crate::kernel#dispatch).
+    pub unsafe fn dispatch(
+        &self,
+        slices: &[KernelSliceArg],
+        push_consts: &[ScalarElem],
+    ) -> Result<()> {
+        let name = self.name();
+        let slice_descs = self.slice_descs();
+        if slices.len() != slice_descs.len() {
+            bail!(
+                "Kernel `{name}` expected {} slices, found {}!",
+                slice_descs.len(),
+                slices.len()
+            );
+        }
+        for (slice, slice_desc) in slices.iter().zip(slice_descs) {
+            let (scalar_type, mutable) = match slice {
+                KernelSliceArg::Slice(x) => (x.scalar_type(), false),
+                KernelSliceArg::SliceMut(x) => (x.scalar_type(), true),
+            };
+            if scalar_type != slice_desc.scalar_type || mutable != slice_desc.mutable {
+                bail!(
+                    "Kernel `{name}`.`{}` expected {}{:?}, found {}{scalar_type:?}!",
+                    slice_desc.name,
+                    if slice_desc.mutable { "mut " } else { "" },
+                    slice_desc.scalar_type,
+                    if mutable { "mut " } else { "" },
+                );
+            }
+        }
+        let push_descs = self.push_descs();
+        if push_consts.len() != push_descs.len() {
+            bail!(
+                "Kernel `{name}` expected {} push constants, found {}!",
+                push_descs.len(),
+                push_consts.len()
+            );
+        }
+        for (push_const, push_desc) in push_consts.iter().zip(push_descs) {
+            let scalar_type = push_const.scalar_type();
+            if scalar_type != push_desc.scalar_type {
+                bail!(
+                    "Kernel `{name}`.`{}` expected {:?}, found {scalar_type:?}!",
+                    push_desc.name,
+                    push_desc.scalar_type
+                );
+            }
+        }
+        if !self.groups && !slice_descs.iter().any(|x| x.item) {
+            bail!("Kernel `{name}` has no items, groups must be provided!");
+        }
+        unsafe { self.inner.dispatch(slices, push_consts) }
+    }
+}
+
 #[cfg_attr(not(feature = "device"), allow(dead_code))]
 #[derive(Clone, Debug)]
 pub(crate) struct KernelDesc {
     pub(crate) name: Cow<'static, str>,
     pub(crate) spirv: Vec<u32>,
     features: Features,
-    pub(crate) threads: u32,
     spec_descs: &'static [SpecDesc],
     pub(crate) slice_descs: &'static [SliceDesc],
     push_descs: &'static [PushDesc],
 }
 
+impl KernelDesc {
+    // The name including threads and spec constants, ie "foo<threads=64, N=4>".
+    fn specialized_name(&self, threads: u32, spec_consts: &[ScalarElem]) -> Cow<'static, str> {
+        let mut spec_string = format!("threads={threads}");
+        use std::fmt::Write;
+        for (desc, spec) in self.spec_descs.iter().zip(spec_consts) {
+            if !spec_string.is_empty() {
+                spec_string.push_str(", ");
+            }
+            let n = desc.name;
+            macro_wrap!(match spec {
+                macro_for!($T in [U8, I8, U16, I16, F16, BF16, U32, I32, F32, U64, I64, F64] {
+                    ScalarElem::$T(x) => write!(&mut spec_string, "{n}={x}").unwrap(),
+                })
+                _ => unreachable!("{spec:?}"),
+            });
+        }
+        if !spec_string.is_empty() {
+            format!("{}<{spec_string}>", self.name).into()
+        } else {
+            self.name.clone()
+        }
+    }
+}
+
 #[cfg(feature = "device")]
 impl KernelDesc {
+    fn load_module(&self) -> Result<rspirv::dr::Module> {
+        rspirv::dr::load_words(&self.spirv)
+            .map_err(|e| anyhow::format_err!("Kernel `{}` failed to load: {e}", self.name))
+    }
     pub(crate) fn push_consts_range(&self) -> u32 {
         let mut size = 0;
         for push_desc in self.push_descs.iter() {
@@ -599,27 +1187,9 @@ impl KernelDesc {
         debug_printf: bool,
     ) -> Result<Self> {
         use rspirv::spirv::{Decoration, Op};
-        let mut module = rspirv::dr::load_words(&self.spirv).unwrap();
+        let mut module = self.load_module()?;
         let mut spec_ids = HashMap::<u32, u32>::with_capacity(spec_consts.len());
-        let mut spec_string = format!("threads={threads}");
-        use std::fmt::Write;
-        for (desc, spec) in self.spec_descs.iter().zip(spec_consts) {
-            if !spec_string.is_empty() {
-                spec_string.push_str(", ");
-            }
-            let n = desc.name;
-            macro_wrap!(match spec {
-                macro_for!($T in [U8, I8, U16, I16, F16, BF16, U32, I32, F32, U64, I64, F64] {
-                    ScalarElem::$T(x) => write!(&mut spec_string, "{n}={x}").unwrap(),
-                })
-                _ => unreachable!("{spec:?}"),
-            });
-        }
-        let name = if !spec_string.is_empty() {
-            format!("{}<{spec_string}>", self.name).into()
-        } else {
-            self.name.clone()
-        };
+        let name = self.specialized_name(threads, spec_consts);
         for inst in module.annotations.iter() {
             if inst.class.opcode == Op::Decorate {
                 if let [Operand::IdRef(id), Operand::Decoration(Decoration::SpecId), Operand::LiteralInt32(spec_id)] =
@@ -638,17 +1208,26 @@ impl KernelDesc {
                         } else if spec_id == spec_consts.len() {
                             ScalarElem::U32(threads)
                         } else {
-                            unreachable!("{inst:?}")
+                            bail!(
+                                "Kernel `{}` has unexpected {:?} with SpecId {spec_id}!",
+                                self.name,
+                                inst.class.opcode,
+                            );
                         };
+                        let (low, high) = spec_const_words(value);
                         match inst.operands.as_mut_slice() {
                             [Operand::LiteralInt32(a)] => {
-                                bytemuck::bytes_of_mut(a).copy_from_slice(value.as_bytes());
+                                *a = low;
                             }
                             [Operand::LiteralInt32(a), Operand::LiteralInt32(b)] => {
-                                bytemuck::bytes_of_mut(a).copy_from_slice(&value.as_bytes()[..8]);
-                                bytemuck::bytes_of_mut(b).copy_from_slice(&value.as_bytes()[9..]);
+                                *a = low;
+                                *b = high;
                             }
-                            _ => unreachable!("{:?}", inst.operands),
+                            operands => bail!(
+                                "Kernel `{}` has unsupported {:?} operands {operands:?}!",
+                                self.name,
+                                inst.class.opcode,
+                            ),
                         }
                     }
                 }
@@ -658,14 +1237,251 @@ impl KernelDesc {
             strip_debug_printf(&mut module);
         }
         let spirv = module.assemble();
+        #[cfg(feature = "spirv-validation")]
+        {
+            use anyhow::Context;
+            use spirv_tools::{val::Validator, TargetEnv};
+
+            let validator = spirv_tools::val::create(Some(TargetEnv::Vulkan_1_2));
+            validator
+                .validate(&spirv, None)
+                .with_context(|| format!("Kernel `{name}` failed validation!"))?;
+        }
         Ok(Self {
             name,
             spirv,
-            spec_descs: &[],
-            threads,
             ..self.clone()
         })
     }
+    // The size in bytes of group memory, ie Workgroup variables, with spec constants applied.
+    fn group_memory_size(&self, threads: u32, spec_consts: &[ScalarElem]) -> Result<u64> {
+        use rspirv::spirv::{Decoration, Op, StorageClass};
+        let name = &self.name;
+        let module = self.load_module()?;
+        let mut spec_ids = HashMap::<u32, usize>::new();
+        for inst in module.annotations.iter() {
+            if inst.class.opcode == Op::Decorate {
+                if let [Operand::IdRef(id), Operand::Decoration(Decoration::SpecId), Operand::LiteralInt32(spec_id)] =
+                    inst.operands.as_slice()
+                {
+                    spec_ids.insert(*id, *spec_id as usize);
+                }
+            }
+        }
+        // (bits, signed) of integer and bool types
+        let mut int_types = HashMap::<u32, (u32, bool)>::new();
+        let mut sizes = HashMap::<u32, u64>::new();
+        let mut pointees = HashMap::<u32, u32>::new();
+        // (value, (bits, signed)) of integer and bool constants, zero extended
+        let mut values = HashMap::<u32, (u64, (u32, bool))>::new();
+        let mut size = 0u64;
+        for inst in module.types_global_values.iter() {
+            let op = inst.class.opcode;
+            let Some(result_id) = inst.result_id else {
+                continue;
+            };
+            let int_type = inst.result_type.and_then(|ty| int_types.get(&ty).copied());
+            match (op, inst.operands.as_slice()) {
+                (Op::TypeBool, _) => {
+                    int_types.insert(result_id, (1, false));
+                }
+                (Op::TypeInt, [Operand::LiteralInt32(bits), Operand::LiteralInt32(signed)]) => {
+                    int_types.insert(result_id, (*bits, *signed != 0));
+                    sizes.insert(result_id, u64::from(*bits / 8));
+                }
+                (Op::TypeFloat, [Operand::LiteralInt32(bits)]) => {
+                    sizes.insert(result_id, u64::from(*bits / 8));
+                }
+                (Op::TypeVector, [Operand::IdRef(ty), Operand::LiteralInt32(len)]) => {
+                    if let Some(elem_size) = sizes.get(ty).copied() {
+                        sizes.insert(result_id, elem_size * u64::from(*len));
+                    }
+                }
+                (Op::TypeArray, [Operand::IdRef(ty), Operand::IdRef(len)]) => {
+                    if let Some((elem_size, (len, _))) = sizes.get(ty).zip(values.get(len)) {
+                        sizes.insert(result_id, elem_size * len);
+                    }
+                }
+                (Op::TypeStruct, members) => {
+                    let member_sizes: Option<u64> = members
+                        .iter()
+                        .map(|x| match x {
+                            Operand::IdRef(ty) => sizes.get(ty).copied(),
+                            _ => None,
+                        })
+                        .sum();
+                    if let Some(struct_size) = member_sizes {
+                        sizes.insert(result_id, struct_size);
+                    }
+                }
+                (
+                    Op::TypePointer,
+                    [Operand::StorageClass(StorageClass::Workgroup), Operand::IdRef(pointee)],
+                ) => {
+                    pointees.insert(result_id, *pointee);
+                }
+                (Op::Constant | Op::SpecConstant, operands) => {
+                    let Some((bits, signed)) = int_type else {
+                        continue;
+                    };
+                    let value = match spec_ids.get(&result_id).copied() {
+                        Some(spec_id) if op == Op::SpecConstant => {
+                            let value = if let Some(value) = spec_consts.get(spec_id).copied() {
+                                value
+                            } else if spec_id == spec_consts.len() {
+                                ScalarElem::U32(threads)
+                            } else {
+                                bail!(
+                                    "Kernel `{name}` has unexpected {op:?} with SpecId {spec_id}!"
+                                );
+                            };
+                            let (low, high) = spec_const_words(value);
+                            u64::from(low) | u64::from(high) << 32
+                        }
+                        _ => match operands {
+                            [Operand::LiteralInt32(x)] => (*x).into(),
+                            [Operand::LiteralInt64(x)] => *x,
+                            _ => continue,
+                        },
+                    };
+                    values.insert(result_id, (truncate_int(value, bits), (bits, signed)));
+                }
+                (Op::ConstantTrue | Op::SpecConstantTrue, _) => {
+                    values.insert(result_id, (1, (1, false)));
+                }
+                (Op::ConstantFalse | Op::SpecConstantFalse, _) => {
+                    values.insert(result_id, (0, (1, false)));
+                }
+                (Op::SpecConstantOp, [Operand::LiteralInt32(opcode), args @ ..]) => {
+                    let Some((bits, signed)) = int_type else {
+                        continue;
+                    };
+                    let args: Option<Vec<_>> = args
+                        .iter()
+                        .map(|x| values.get(&x.id_ref_any()?).copied())
+                        .collect();
+                    let Some(args) = args else {
+                        continue;
+                    };
+                    if let Some(value) = eval_spec_constant_op(*opcode, &args) {
+                        values.insert(result_id, (truncate_int(value, bits), (bits, signed)));
+                    }
+                }
+                (Op::Variable, [Operand::StorageClass(StorageClass::Workgroup), ..]) => {
+                    let var_size = inst
+                        .result_type
+                        .and_then(|ty| pointees.get(&ty))
+                        .and_then(|pointee| sizes.get(pointee));
+                    let Some(var_size) = var_size else {
+                        bail!("Kernel `{name}` has group memory of unknown size!");
+                    };
+                    size += var_size;
+                }
+                _ => {}
+            }
+        }
+        Ok(size)
+    }
+}
+
+// Truncates to `bits`, zero extended.
+#[cfg(feature = "device")]
+fn truncate_int(value: u64, bits: u32) -> u64 {
+    if bits >= 64 {
+        value
+    } else {
+        value & ((1 << bits) - 1)
+    }
+}
+
+// Evaluates the integer ops that krnlc converts to OpSpecConstantOp. `args` are (value, (bits,
+// signed)), with values zero extended.
+#[cfg(feature = "device")]
+fn eval_spec_constant_op(opcode: u32, args: &[(u64, (u32, bool))]) -> Option<u64> {
+    use num_traits::FromPrimitive;
+    use rspirv::spirv::Op;
+
+    fn sign_extend((value, (bits, _)): (u64, (u32, bool))) -> i64 {
+        if bits >= 64 {
+            value as i64
+        } else {
+            let shift = 64 - bits;
+            ((value << shift) as i64) >> shift
+        }
+    }
+
+    let op = Op::from_u32(opcode)?;
+    let value = match (op, args) {
+        (Op::UConvert, [a]) => a.0,
+        (Op::SConvert, [a]) => sign_extend(*a) as u64,
+        (Op::SNegate, [a]) => sign_extend(*a).wrapping_neg() as u64,
+        (Op::Not, [a]) => !a.0,
+        (Op::LogicalNot, [a]) => u64::from(a.0 == 0),
+        (Op::Select, [c, a, b]) => {
+            if c.0 != 0 {
+                a.0
+            } else {
+                b.0
+            }
+        }
+        (op, [a, b]) => {
+            let (x, y) = (a.0, b.0);
+            let (sx, sy) = (sign_extend(*a), sign_extend(*b));
+            match op {
+                Op::IAdd => x.wrapping_add(y),
+                Op::ISub => x.wrapping_sub(y),
+                Op::IMul => x.wrapping_mul(y),
+                Op::UDiv => x.checked_div(y)?,
+                Op::SDiv => sx.checked_div(sy)? as u64,
+                Op::UMod => x.checked_rem(y)?,
+                Op::SRem => sx.checked_rem(sy)? as u64,
+                Op::SMod => {
+                    let r = sx.checked_rem(sy)?;
+                    if r != 0 && (r < 0) != (sy < 0) {
+                        r.wrapping_add(sy) as u64
+                    } else {
+                        r as u64
+                    }
+                }
+                Op::ShiftRightLogical => x.checked_shr(y.try_into().ok()?)?,
+                Op::ShiftRightArithmetic => sx.checked_shr(y.try_into().ok()?)? as u64,
+                Op::ShiftLeftLogical => x.checked_shl(y.try_into().ok()?)?,
+                Op::BitwiseOr | Op::LogicalOr => x | y,
+                Op::BitwiseXor => x ^ y,
+                Op::BitwiseAnd | Op::LogicalAnd => x & y,
+                Op::LogicalEqual | Op::IEqual => u64::from(x == y),
+                Op::LogicalNotEqual | Op::INotEqual => u64::from(x != y),
+                Op::ULessThan => u64::from(x < y),
+                Op::SLessThan => u64::from(sx < sy),
+                Op::UGreaterThan => u64::from(x > y),
+                Op::SGreaterThan => u64::from(sx > sy),
+                Op::ULessThanEqual => u64::from(x <= y),
+                Op::SLessThanEqual => u64::from(sx <= sy),
+                Op::UGreaterThanEqual => u64::from(x >= y),
+                Op::SGreaterThanEqual => u64::from(sx >= sy),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+// Literals are 32 bit words, low order first. Types narrower than 32 bits are
+// sign extended if signed, otherwise zero extended.
+#[cfg(feature = "device")]
+fn spec_const_words(value: ScalarElem) -> (u32, u32) {
+    match value {
+        ScalarElem::I8(x) => (x as i32 as u32, 0),
+        ScalarElem::I16(x) => (x as i32 as u32, 0),
+        _ => match value.to_scalar_bits() {
+            ScalarElem::U8(x) => (x.into(), 0),
+            ScalarElem::U16(x) => (x.into(), 0),
+            ScalarElem::U32(x) => (x, 0),
+            ScalarElem::U64(x) => (x as u32, (x >> 32) as u32),
+            _ => unreachable!("{value:?}"),
+        },
+    }
 }
 
 #[cfg(feature = "device")]
@@ -724,11 +1540,18 @@ pub mod __private {
 
     use super::*;
     #[cfg(feature = "device")]
-    use crate::device::{DeviceBuffer, RawKernel};
+    use crate::device::{DeviceBuffer, RawDescriptorSet, RawDevice, RawKernel};
     use crate::{
         buffer::{ScalarSlice, ScalarSliceMut, Slice, SliceMut},
         scalar::Scalar,
     };
+    use parking_lot::{Condvar, Mutex};
+    use std::{
+        any::Any,
+        cell::UnsafeCell,
+        panic::AssertUnwindSafe,
+        sync::mpsc::{channel, SendError, Sender},
+    };
 
     #[derive(Clone, Copy)]
     pub struct KernelDesc {
@@ -834,7 +1657,7 @@ pub mod __private {
                 if self.spec_descs.len() != spec_descs.len() {
                     return false;
                 }
-                let mut index = spec_descs.len();
+                let mut index = 0;
                 while index < spec_descs.len() {
                     if !self.spec_descs[index].const_eq(&spec_descs[index]) {
                         return false;
@@ -846,7 +1669,7 @@ pub mod __private {
                 if self.slice_descs.len() != slice_descs.len() {
                     return false;
                 }
-                let mut index = slice_descs.len();
+                let mut index = 0;
                 while index < slice_descs.len() {
                     if !self.slice_descs[index].const_eq(&slice_descs[index]) {
                         return false;
@@ -858,7 +1681,7 @@ pub mod __private {
                 if self.push_descs.len() != push_descs.len() {
                     return false;
                 }
-                let mut index = push_descs.len();
+                let mut index = 0;
                 while index < push_descs.len() {
                     if !self.push_descs[index].const_eq(&push_descs[index]) {
                         return false;
@@ -870,6 +1693,215 @@ pub mod __private {
         }
     }
 
+    // Encoding of KernelDesc, see KernelDesc::to_bytes.
+    const KERNEL_DESC_MAGIC: &[u8] = b"krnl-kernel";
+
+    fn write_bytes(output: &mut Vec<u8>, bytes: &[u8]) {
+        output.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        output.extend_from_slice(bytes);
+    }
+
+    fn read_bytes<'a>(input: &mut &'a [u8]) -> Result<&'a [u8], String> {
+        let len = read_u32(input)? as usize;
+        if input.len() < len {
+            return Err("Unexpected end of kernel desc!".to_string());
+        }
+        let (bytes, rest) = input.split_at(len);
+        *input = rest;
+        Ok(bytes)
+    }
+
+    fn read_u32(input: &mut &[u8]) -> Result<u32, String> {
+        if input.len() < 4 {
+            return Err("Unexpected end of kernel desc!".to_string());
+        }
+        let (bytes, rest) = input.split_at(4);
+        *input = rest;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn read_str<'a>(input: &mut &'a [u8]) -> Result<&'a str, String> {
+        std::str::from_utf8(read_bytes(input)?).map_err(|e| e.to_string())
+    }
+
+    fn write_scalar_elem(output: &mut Vec<u8>, elem: ScalarElem) {
+        let bits = match elem.to_scalar_bits() {
+            ScalarElem::U8(x) => x.into(),
+            ScalarElem::U16(x) => x.into(),
+            ScalarElem::U32(x) => x.into(),
+            ScalarElem::U64(x) => x,
+            _ => unreachable!("{elem:?}"),
+        };
+        output.extend_from_slice(&(elem.scalar_type() as u32).to_le_bytes());
+        output.extend_from_slice(&u64::to_le_bytes(bits));
+    }
+
+    fn read_scalar_elem(input: &mut &[u8]) -> Result<ScalarElem, String> {
+        use half::{bf16, f16};
+
+        let scalar_type = read_scalar_type(input)?;
+        let low = u64::from(read_u32(input)?);
+        let high = u64::from(read_u32(input)?);
+        let bits = low | high << 32;
+        let elem = match scalar_type {
+            ScalarType::U8 => ScalarElem::U8(bits as u8),
+            ScalarType::I8 => ScalarElem::I8(bits as i8),
+            ScalarType::U16 => ScalarElem::U16(bits as u16),
+            ScalarType::I16 => ScalarElem::I16(bits as i16),
+            ScalarType::F16 => ScalarElem::F16(f16::from_bits(bits as u16)),
+            ScalarType::BF16 => ScalarElem::BF16(bf16::from_bits(bits as u16)),
+            ScalarType::U32 => ScalarElem::U32(bits as u32),
+            ScalarType::I32 => ScalarElem::I32(bits as i32),
+            ScalarType::F32 => ScalarElem::F32(f32::from_bits(bits as u32)),
+            ScalarType::U64 => ScalarElem::U64(bits),
+            ScalarType::I64 => ScalarElem::I64(bits as i64),
+            ScalarType::F64 => ScalarElem::F64(f64::from_bits(bits)),
+            _ => {
+                return Err(format!(
+                    "Unexpected scalar_type {scalar_type:?} in kernel desc!"
+                ))
+            }
+        };
+        Ok(elem)
+    }
+
+    fn leak_str(string: &str) -> &'static str {
+        Box::leak(Box::<str>::from(string))
+    }
+
+    // Loaded kernels by their encoding. Loading the same bytes again returns the same desc, so
+    // that it is only leaked once, and its name, used as the id of the kernel, is the same.
+    fn loaded_kernel_descs() -> &'static Mutex<HashMap<Vec<u8>, KernelDesc>> {
+        static LOADED_KERNEL_DESCS: OnceLock<Mutex<HashMap<Vec<u8>, KernelDesc>>> = OnceLock::new();
+        LOADED_KERNEL_DESCS.get_or_init(Default::default)
+    }
+
+    fn read_scalar_type(input: &mut &[u8]) -> Result<ScalarType, String> {
+        let scalar_type = read_u32(input)?;
+        ScalarType::try_from(scalar_type)
+            .map_err(|_| format!("Unknown scalar_type {scalar_type} in kernel desc!"))
+    }
+
+    impl KernelDesc {
+        /// Encodes the kernel, including its SPIR-V, so that it can be loaded in
+        /// another process with [`from_bytes`](KernelDesc::from_bytes).
+        pub fn to_bytes(&self) -> Vec<u8> {
+            let mut output = Vec::new();
+            output.extend_from_slice(KERNEL_DESC_MAGIC);
+            write_bytes(&mut output, env!("CARGO_PKG_VERSION").as_bytes());
+            write_bytes(&mut output, self.name.as_bytes());
+            write_bytes(&mut output, self.spirv);
+            output.extend_from_slice(&self.features.bits().to_le_bytes());
+            output.extend_from_slice(&u32::from(self.safe).to_le_bytes());
+            output.extend_from_slice(&(self.spec_descs.len() as u32).to_le_bytes());
+            for spec_desc in self.spec_descs {
+                write_bytes(&mut output, spec_desc.name.as_bytes());
+                output.extend_from_slice(&(spec_desc.scalar_type as u32).to_le_bytes());
+            }
+            output.extend_from_slice(&(self.slice_descs.len() as u32).to_le_bytes());
+            for slice_desc in self.slice_descs {
+                write_bytes(&mut output, slice_desc.name.as_bytes());
+                output.extend_from_slice(&(slice_desc.scalar_type as u32).to_le_bytes());
+                output.extend_from_slice(&u32::from(slice_desc.mutable).to_le_bytes());
+                output.extend_from_slice(&u32::from(slice_desc.item).to_le_bytes());
+            }
+            output.extend_from_slice(&(self.push_descs.len() as u32).to_le_bytes());
+            for push_desc in self.push_descs {
+                write_bytes(&mut output, push_desc.name.as_bytes());
+                output.extend_from_slice(&(push_desc.scalar_type as u32).to_le_bytes());
+            }
+            output
+        }
+        /// Decodes a kernel encoded with [`to_bytes`](KernelDesc::to_bytes).
+        ///
+        /// Like kernels embedded in the binary, the desc lives for the rest of the program.
+        /// Loading the same bytes again returns the same desc.
+        ///
+        /// # Errors
+        /// - The bytes are invalid.
+        /// - The kernel was encoded by a different version of krnl.
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let mut loaded = loaded_kernel_descs().lock();
+            if let Some(desc) = loaded.get(bytes) {
+                return Ok(*desc);
+            }
+            let desc = Self::decode(bytes)?;
+            loaded.insert(bytes.to_vec(), desc);
+            Ok(desc)
+        }
+        fn decode(bytes: &[u8]) -> Result<Self, String> {
+            let mut input = bytes
+                .strip_prefix(KERNEL_DESC_MAGIC)
+                .ok_or_else(|| "Expected kernel desc!".to_string())?;
+            let input = &mut input;
+            let version = read_bytes(input)?;
+            if version != env!("CARGO_PKG_VERSION").as_bytes() {
+                return Err(format!(
+                    "Kernel desc version {:?} is not compatible with krnl {}!",
+                    String::from_utf8_lossy(version),
+                    env!("CARGO_PKG_VERSION"),
+                ));
+            }
+            let name = read_str(input)?;
+            let spirv = read_bytes(input)?;
+            let features = Features::new(read_u32(input)?);
+            let safe = read_u32(input)? != 0;
+            let mut spec_descs = Vec::new();
+            for _ in 0..read_u32(input)? {
+                spec_descs.push((read_str(input)?, read_scalar_type(input)?));
+            }
+            let mut slice_descs = Vec::new();
+            for _ in 0..read_u32(input)? {
+                slice_descs.push((
+                    read_str(input)?,
+                    read_scalar_type(input)?,
+                    read_u32(input)? != 0,
+                    read_u32(input)? != 0,
+                ));
+            }
+            let mut push_descs = Vec::new();
+            for _ in 0..read_u32(input)? {
+                push_descs.push((read_str(input)?, read_scalar_type(input)?));
+            }
+            if !input.is_empty() {
+                return Err("Unexpected trailing bytes in kernel desc!".to_string());
+            }
+            // only leaked once decoded successfully
+            let spec_descs = spec_descs
+                .into_iter()
+                .map(|(name, scalar_type)| SpecDesc {
+                    name: leak_str(name),
+                    scalar_type,
+                })
+                .collect::<Vec<_>>();
+            let slice_descs = slice_descs
+                .into_iter()
+                .map(|(name, scalar_type, mutable, item)| SliceDesc {
+                    name: leak_str(name),
+                    scalar_type,
+                    mutable,
+                    item,
+                })
+                .collect::<Vec<_>>();
+            let push_descs = push_descs
+                .into_iter()
+                .map(|(name, scalar_type)| PushDesc {
+                    name: leak_str(name),
+                    scalar_type,
+                })
+                .collect::<Vec<_>>();
+            Ok(Self {
+                name: leak_str(name),
+                spirv: Box::leak(Box::<[u8]>::from(spirv)),
+                features,
+                safe,
+                spec_descs: spec_descs.leak(),
+                slice_descs: slice_descs.leak(),
+                push_descs: push_descs.leak(),
+            })
+        }
+    }
+
     #[derive(Clone, Copy)]
     pub enum Safety {
         Safe,
@@ -882,50 +1914,109 @@ pub mod __private {
         }
     }
 
-    const fn scalar_type_const_eq(a: ScalarType, b: ScalarType) -> bool {
-        a as u32 == b as u32
-    }
-
+    /// A spec constant of a kernel.
+    ///
+    /// Returned by `.spec_descs()` of a built [kernel](crate::kernel#dispatch).
     #[derive(Clone, Copy, Debug)]
     pub struct SpecDesc {
-        pub name: &'static str,
-        pub scalar_type: ScalarType,
+        pub(crate) name: &'static str,
+        pub(crate) scalar_type: ScalarType,
     }
 
     impl SpecDesc {
+        #[doc(hidden)]
+        pub const fn new(name: &'static str, scalar_type: ScalarType) -> Self {
+            Self { name, scalar_type }
+        }
+        /// The name of the const generic.
+        pub fn name(&self) -> &'static str {
+            self.name
+        }
+        /// The type of the spec constant.
+        pub fn scalar_type(&self) -> ScalarType {
+            self.scalar_type
+        }
         const fn const_eq(&self, other: &Self) -> bool {
             bytes_eq(self.name.as_bytes(), other.name.as_bytes())
-                && scalar_type_const_eq(self.scalar_type, other.scalar_type)
+                && self.scalar_type.const_eq(&other.scalar_type)
         }
     }
 
+    /// A slice argument of a kernel.
+    ///
+    /// Returned by `.slice_descs()` of a built [kernel](crate::kernel#dispatch).
     #[derive(Clone, Copy, Debug)]
     pub struct SliceDesc {
-        pub name: &'static str,
-        pub scalar_type: ScalarType,
-        pub mutable: bool,
-        pub item: bool,
+        pub(crate) name: &'static str,
+        pub(crate) scalar_type: ScalarType,
+        pub(crate) mutable: bool,
+        pub(crate) item: bool,
     }
 
     impl SliceDesc {
+        #[doc(hidden)]
+        pub const fn new(
+            name: &'static str,
+            scalar_type: ScalarType,
+            mutable: bool,
+            item: bool,
+        ) -> Self {
+            Self {
+                name,
+                scalar_type,
+                mutable,
+                item,
+            }
+        }
+        /// The name of the argument.
+        pub fn name(&self) -> &'static str {
+            self.name
+        }
+        /// The scalar type of the slice.
+        pub fn scalar_type(&self) -> ScalarType {
+            self.scalar_type
+        }
+        /// Whether the slice is mutable.
+        pub fn mutable(&self) -> bool {
+            self.mutable
+        }
+        /// Whether the slice is an `#[item]`, otherwise it is `#[global]`.
+        pub fn item(&self) -> bool {
+            self.item
+        }
         const fn const_eq(&self, other: &Self) -> bool {
             bytes_eq(self.name.as_bytes(), other.name.as_bytes())
-                && scalar_type_const_eq(self.scalar_type, other.scalar_type)
+                && self.scalar_type.const_eq(&other.scalar_type)
                 && self.mutable == other.mutable
                 && self.item == other.item
         }
     }
 
+    /// A push constant argument of a kernel.
+    ///
+    /// Returned by `.push_descs()` of a built [kernel](crate::kernel#dispatch).
     #[derive(Clone, Copy, Debug)]
     pub struct PushDesc {
-        pub name: &'static str,
-        pub scalar_type: ScalarType,
+        pub(crate) name: &'static str,
+        pub(crate) scalar_type: ScalarType,
     }
 
     impl PushDesc {
+        #[doc(hidden)]
+        pub const fn new(name: &'static str, scalar_type: ScalarType) -> Self {
+            Self { name, scalar_type }
+        }
+        /// The name of the argument.
+        pub fn name(&self) -> &'static str {
+            self.name
+        }
+        /// The type of the push constant.
+        pub fn scalar_type(&self) -> ScalarType {
+            self.scalar_type
+        }
         const fn const_eq(&self, other: &Self) -> bool {
             bytes_eq(self.name.as_bytes(), other.name.as_bytes())
-                && scalar_type_const_eq(self.scalar_type, other.scalar_type)
+                && self.scalar_type.const_eq(&other.scalar_type)
         }
     }
 
@@ -933,10 +2024,35 @@ pub mod __private {
         use flate2::read::GzDecoder;
         use std::io::Read;
 
+        const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+        const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+        // the compression is detected from the magic number, uncompressed spirv is copied
         let mut output = Vec::new();
-        GzDecoder::new(bytemuck::cast_slice(input))
-            .read_to_end(&mut output)
-            .map_err(|e| format!("Kernel `{name}` failed to decode! {e}"))?;
+        if input.starts_with(&GZIP_MAGIC) {
+            GzDecoder::new(input)
+                .read_to_end(&mut output)
+                .map_err(|e| format!("Kernel `{name}` failed to decode! {e}"))?;
+        } else if input.starts_with(&ZSTD_MAGIC) {
+            #[cfg(feature = "zstd")]
+            {
+                ruzstd::decoding::StreamingDecoder::new(input)
+                    .map_err(|e| format!("Kernel `{name}` failed to decode! {e}"))?
+                    .read_to_end(&mut output)
+                    .map_err(|e| format!("Kernel `{name}` failed to decode! {e}"))?;
+            }
+            #[cfg(not(feature = "zstd"))]
+            {
+                return Err(format!(
+                    "Kernel `{name}` is compressed with zstd, enable the `zstd` feature of krnl!"
+                ));
+            }
+        } else {
+            output.extend_from_slice(input);
+        }
+        if output.len() % 4 != 0 {
+            return Err(format!("Kernel `{name}` failed to decode! Invalid length."));
+        }
         let output = output
             .chunks_exact(4)
             .map(|x| u32::from_ne_bytes(x.try_into().unwrap()))
@@ -950,13 +2066,17 @@ pub mod __private {
     #[derive(Clone)]
     pub struct KernelBuilder {
         id: usize,
+        kernel_desc: KernelDesc,
         desc: Arc<super::KernelDesc>,
         spec_consts: Vec<ScalarElem>,
         threads: Option<u32>,
+        clamp_threads: bool,
+        host_fn: Option<HostFn>,
     }
 
     impl KernelBuilder {
         pub fn from_desc(desc: KernelDesc) -> Result<Self, String> {
+            let kernel_desc = desc;
             let KernelDesc {
                 name,
                 spirv,
@@ -971,57 +2091,236 @@ pub mod __private {
                 name: name.into(),
                 spirv,
                 features,
-                threads: 0,
                 spec_descs,
                 slice_descs,
                 push_descs,
             };
             Ok(Self {
+                // Names are unique per kernel, loaded kernels are deduplicated by from_bytes.
                 id: name.as_ptr() as usize,
+                kernel_desc,
                 desc: desc.into(),
                 spec_consts: Vec::new(),
                 threads: None,
+                clamp_threads: false,
+                host_fn: None,
             })
         }
+        // The desc followed by the spec constants, so that a specialized kernel is loaded
+        // specialized.
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+            let input = &mut &*bytes;
+            let builder = Self::from_desc(KernelDesc::from_bytes(read_bytes(input)?)?)?;
+            let mut spec_consts = Vec::new();
+            for _ in 0..read_u32(input)? {
+                spec_consts.push(read_scalar_elem(input)?);
+            }
+            if !input.is_empty() {
+                return Err("Unexpected trailing bytes in kernel desc!".to_string());
+            }
+            Ok(builder.specialize(&spec_consts))
+        }
+        pub fn to_bytes(&self) -> Vec<u8> {
+            let mut output = Vec::new();
+            write_bytes(&mut output, &self.kernel_desc.to_bytes());
+            output.extend_from_slice(&(self.spec_consts.len() as u32).to_le_bytes());
+            for spec_const in self.spec_consts.iter() {
+                write_scalar_elem(&mut output, *spec_const);
+            }
+            output
+        }
+        pub fn name(&self) -> &str {
+            &self.desc.name
+        }
+        pub fn spec_descs(&self) -> &[SpecDesc] {
+            self.desc.spec_descs
+        }
         pub fn with_threads(self, threads: u32) -> Self {
             Self {
                 threads: Some(threads),
                 ..self
             }
         }
-        pub fn specialize(self, spec_consts: &[ScalarElem]) -> Self {
-            debug_assert_eq!(spec_consts.len(), self.desc.spec_descs.len());
-            #[cfg(debug_assertions)]
-            for (spec_const, spec_desc) in
-                spec_consts.iter().copied().zip(self.desc.spec_descs.iter())
-            {
-                assert_eq!(spec_const.scalar_type(), spec_desc.scalar_type);
+        pub fn clamp_threads(self, clamp_threads: bool) -> Self {
+            Self {
+                clamp_threads,
+                ..self
             }
+        }
+        // Enables building for the host, see `#[kernel(host)]`.
+        pub fn with_host_fn(self, host_fn: HostFn) -> Self {
+            Self {
+                host_fn: Some(host_fn),
+                ..self
+            }
+        }
+        // Spec constants are validated in check_spec_consts.
+        pub fn specialize(self, spec_consts: &[ScalarElem]) -> Self {
             Self {
                 spec_consts: spec_consts.to_vec(),
                 ..self
             }
         }
+        pub fn with_group_buffer_len(self, name: &str, len: usize) -> Result<Self> {
+            let kernel = &self.desc.name;
+            let Some(index) = self
+                .desc
+                .spec_descs
+                .iter()
+                .position(|spec_desc| spec_desc.name == name)
+            else {
+                bail!("Kernel {kernel} has no spec constant `{name}`!");
+            };
+            let scalar_type = self.desc.spec_descs[index].scalar_type;
+            let spec_const = ScalarElem::U64(len as u64).scalar_cast(scalar_type);
+            if spec_const.cast::<u64>() != len as u64 {
+                bail!("Kernel {kernel} group buffer len {len} does not fit in spec constant `{name}: {scalar_type:?}`!");
+            }
+            let mut spec_consts = self.spec_consts;
+            spec_consts[index] = spec_const;
+            Ok(Self {
+                spec_consts,
+                ..self
+            })
+        }
+        fn check_spec_consts(&self) -> Result<()> {
+            let name = &self.desc.name;
+            let spec_descs = self.desc.spec_descs;
+            if self.spec_consts.len() != spec_descs.len() {
+                bail!(
+                    "Kernel `{name}` expected {} spec constants, found {}!",
+                    spec_descs.len(),
+                    self.spec_consts.len()
+                );
+            }
+            for (spec_const, spec_desc) in self.spec_consts.iter().zip(spec_descs) {
+                let scalar_type = spec_const.scalar_type();
+                if scalar_type != spec_desc.scalar_type {
+                    bail!(
+                        "Kernel `{name}` spec constant `{}` expected {:?}, found {scalar_type:?}!",
+                        spec_desc.name,
+                        spec_desc.scalar_type
+                    );
+                }
+            }
+            Ok(())
+        }
+        // Returns threads.
+        #[cfg(feature = "device")]
+        fn check_support(&self, device: &RawDevice) -> Result<u32> {
+            let desc = &self.desc;
+            let name = &desc.name;
+            let features = desc.features;
+            let info = device.info();
+            let device_features = info.features();
+            if !device_features.contains(features) {
+                bail!("Kernel {name} requires {features:?}, {device:?} has {device_features:?}!");
+            }
+            // features are recorded by krnlc, capabilities are checked in case they are not covered
+            for inst in desc.load_module()?.capabilities.iter() {
+                let capability = inst.operands[0].unwrap_capability();
+                match capability_features(capability) {
+                    Some(features) if !device_features.contains(features) => {
+                        bail!("Kernel `{name}` requires capability {capability:?}, {device:?} has {device_features:?}!");
+                    }
+                    Some(_) => (),
+                    None => {
+                        bail!("Kernel `{name}` requires capability {capability:?}, which is not supported!");
+                    }
+                }
+            }
+            // The SPIR-V version depends on `krnlc --spirv-target`.
+            if let Some(version) = desc.spirv.get(1) {
+                let spirv_version = ((version >> 16) & 0xff, (version >> 8) & 0xff);
+                let max_spirv_version = match info.api_version() {
+                    (1, 0, _) => (1, 0),
+                    (1, 1, _) => (1, 3),
+                    (1, 2, _) => (1, 5),
+                    _ => (1, 6),
+                };
+                if spirv_version > max_spirv_version {
+                    let (major, minor) = spirv_version;
+                    let (max_major, max_minor) = max_spirv_version;
+                    bail!("Kernel {name} requires SPIR-V {major}.{minor}, {device:?} supports up to SPIR-V {max_major}.{max_minor}!");
+                }
+            }
+            let mut threads = self
+                .threads
+                .or_else(|| default_threads(name))
+                .unwrap_or(info.default_threads());
+            let max_threads = info.max_threads();
+            if threads > max_threads && self.clamp_threads {
+                threads = max_threads;
+            }
+            if threads > max_threads {
+                bail!("Kernel {name} threads {threads} is greater than max_threads {max_threads}!");
+            }
+            let push_consts_size = desc.push_consts_range();
+            let max_push_constants_size = info.max_push_constants_size();
+            if push_consts_size > max_push_constants_size {
+                bail!("Kernel {name} push constants size {push_consts_size} is greater than max_push_constants_size {max_push_constants_size}!");
+            }
+            let group_memory_size = desc.group_memory_size(threads, &self.spec_consts)?;
+            let max_group_memory_size = info.max_group_memory_size();
+            if group_memory_size > max_group_memory_size.into() {
+                bail!("Kernel {name} group memory size {group_memory_size} is greater than max_group_memory_size {max_group_memory_size}!");
+            }
+            Ok(threads)
+        }
+        // Returns threads.
+        fn check_host_support(&self) -> Result<u32> {
+            let name = &self.desc.name;
+            if self.host_fn.is_none() {
+                bail!("Kernel `{name}` expected device, found host!");
+            }
+            let mut threads = self
+                .threads
+                .or_else(|| default_threads(name))
+                .unwrap_or(HOST_DEFAULT_THREADS);
+            if threads == 0 {
+                bail!("Kernel {name} threads must be greater than 0!");
+            }
+            if threads > HOST_MAX_THREADS && self.clamp_threads {
+                threads = HOST_MAX_THREADS;
+            }
+            if threads > HOST_MAX_THREADS {
+                bail!("Kernel {name} threads {threads} is greater than max_threads {HOST_MAX_THREADS}!");
+            }
+            Ok(threads)
+        }
+        pub fn supported_on(&self, device: &Device) -> Result<()> {
+            self.check_spec_consts()?;
+            match device.inner() {
+                DeviceInner::Host => self.check_host_support().map(|_| ()),
+                #[cfg(feature = "device")]
+                DeviceInner::Device(device) => self.check_support(device).map(|_| ()),
+            }
+        }
         pub fn build(&self, device: Device) -> Result<Kernel> {
+            self.check_spec_consts()?;
             match device.inner() {
                 DeviceInner::Host => {
-                    bail!("Kernel `{}` expected device, found host!", self.desc.name);
+                    let threads = self.check_host_support()?;
+                    let desc = super::KernelDesc {
+                        name: self.desc.specialized_name(threads, &self.spec_consts),
+                        ..(*self.desc).clone()
+                    };
+                    Ok(Kernel {
+                        inner: KernelInner::Host(HostKernel {
+                            desc: Arc::new(desc),
+                            spec_consts: self.spec_consts.clone().into(),
+                            host_fn: self.host_fn.unwrap(),
+                        }),
+                        threads,
+                        groups: None,
+                        cancel_token: None,
+                    })
                 }
                 #[cfg(feature = "device")]
                 DeviceInner::Device(device) => {
                     let desc = &self.desc;
-                    let name = &desc.name;
-                    let features = desc.features;
                     let info = device.info();
-                    let device_features = info.features();
-                    if !device_features.contains(features) {
-                        bail!("Kernel {name} requires {features:?}, {device:?} has {device_features:?}!");
-                    }
-                    let threads = self.threads.unwrap_or(info.default_threads());
-                    let max_threads = info.max_threads();
-                    if threads > max_threads {
-                        bail!("Kernel {name} threads {threads} is greater than max_threads {max_threads}!");
-                    }
+                    let threads = self.check_support(device)?;
                     let spec_bytes = self
                         .spec_consts
                         .iter()
@@ -1039,9 +2338,10 @@ pub mod __private {
                             .map(Arc::new)
                     })?;
                     Ok(Kernel {
-                        inner,
+                        inner: KernelInner::Device(inner),
                         threads,
                         groups: None,
+                        cancel_token: None,
                     })
                 }
             }
@@ -1049,164 +2349,806 @@ pub mod __private {
         pub fn features(&self) -> Features {
             self.desc.features
         }
+        pub fn required_capabilities(&self) -> Result<Vec<String>> {
+            #[cfg(feature = "device")]
+            {
+                let module = self.desc.load_module()?;
+                Ok(module
+                    .capabilities
+                    .iter()
+                    .map(|inst| format!("{:?}", inst.operands[0].unwrap_capability()))
+                    .collect())
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                bail!(
+                    "Kernel `{}` capabilities can not be read without the device feature!",
+                    self.desc.name
+                );
+            }
+        }
+        pub fn required_extensions(&self) -> Result<Vec<String>> {
+            #[cfg(feature = "device")]
+            {
+                let module = self.desc.load_module()?;
+                Ok(module
+                    .extensions
+                    .iter()
+                    .map(|inst| inst.operands[0].unwrap_literal_string().to_string())
+                    .collect())
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                bail!(
+                    "Kernel `{}` extensions can not be read without the device feature!",
+                    self.desc.name
+                );
+            }
+        }
+    }
+
+    // The features required by a capability that krnlc may emit, None if krnl does not support
+    // the capability.
+    #[cfg(feature = "device")]
+    fn capability_features(capability: rspirv::spirv::Capability) -> Option<Features> {
+        use rspirv::spirv::Capability::*;
+
+        let features = match capability {
+            Shader | VulkanMemoryModel => Features::empty(),
+            Int8 => Features::INT8,
+            Int16 => Features::INT16,
+            Int64 => Features::INT64,
+            Float16 => Features::FLOAT16,
+            Float64 => Features::FLOAT64,
+            StorageBuffer8BitAccess => Features::BUFFER8,
+            StorageBuffer16BitAccess => Features::BUFFER16,
+            StoragePushConstant8 => Features::PUSH_CONSTANT8,
+            StoragePushConstant16 => Features::PUSH_CONSTANT16,
+            GroupNonUniform => Features::SUBGROUP_BASIC,
+            GroupNonUniformVote => Features::SUBGROUP_VOTE,
+            GroupNonUniformArithmetic => Features::SUBGROUP_ARITHMETIC,
+            GroupNonUniformBallot => Features::SUBGROUP_BALLOT,
+            GroupNonUniformShuffle => Features::SUBGROUP_SHUFFLE,
+            GroupNonUniformShuffleRelative => Features::SUBGROUP_SHUFFLE_RELATIVE,
+            GroupNonUniformClustered => Features::SUBGROUP_CLUSTERED,
+            GroupNonUniformQuad => Features::SUBGROUP_QUAD,
+            _ => return None,
+        };
+        Some(features)
     }
 
     pub enum WithGroups<const G: bool> {}
 
     #[derive(Clone)]
-    pub struct Kernel {
+    enum KernelInner {
         #[cfg(feature = "device")]
-        inner: RawKernel,
+        Device(RawKernel),
+        Host(HostKernel),
+    }
+
+    #[derive(Clone)]
+    pub struct Kernel {
+        inner: KernelInner,
         threads: u32,
-        #[cfg(feature = "device")]
-        groups: Option<u32>,
+        groups: Option<[u32; 3]>,
+        cancel_token: Option<CancelToken>,
     }
 
     impl Kernel {
+        fn desc(&self) -> &super::KernelDesc {
+            match &self.inner {
+                #[cfg(feature = "device")]
+                KernelInner::Device(raw) => raw.desc(),
+                KernelInner::Host(host) => &host.desc,
+            }
+        }
+        #[cfg(feature = "device")]
+        fn raw(&self) -> &RawKernel {
+            match &self.inner {
+                KernelInner::Device(raw) => raw,
+                KernelInner::Host(_) => unreachable!(),
+            }
+        }
         pub fn threads(&self) -> u32 {
             self.threads
         }
         pub fn with_global_threads(self, global_threads: u32) -> Self {
-            #[cfg(feature = "device")]
-            {
-                let desc = &self.inner.desc();
-                let threads = desc.threads;
-                let groups = global_threads / threads + u32::from(global_threads % threads != 0);
-                self.with_groups(groups)
+            self.with_global_threads_3d([global_threads, 1, 1])
+        }
+        pub fn with_global_threads_3d(self, global_threads: [u32; 3]) -> Self {
+            let threads = self.threads;
+            let [x, y, z] = global_threads;
+            let groups = x / threads + u32::from(x % threads != 0);
+            self.with_groups_3d([groups, y, z])
+        }
+        pub fn with_groups(self, groups: u32) -> Self {
+            self.with_groups_3d([groups, 1, 1])
+        }
+        pub fn with_groups_3d(self, groups: [u32; 3]) -> Self {
+            Self {
+                groups: Some(groups),
+                ..self
             }
-            #[cfg(not(feature = "device"))]
-            {
-                let _ = global_threads;
-                unreachable!()
+        }
+        pub fn with_cancel_token(self, cancel_token: CancelToken) -> Self {
+            Self {
+                cancel_token: Some(cancel_token),
+                ..self
             }
         }
-        pub fn with_groups(self, groups: u32) -> Self {
-            #[cfg(feature = "device")]
-            {
-                Self {
-                    groups: Some(groups),
-                    ..self
+        fn is_cancelled(&self) -> bool {
+            self.cancel_token
+                .as_ref()
+                .is_some_and(CancelToken::is_cancelled)
+        }
+        #[cfg(feature = "device")]
+        fn dispatch_args(&self, slices: &[KernelSliceArg]) -> Result<DispatchArgs> {
+            let desc = &self.raw().desc();
+            let kernel_name = &desc.name;
+            let mut buffers = Vec::with_capacity(desc.slice_descs.len());
+            let mut items: Option<u32> = None;
+            let device = self.raw().device();
+            let mut slice_push_bytes = Vec::with_capacity(2 * 4 * desc.slice_descs.len());
+            for (slice, slice_desc) in slices.iter().zip(desc.slice_descs.iter()) {
+                debug_assert_eq!(slice.scalar_type(), slice_desc.scalar_type);
+                debug_assert!(!slice_desc.mutable || slice.mutable());
+                let slice_name = &slice_desc.name;
+                // empty items are a no-op, unless groups are provided
+                if slice.len() == 0 && !(slice_desc.item && self.groups.is_none()) {
+                    bail!("Kernel `{kernel_name}`.`{slice_name}` is empty!");
+                }
+                let buffer = if let Some(buffer) = slice.device_buffer() {
+                    buffer
+                } else {
+                    bail!("Kernel `{kernel_name}`.`{slice_name}` expected device, found host!");
+                };
+                let buffer_device = buffer.device();
+                if device != buffer_device {
+                    bail!(
+                        "Kernel `{kernel_name}`.`{slice_name}`, expected `{device:?}`, found {buffer_device:?}!"
+                    );
+                }
+                buffers.push(buffer.clone());
+                if slice_desc.item {
+                    items.replace(if let Some(items) = items {
+                        items.min(slice.len() as u32)
+                    } else {
+                        slice.len() as u32
+                    });
                 }
+                let width = slice_desc.scalar_type.size();
+                let offset = buffer.offset() / width;
+                let len = buffer.len() / width;
+                slice_push_bytes.extend_from_slice(&offset.to_u32().unwrap().to_ne_bytes());
+                slice_push_bytes.extend_from_slice(&len.to_u32().unwrap().to_ne_bytes());
             }
-            #[cfg(not(feature = "device"))]
+            let max_groups = device.info().max_groups_3d();
+            let groups = if let Some(groups) = self.groups {
+                if groups.iter().zip(max_groups).any(|(g, max)| *g > max) {
+                    bail!("Kernel `{kernel_name}` groups {groups:?} is greater than max_groups {max_groups:?}!");
+                }
+                // item kernels index items with the x dimension only
+                if items.is_some() && groups[1..] != [1, 1] {
+                    bail!(
+                        "Kernel `{kernel_name}` with items expected 1d groups, found {groups:?}!"
+                    );
+                }
+                groups
+            } else if let Some(items) = items {
+                let threads = self.threads;
+                let groups = items / threads + u32::from(items % threads != 0);
+                [groups.min(max_groups[0]), 1, 1]
+            } else {
+                unreachable!("groups not provided!")
+            };
+            Ok(DispatchArgs {
+                buffers,
+                slice_push_bytes,
+                groups,
+            })
+        }
+        #[cfg(feature = "device")]
+        unsafe fn dispatch_async_impl(
+            &self,
+            args: &DispatchArgs,
+            descriptor_set: Option<&RawDescriptorSet>,
+            push_consts: &[ScalarElem],
+        ) -> Result<DispatchHandle> {
+            let skipped = DispatchHandle { inner: None };
+            if args.groups.contains(&0) {
+                return Ok(skipped);
+            }
+            let cancelled = self.cancel_token.as_ref().map(|x| &*x.cancelled);
+            if cancelled.is_some_and(|x| x.load(Ordering::SeqCst)) {
+                return Ok(skipped);
+            }
+            let desc = &self.raw().desc();
+            let kernel_name = &desc.name;
+            let device = self.raw().device();
+            let push_consts_range = desc.push_consts_range() as usize;
+            let mut push_bytes = Vec::with_capacity(push_consts_range);
+            debug_assert_eq!(push_consts.len(), desc.push_descs.len());
+            for (push, push_desc) in push_consts.iter().zip(desc.push_descs.iter()) {
+                debug_assert_eq!(push.scalar_type(), push_desc.scalar_type);
+                debug_assert_eq!(push_bytes.len() % push.scalar_type().size(), 0);
+                push_bytes.extend_from_slice(push.as_bytes());
+            }
+            while push_bytes.len() % 4 != 0 {
+                push_bytes.push(0);
+            }
+            push_bytes.extend_from_slice(&args.slice_push_bytes);
+            // The pipeline layout declares push_consts_range, a mismatch would be a driver error.
+            if push_bytes.len() != push_consts_range {
+                bail!(
+                    "Kernel `{kernel_name}` push constants are {} bytes, expected {push_consts_range}!",
+                    push_bytes.len()
+                );
+            }
+            let debug_printf_panic = if device.info().debug_printf() {
+                Some(Arc::new(AtomicBool::default()))
+            } else {
+                None
+            };
+            unsafe {
+                self.raw().dispatch(
+                    args.groups,
+                    &args.buffers,
+                    descriptor_set,
+                    push_bytes,
+                    cancelled,
+                    debug_printf_panic.clone(),
+                )?;
+            }
+            // the kernel is queued in the frame with this epoch, or an earlier one
+            let epoch = device.epoch();
+            Ok(DispatchHandle {
+                inner: Some(RawDispatchHandle {
+                    device,
+                    epoch,
+                    kernel_name: kernel_name.clone(),
+                    debug_printf_panic,
+                }),
+            })
+        }
+        #[cfg(feature = "device")]
+        unsafe fn dispatch_impl(
+            &self,
+            args: &DispatchArgs,
+            descriptor_set: Option<&RawDescriptorSet>,
+            push_consts: &[ScalarElem],
+        ) -> Result<()> {
+            let handle = unsafe { self.dispatch_async_impl(args, descriptor_set, push_consts)? };
+            // panics are only reported once the kernel has finished
+            if handle
+                .inner
+                .as_ref()
+                .is_some_and(|x| x.debug_printf_panic.is_some())
             {
-                let _ = groups;
-                unreachable!()
+                handle.wait()?;
+            }
+            Ok(())
+        }
+        fn dispatch_host(
+            &self,
+            host: &HostKernel,
+            slices: &[KernelSliceArg],
+            push_consts: &[ScalarElem],
+        ) -> Result<()> {
+            let desc = &host.desc;
+            let kernel_name = &desc.name;
+            let mut host_slices = Vec::with_capacity(desc.slice_descs.len());
+            let mut items: Option<u32> = None;
+            for (slice, slice_desc) in slices.iter().zip(desc.slice_descs.iter()) {
+                let slice_name = &slice_desc.name;
+                // empty items are a no-op, unless groups are provided
+                if slice.len() == 0 && !(slice_desc.item && self.groups.is_none()) {
+                    bail!("Kernel `{kernel_name}`.`{slice_name}` is empty!");
+                }
+                let Some((ptr, len)) = slice.host_ptr() else {
+                    bail!("Kernel `{kernel_name}`.`{slice_name}` expected host, found device!");
+                };
+                host_slices.push(HostSlice { ptr, len });
+                if slice_desc.item {
+                    let len = slice.len() as u32;
+                    items.replace(items.map_or(len, |items| items.min(len)));
+                }
+            }
+            let groups = if let Some(groups) = self.groups {
+                // item kernels index items with the x dimension only
+                if items.is_some() && groups[1..] != [1, 1] {
+                    bail!(
+                        "Kernel `{kernel_name}` with items expected 1d groups, found {groups:?}!"
+                    );
+                }
+                groups
+            } else if let Some(items) = items {
+                let threads = self.threads;
+                [items / threads + u32::from(items % threads != 0), 1, 1]
+            } else {
+                unreachable!("groups not provided!")
+            };
+            if groups.contains(&0) || self.is_cancelled() {
+                return Ok(());
             }
+            let dispatch = HostDispatch {
+                spec_consts: &host.spec_consts,
+                slices: &host_slices,
+                push_consts,
+                groups,
+                threads: self.threads,
+            };
+            dispatch
+                .run(host.host_fn)
+                .map_err(|msg| anyhow::format_err!("Kernel `{kernel_name}` panicked! {msg}"))
         }
         pub unsafe fn dispatch(
             &self,
             slices: &[KernelSliceArg],
             push_consts: &[ScalarElem],
         ) -> Result<()> {
-            #[cfg(feature = "device")]
-            {
-                let desc = &self.inner.desc();
-                let kernel_name = &desc.name;
-                let mut buffers = Vec::with_capacity(desc.slice_descs.len());
-                let mut items: Option<u32> = None;
-                let device = self.inner.device();
-                let mut push_bytes = Vec::with_capacity(desc.push_consts_range() as usize);
-                debug_assert_eq!(push_consts.len(), desc.push_descs.len());
-                for (push, push_desc) in push_consts.iter().zip(desc.push_descs.iter()) {
-                    debug_assert_eq!(push.scalar_type(), push_desc.scalar_type);
-                    debug_assert_eq!(push_bytes.len() % push.scalar_type().size(), 0);
-                    push_bytes.extend_from_slice(push.as_bytes());
+            match &self.inner {
+                #[cfg(feature = "device")]
+                KernelInner::Device(_) => {
+                    let args = self.dispatch_args(slices)?;
+                    unsafe { self.dispatch_impl(&args, None, push_consts) }
                 }
-                while push_bytes.len() % 4 != 0 {
-                    push_bytes.push(0);
+                KernelInner::Host(host) => self.dispatch_host(host, slices, push_consts),
+            }
+        }
+        pub unsafe fn dispatch_async(
+            &self,
+            slices: &[KernelSliceArg],
+            push_consts: &[ScalarElem],
+        ) -> Result<DispatchHandle> {
+            match &self.inner {
+                #[cfg(feature = "device")]
+                KernelInner::Device(_) => {
+                    let args = self.dispatch_args(slices)?;
+                    unsafe { self.dispatch_async_impl(&args, None, push_consts) }
                 }
-                for (slice, slice_desc) in slices.iter().zip(desc.slice_descs.iter()) {
-                    debug_assert_eq!(slice.scalar_type(), slice_desc.scalar_type);
-                    debug_assert!(!slice_desc.mutable || slice.mutable());
-                    let slice_name = &slice_desc.name;
-                    if slice.len() == 0 {
-                        bail!("Kernel `{kernel_name}`.`{slice_name}` is empty!");
-                    }
-                    let buffer = if let Some(buffer) = slice.device_buffer() {
-                        buffer
+                // the host finishes the kernel before returning
+                KernelInner::Host(host) => {
+                    self.dispatch_host(host, slices, push_consts)?;
+                    Ok(DispatchHandle {
+                        #[cfg(feature = "device")]
+                        inner: None,
+                    })
+                }
+            }
+        }
+        pub unsafe fn prepare(&self, slices: &[KernelSliceArg]) -> Result<PreparedDispatch> {
+            match &self.inner {
+                #[cfg(feature = "device")]
+                KernelInner::Device(raw) => {
+                    let args = self.dispatch_args(slices)?;
+                    let descriptor_set = if !args.groups.contains(&0) && !args.buffers.is_empty() {
+                        Some(raw.descriptor_set(&args.buffers)?)
                     } else {
-                        bail!("Kernel `{kernel_name}`.`{slice_name}` expected device, found host!");
+                        None
                     };
-                    let buffer_device = buffer.device();
-                    if device != buffer_device {
-                        bail!(
-                            "Kernel `{kernel_name}`.`{slice_name}`, expected `{device:?}`, found {buffer_device:?}!"
-                        );
-                    }
-                    buffers.push(buffer.clone());
-                    if slice_desc.item {
-                        items.replace(if let Some(items) = items {
-                            items.min(slice.len() as u32)
-                        } else {
-                            slice.len() as u32
-                        });
-                    }
-                    let width = slice_desc.scalar_type.size();
-                    let offset = buffer.offset() / width;
-                    let len = buffer.len() / width;
-                    push_bytes.extend_from_slice(&offset.to_u32().unwrap().to_ne_bytes());
-                    push_bytes.extend_from_slice(&len.to_u32().unwrap().to_ne_bytes());
-                }
-                let info = self.inner.device().info().clone();
-                let max_groups = info.max_groups();
-                let groups = if let Some(groups) = self.groups {
-                    if groups > max_groups {
-                        bail!("Kernel `{kernel_name}` groups {groups} is greater than max_groups {max_groups}!");
-                    }
-                    groups
-                } else if let Some(items) = items {
-                    let threads = self.threads;
-                    let groups = items / threads + u32::from(items % threads != 0);
-                    groups.min(max_groups)
-                } else {
-                    unreachable!("groups not provided!")
-                };
-                let debug_printf_panic = if info.debug_printf() {
-                    Some(Arc::new(AtomicBool::default()))
-                } else {
-                    None
-                };
-                unsafe {
-                    self.inner.dispatch(
-                        groups,
-                        &buffers,
-                        push_bytes,
-                        debug_printf_panic.clone(),
-                    )?;
+                    Ok(PreparedDispatch {
+                        kernel: self.clone(),
+                        args,
+                        descriptor_set,
+                    })
                 }
-                if let Some(debug_printf_panic) = debug_printf_panic {
-                    device.wait()?;
-                    while Arc::strong_count(&debug_printf_panic) > 1 {
-                        std::thread::yield_now();
-                    }
-                    if debug_printf_panic.load(Ordering::SeqCst) {
-                        bail!("Kernel `{kernel_name}` panicked!");
-                    }
+                KernelInner::Host(host) => {
+                    let _ = slices;
+                    bail!(
+                        "Kernel `{}` can not be prepared on the host, use `.dispatch()` instead!",
+                        host.desc.name
+                    );
                 }
-                Ok(())
             }
+        }
+        pub fn features(&self) -> Features {
+            self.desc().features
+        }
+        pub fn name(&self) -> &str {
+            &self.desc().name
+        }
+        pub fn spec_descs(&self) -> &[SpecDesc] {
+            self.desc().spec_descs
+        }
+        pub fn slice_descs(&self) -> &[SliceDesc] {
+            self.desc().slice_descs
+        }
+        pub fn push_descs(&self) -> &[PushDesc] {
+            self.desc().push_descs
+        }
+        pub fn is_item_kernel(&self) -> bool {
+            self.desc().slice_descs.iter().any(|x| x.item)
+        }
+        pub fn slice_count(&self) -> usize {
+            self.desc().slice_descs.len()
+        }
+        pub fn push_count(&self) -> usize {
+            self.desc().push_descs.len()
+        }
+        pub fn disassemble(&self) -> Result<String> {
+            #[cfg(feature = "device")]
+            {
+                use rspirv::binary::Disassemble;
+
+                Ok(self.desc().load_module()?.disassemble())
+            }
+            // spirv is only loaded with the device feature
             #[cfg(not(feature = "device"))]
             {
-                let _ = (slices, push_consts);
-                unreachable!()
+                bail!(
+                    "Kernel `{}` can not be disassembled without the device feature!",
+                    self.desc().name
+                );
             }
         }
-        pub fn features(&self) -> Features {
+    }
+
+    #[cfg(feature = "device")]
+    #[derive(Clone)]
+    struct DispatchArgs {
+        buffers: Vec<DeviceBuffer>,
+        slice_push_bytes: Vec<u8>,
+        groups: [u32; 3],
+    }
+
+    // Slices are validated and bound to a descriptor set once, only push constants
+    // are provided per dispatch.
+    #[derive(Clone)]
+    pub struct PreparedDispatch {
+        #[cfg(feature = "device")]
+        kernel: Kernel,
+        #[cfg(feature = "device")]
+        args: DispatchArgs,
+        #[cfg(feature = "device")]
+        descriptor_set: Option<RawDescriptorSet>,
+    }
+
+    impl PreparedDispatch {
+        pub unsafe fn dispatch(&self, push_consts: &[ScalarElem]) -> Result<()> {
             #[cfg(feature = "device")]
-            {
-                return self.inner.desc().features;
+            unsafe {
+                self.kernel
+                    .dispatch_impl(&self.args, self.descriptor_set.as_ref(), push_consts)
             }
             #[cfg(not(feature = "device"))]
             {
+                let _ = push_consts;
                 unreachable!()
             }
         }
     }
 
+    /* Kernels on the host.
+
+    `#[kernel(host)]` generates a function that executes one thread of the kernel. The threads
+    of a group are run concurrently on pooled threads, so that group barriers can block until
+    every thread of the group arrives. Each thread executes every group in order, and the last
+    thread to finish a group zeroes group memory before the next group starts. */
+
+    // Executes one thread of the kernel, generated by `#[kernel(host)]`.
+    pub type HostFn = fn(&HostThread);
+
+    // Each thread of a group is an os thread from HOST_THREADS.
+    const HOST_DEFAULT_THREADS: u32 = 64;
+    const HOST_MAX_THREADS: u32 = 1024;
+
+    #[derive(Clone)]
+    struct HostKernel {
+        desc: Arc<super::KernelDesc>,
+        spec_consts: Arc<[ScalarElem]>,
+        host_fn: HostFn,
+    }
+
+    #[derive(Clone, Copy)]
+    struct HostSlice {
+        ptr: *mut u8,
+        // bytes
+        len: usize,
+    }
+
+    // Threads write through the pointers like UnsafeSlice, slices are borrowed for the dispatch.
+    unsafe impl Send for HostSlice {}
+    unsafe impl Sync for HostSlice {}
+
+    struct HostDispatch<'a> {
+        spec_consts: &'a [ScalarElem],
+        slices: &'a [HostSlice],
+        push_consts: &'a [ScalarElem],
+        groups: [u32; 3],
+        threads: u32,
+    }
+
+    impl HostDispatch<'_> {
+        // Returns the message of the first panic.
+        fn run(&self, host_fn: HostFn) -> Result<(), String> {
+            let [groups_x, groups_y, groups_z] = self.groups;
+            let barrier = HostBarrier::new(self.threads);
+            let group = HostGroup::default();
+            let panic = Mutex::new(None);
+            let run_thread = |thread_id: u32| {
+                let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                    for z in 0..groups_z {
+                        for y in 0..groups_y {
+                            for x in 0..groups_x {
+                                host_fn(&HostThread {
+                                    dispatch: self,
+                                    group: &group,
+                                    barrier: &barrier,
+                                    group_id: [x, y, z],
+                                    thread_id,
+                                });
+                                barrier.wait_with(|| group.zero());
+                            }
+                        }
+                    }
+                }));
+                if let Err(payload) = result {
+                    if !payload.is::<HostBarrierPoisoned>() {
+                        panic.lock().get_or_insert_with(|| panic_message(&*payload));
+                    }
+                    barrier.poison();
+                }
+            };
+            if let Err(e) = host_scope(self.threads, &run_thread, &|| barrier.poison()) {
+                panic
+                    .lock()
+                    .get_or_insert_with(|| format!("Failed to spawn thread! {e}"));
+            }
+            match panic.into_inner() {
+                Some(msg) => Err(msg),
+                None => Ok(()),
+            }
+        }
+    }
+
+    type HostJob = Box<dyn FnOnce() + Send>;
+
+    // Idle threads, reused between dispatches. Each waits for a job on its channel.
+    static HOST_THREADS: Mutex<Vec<Sender<HostJob>>> = Mutex::new(Vec::new());
+
+    // Runs `f(thread_id)` for each thread concurrently, blocking until all have finished. Threads
+    // are taken from HOST_THREADS, and spawned when there are not enough idle threads. If a thread
+    // can not be spawned, `abort` is called so that the started threads can finish. `f` must not
+    // unwind.
+    fn host_scope(threads: u32, f: &(dyn Fn(u32) + Sync), abort: &dyn Fn()) -> std::io::Result<()> {
+        let remaining = Arc::new((Mutex::new(threads), Condvar::new()));
+        // Safety: f is borrowed until every job has finished, see below.
+        let f: &'static (dyn Fn(u32) + Sync) = unsafe { std::mem::transmute(f) };
+        let mut result = Ok(());
+        for thread_id in 0..threads {
+            let job_remaining = remaining.clone();
+            let job: HostJob = Box::new(move || {
+                f(thread_id);
+                let (count, condvar) = &*job_remaining;
+                let mut count = count.lock();
+                *count -= 1;
+                if *count == 0 {
+                    condvar.notify_one();
+                }
+            });
+            let idle = HOST_THREADS.lock().pop();
+            let job = match idle {
+                Some(sender) => match sender.send(job) {
+                    Ok(()) => continue,
+                    Err(SendError(job)) => job,
+                },
+                None => job,
+            };
+            if let Err(e) = spawn_host_thread(job) {
+                abort();
+                *remaining.0.lock() -= threads - thread_id;
+                result = Err(e);
+                break;
+            }
+        }
+        let (count, condvar) = &*remaining;
+        let mut count = count.lock();
+        while *count > 0 {
+            condvar.wait(&mut count);
+        }
+        result
+    }
+
+    fn spawn_host_thread(job: HostJob) -> std::io::Result<()> {
+        let (sender, receiver) = channel::<HostJob>();
+        sender.send(job).unwrap();
+        std::thread::Builder::new()
+            .name("krnl-host".into())
+            .spawn(move || {
+                for job in receiver.iter() {
+                    job();
+                    HOST_THREADS.lock().push(sender.clone());
+                }
+            })?;
+        Ok(())
+    }
+
+    fn panic_message(payload: &(dyn Any + Send)) -> String {
+        if let Some(msg) = payload.downcast_ref::<&str>() {
+            msg.to_string()
+        } else if let Some(msg) = payload.downcast_ref::<String>() {
+            msg.clone()
+        } else {
+            "Box<dyn Any>".to_string()
+        }
+    }
+
+    // Group memory, allocated by the first thread to use it. The buffers are never moved,
+    // threads write through pointers into them.
+    #[derive(Default)]
+    struct HostGroup {
+        buffers: Mutex<Vec<Box<[UnsafeCell<u64>]>>>,
+    }
+
+    impl HostGroup {
+        fn buffer(&self, index: usize, bytes: usize) -> *mut u8 {
+            let mut buffers = self.buffers.lock();
+            if buffers.len() <= index {
+                buffers.resize_with(index + 1, Default::default);
+            }
+            let buffer = &mut buffers[index];
+            if buffer.is_empty() {
+                let words = (bytes + 7) / 8;
+                *buffer = (0..words.max(1)).map(|_| UnsafeCell::new(0)).collect();
+            }
+            UnsafeCell::raw_get(buffer.as_ptr()).cast()
+        }
+        // Called between groups, while the other threads are blocked.
+        fn zero(&self) {
+            for buffer in self.buffers.lock().iter() {
+                for x in buffer.iter() {
+                    unsafe {
+                        *x.get() = 0;
+                    }
+                }
+            }
+        }
+    }
+
+    // A barrier that can be poisoned when a thread panics, so that the other threads of the
+    // group unwind instead of blocking forever.
+    struct HostBarrier {
+        threads: u32,
+        state: Mutex<HostBarrierState>,
+        condvar: Condvar,
+    }
+
+    #[derive(Default)]
+    struct HostBarrierState {
+        waiting: u32,
+        generation: u64,
+        poisoned: bool,
+    }
+
+    // Unwinds threads blocked on a poisoned barrier.
+    struct HostBarrierPoisoned;
+
+    impl HostBarrier {
+        fn new(threads: u32) -> Self {
+            Self {
+                threads,
+                state: Mutex::default(),
+                condvar: Condvar::new(),
+            }
+        }
+        fn wait(&self) {
+            self.wait_with(|| {});
+        }
+        // The last thread to arrive calls `f` before the others are released.
+        fn wait_with(&self, f: impl FnOnce()) {
+            let mut state = self.state.lock();
+            if !state.poisoned {
+                let generation = state.generation;
+                state.waiting += 1;
+                if state.waiting == self.threads {
+                    f();
+                    state.waiting = 0;
+                    state.generation += 1;
+                    self.condvar.notify_all();
+                    return;
+                }
+                while state.generation == generation && !state.poisoned {
+                    self.condvar.wait(&mut state);
+                }
+                if state.generation != generation {
+                    return;
+                }
+            }
+            drop(state);
+            std::panic::resume_unwind(Box::new(HostBarrierPoisoned))
+        }
+        fn poison(&self) {
+            self.state.lock().poisoned = true;
+            self.condvar.notify_all();
+        }
+    }
+
+    unsafe fn host_group_barrier(barrier: *const ()) {
+        let barrier = unsafe { &*barrier.cast::<HostBarrier>() };
+        barrier.wait();
+    }
+
     #[doc(hidden)]
+    pub struct HostThread<'a> {
+        dispatch: &'a HostDispatch<'a>,
+        group: &'a HostGroup,
+        barrier: &'a HostBarrier,
+        group_id: [u32; 3],
+        thread_id: u32,
+    }
+
+    impl HostThread<'_> {
+        pub fn kernel(&self) -> krnl_core::kernel::Kernel {
+            let threads = self.dispatch.threads;
+            let group_id = self.group_id;
+            let thread_id = self.thread_id;
+            unsafe {
+                krnl_core::kernel::__private::KernelArgs {
+                    global_id: [group_id[0] * threads + thread_id, group_id[1], group_id[2]],
+                    groups: self.dispatch.groups,
+                    group_id,
+                    // each thread is a subgroup
+                    subgroups: threads,
+                    subgroup_id: thread_id,
+                    subgroup_thread_id: 0,
+                    threads,
+                    thread_id,
+                    group_barrier: krnl_core::kernel::__private::GroupBarrier {
+                        data: (self.barrier as *const HostBarrier).cast(),
+                        wait: host_group_barrier,
+                    },
+                }
+                .into_kernel()
+            }
+        }
+        pub fn spec<T: Scalar>(&self, index: usize) -> T {
+            self.dispatch.spec_consts[index].cast()
+        }
+        pub fn push<T: Scalar>(&self, index: usize) -> T {
+            self.dispatch.push_consts[index].cast()
+        }
+        /// # Safety
+        /// The slice at `index` must be a slice of `T`.
+        pub unsafe fn slice<T: Scalar>(&self, index: usize) -> krnl_core::buffer::Slice<T> {
+            let HostSlice { ptr, len } = self.dispatch.slices[index];
+            let slice = unsafe {
+                std::slice::from_raw_parts(ptr.cast_const().cast(), len / T::SCALAR_TYPE.size())
+            };
+            slice.into()
+        }
+        /// # Safety
+        /// The slice at `index` must be a mutable slice of `T`.
+        pub unsafe fn unsafe_slice<T: Scalar>(
+            &self,
+            index: usize,
+        ) -> krnl_core::buffer::UnsafeSlice<T> {
+            let HostSlice { ptr, len } = self.dispatch.slices[index];
+            unsafe {
+                krnl_core::buffer::UnsafeSlice::from_host_raw_parts(
+                    ptr.cast(),
+                    len / T::SCALAR_TYPE.size(),
+                )
+            }
+        }
+        /// # Safety
+        /// The group buffer at `index` must be a buffer of `T` with `len`, for all threads.
+        pub unsafe fn group_buffer<T: Scalar>(
+            &self,
+            index: usize,
+            len: usize,
+        ) -> krnl_core::buffer::UnsafeSlice<T> {
+            let ptr = self.group.buffer(index, len * T::SCALAR_TYPE.size());
+            unsafe { krnl_core::buffer::UnsafeSlice::from_host_raw_parts(ptr.cast(), len) }
+        }
+    }
+
+    /// A slice argument of a [`DynKernel`](super::DynKernel).
+    ///
+    /// Converted from [`Slice`] and [`SliceMut`] with [`From`].
     pub enum KernelSliceArg<'a> {
+        /// An immutable slice.
         Slice(ScalarSlice<'a>),
+        /// A mutable slice.
         SliceMut(ScalarSliceMut<'a>),
     }
 
@@ -1230,12 +3172,21 @@ pub mod __private {
                 Self::SliceMut(x) => x.device_buffer_mut(),
             }
         }
+    }
+
+    impl KernelSliceArg<'_> {
         fn len(&self) -> usize {
             match self {
                 Self::Slice(x) => x.len(),
                 Self::SliceMut(x) => x.len(),
             }
         }
+        fn host_ptr(&self) -> Option<(*mut u8, usize)> {
+            match self {
+                Self::Slice(x) => x.host_ptr(),
+                Self::SliceMut(x) => x.host_ptr_mut(),
+            }
+        }
     }
 
     impl<'a, T: Scalar> From<Slice<'a, T>> for KernelSliceArg<'a> {
@@ -1251,4 +3202,306 @@ pub mod __private {
     }
 }
 
-pub(crate) use __private::{PushDesc, SliceDesc, SpecDesc};
+pub use __private::{KernelSliceArg, PushDesc, SliceDesc, SpecDesc};
+
+#[cfg(test)]
+mod tests {
+    use super::__private::*;
+    use crate::{
+        device::{Device, Features},
+        scalar::{ScalarElem, ScalarType},
+    };
+
+    const SPEC_DESCS: &[SpecDesc] = &[SpecDesc {
+        name: "N",
+        scalar_type: ScalarType::U32,
+    }];
+    const SLICE_DESCS: &[SliceDesc] = &[SliceDesc {
+        name: "y",
+        scalar_type: ScalarType::U32,
+        mutable: true,
+        item: false,
+    }];
+
+    fn kernel_desc() -> KernelDesc {
+        KernelDesc::from_args(KernelDescArgs {
+            name: "foo",
+            spirv: &[],
+            features: Features::empty(),
+            safe: true,
+            spec_descs: SPEC_DESCS,
+            slice_descs: SLICE_DESCS,
+            push_descs: &[],
+        })
+    }
+
+    #[test]
+    fn validate_kernel_declaration() {
+        let kernel = validate_kernel(
+            Some(Some(kernel_desc())),
+            Safety::Safe,
+            SPEC_DESCS,
+            SLICE_DESCS,
+            &[],
+        );
+        assert!(kernel.is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "recompile with krnlc")]
+    fn validate_kernel_slice_scalar_type_mismatch() {
+        let slice_descs = [SliceDesc {
+            scalar_type: ScalarType::F32,
+            ..SLICE_DESCS[0]
+        }];
+        validate_kernel(
+            Some(Some(kernel_desc())),
+            Safety::Safe,
+            SPEC_DESCS,
+            &slice_descs,
+            &[],
+        );
+    }
+
+    #[test]
+    fn from_desc_zstd() {
+        const ZSTD_EMPTY_FRAME: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd, 0x00, 0x00, 0x01, 0x00, 0x00];
+
+        let desc = KernelDesc::from_args(KernelDescArgs {
+            name: "foo",
+            spirv: ZSTD_EMPTY_FRAME,
+            features: Features::empty(),
+            safe: true,
+            spec_descs: &[],
+            slice_descs: &[],
+            push_descs: &[],
+        });
+        let result = KernelBuilder::from_desc(desc);
+        #[cfg(feature = "zstd")]
+        result.unwrap();
+        #[cfg(not(feature = "zstd"))]
+        assert!(result.err().unwrap().contains("`zstd` feature"));
+    }
+
+    #[test]
+    fn build_clamp_threads() {
+        fn host_fn(_: &HostThread) {}
+
+        let builder = KernelBuilder::from_desc(kernel_desc())
+            .unwrap()
+            .specialize(&[ScalarElem::U32(1)])
+            .with_threads(u32::MAX)
+            .with_host_fn(host_fn);
+        assert!(builder.build(Device::host()).is_err());
+        let kernel = builder.clamp_threads(true).build(Device::host()).unwrap();
+        assert!(kernel.threads() < u32::MAX);
+    }
+
+    #[cfg(feature = "device")]
+    #[test]
+    fn disassemble() {
+        use rspirv::{binary::Assemble, spirv::Capability};
+
+        fn host_fn(_: &HostThread) {}
+
+        fn build(spirv: &'static [u8]) -> Kernel {
+            let desc = KernelDesc::from_args(KernelDescArgs {
+                name: "foo",
+                spirv,
+                features: Features::empty(),
+                safe: true,
+                spec_descs: &[],
+                slice_descs: &[],
+                push_descs: &[],
+            });
+            KernelBuilder::from_desc(desc)
+                .unwrap()
+                .with_host_fn(host_fn)
+                .build(Device::host())
+                .unwrap()
+        }
+
+        let mut builder = rspirv::dr::Builder::new();
+        builder.capability(Capability::Shader);
+        let spirv: Vec<u8> = builder
+            .module()
+            .assemble()
+            .iter()
+            .flat_map(|x| x.to_ne_bytes())
+            .collect();
+        let kernel = build(spirv.leak());
+        let disassembly = kernel.disassemble().unwrap();
+        assert!(disassembly.contains("OpCapability Shader"), "{disassembly}");
+        let kernel = build(&[0; 4]);
+        assert!(kernel.disassemble().is_err());
+    }
+
+    #[cfg(feature = "device")]
+    #[test]
+    fn required_capabilities() {
+        use rspirv::{binary::Assemble, spirv::Capability};
+
+        let mut builder = rspirv::dr::Builder::new();
+        builder.capability(Capability::Shader);
+        builder.capability(Capability::GroupNonUniformArithmetic);
+        builder.extension("SPV_KHR_vulkan_memory_model");
+        let spirv: Vec<u8> = builder
+            .module()
+            .assemble()
+            .iter()
+            .flat_map(|x| x.to_ne_bytes())
+            .collect();
+        let desc = KernelDesc::from_args(KernelDescArgs {
+            name: "foo",
+            spirv: spirv.leak(),
+            features: Features::empty(),
+            safe: true,
+            spec_descs: &[],
+            slice_descs: &[],
+            push_descs: &[],
+        });
+        let builder = KernelBuilder::from_desc(desc).unwrap();
+        assert_eq!(
+            builder.required_capabilities().unwrap(),
+            ["Shader", "GroupNonUniformArithmetic"]
+        );
+        assert_eq!(
+            builder.required_extensions().unwrap(),
+            ["SPV_KHR_vulkan_memory_model"]
+        );
+    }
+
+    #[test]
+    fn host_dispatch_group_barrier() {
+        use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+        static ARRIVED: AtomicU32 = AtomicU32::new(0);
+        static FAILED: AtomicBool = AtomicBool::new(false);
+
+        fn host_fn(thread: &HostThread) {
+            let kernel = thread.kernel();
+            ARRIVED.fetch_add(1, Ordering::SeqCst);
+            unsafe {
+                kernel.group_barrier();
+            }
+            // every thread of this group has arrived, and none of the next group
+            let arrived = ARRIVED.load(Ordering::SeqCst);
+            if arrived != (kernel.group_id() as u32 + 1) * kernel.threads() as u32 {
+                FAILED.store(true, Ordering::SeqCst);
+            }
+            unsafe {
+                kernel.group_barrier();
+            }
+        }
+
+        let desc = KernelDesc::from_args(KernelDescArgs {
+            name: "foo",
+            spirv: &[],
+            features: Features::empty(),
+            safe: true,
+            spec_descs: &[],
+            slice_descs: &[],
+            push_descs: &[],
+        });
+        let kernel = KernelBuilder::from_desc(desc)
+            .unwrap()
+            .with_threads(4)
+            .with_host_fn(host_fn)
+            .build(Device::host())
+            .unwrap()
+            .with_groups(3);
+        // threads are reused by the second dispatch
+        for _ in 0..2 {
+            ARRIVED.store(0, Ordering::SeqCst);
+            unsafe {
+                kernel.dispatch(&[], &[]).unwrap();
+            }
+            assert_eq!(ARRIVED.load(Ordering::SeqCst), 3 * 4);
+        }
+        assert!(!FAILED.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn kernel_desc_bytes_roundtrip() {
+        let builder = KernelBuilder::from_desc(kernel_desc()).unwrap();
+        let bytes = builder.to_bytes();
+        let loaded = KernelBuilder::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.to_bytes(), bytes);
+        assert_eq!(loaded.name(), "foo");
+        let spec_descs: Vec<_> = loaded
+            .spec_descs()
+            .iter()
+            .map(|x| (x.name, x.scalar_type))
+            .collect();
+        assert_eq!(spec_descs, [("N", ScalarType::U32)]);
+        // loading the same bytes again returns the same desc
+        assert!(std::ptr::eq(
+            loaded.spec_descs().as_ptr(),
+            KernelBuilder::from_bytes(&bytes)
+                .unwrap()
+                .spec_descs()
+                .as_ptr()
+        ));
+        let desc_bytes = kernel_desc().to_bytes();
+        let desc = KernelDesc::from_bytes(&desc_bytes).unwrap();
+        assert_eq!(desc.to_bytes(), desc_bytes);
+        assert!(KernelDesc::from_bytes(&desc_bytes[..desc_bytes.len() - 1]).is_err());
+        assert!(KernelBuilder::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn specialized_kernel_bytes_roundtrip() {
+        fn host_fn(_: &HostThread) {}
+
+        let builder = KernelBuilder::from_desc(kernel_desc())
+            .unwrap()
+            .specialize(&[ScalarElem::U32(7)]);
+        let bytes = builder.to_bytes();
+        let loaded = KernelBuilder::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.to_bytes(), bytes);
+        // the loaded kernel is already specialized
+        let kernel = loaded.with_host_fn(host_fn).build(Device::host()).unwrap();
+        assert!(kernel.name().contains("N=7"), "{}", kernel.name());
+        assert_eq!(kernel.spec_descs().len(), 1);
+    }
+
+    #[test]
+    fn build_spec_const_count_mismatch() {
+        let builder = KernelBuilder::from_desc(kernel_desc()).unwrap();
+        let error = builder.build(Device::host()).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Kernel `foo` expected 1 spec constants, found 0!"
+        );
+        let builder = builder.specialize(&[ScalarElem::U32(1), ScalarElem::U32(2)]);
+        let error = builder.build(Device::host()).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Kernel `foo` expected 1 spec constants, found 2!"
+        );
+    }
+
+    #[test]
+    fn default_threads_by_path() {
+        use super::{default_threads, set_default_threads};
+
+        set_default_threads("a::default_threads_by_path", Some(8));
+        assert_eq!(default_threads("a::default_threads_by_path"), Some(8));
+        assert_eq!(default_threads("b::default_threads_by_path"), None);
+        assert_eq!(default_threads("default_threads_by_path"), None);
+        set_default_threads("a::default_threads_by_path", None);
+        assert_eq!(default_threads("a::default_threads_by_path"), None);
+    }
+
+    #[test]
+    fn build_spec_const_type_mismatch() {
+        let builder = KernelBuilder::from_desc(kernel_desc())
+            .unwrap()
+            .specialize(&[ScalarElem::I32(1)]);
+        let error = builder.build(Device::host()).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Kernel `foo` spec constant `N` expected U32, found I32!"
+        );
+    }
+}
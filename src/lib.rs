@@ -42,6 +42,8 @@ For device functionality (kernels), install [Vulkan](https://www.vulkan.org) for
 
 /// anyhow
 pub extern crate anyhow;
+#[cfg(all(not(target_family = "wasm"), feature = "device"))]
+pub extern crate ash;
 /// krnl-core
 pub extern crate krnl_core;
 /// krnl-macros
@@ -51,6 +53,14 @@ pub use krnl_core::half;
 /// Numerical types.
 #[doc(no_inline)]
 pub use krnl_core::scalar;
+/// Error type, re-exported from [anyhow].
+///
+/// Fallible functions return [`Result`], which can be propagated with `?`
+/// into [`anyhow::Result`] or any error type that implements `From<anyhow::Error>`.
+pub use anyhow::Error;
+
+/// Result type, with [`Error`] as the default error type.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 /// Buffers.
 pub mod buffer;
@@ -83,6 +83,43 @@ fn buffer_tests(device: &Device, device2: Option<&Device>) -> impl IntoIterator<
     let mut tests = Vec::new();
 
     tests.push(device_test(device, "buffer_from_vec", buffer_from_vec));
+    #[cfg(feature = "device")]
+    tests.push(device_test(device, "buffer_try_new", buffer_try_new));
+    tests.push(device_test(
+        device,
+        "buffer_write_visible_after_drop",
+        buffer_write_visible_after_drop,
+    ));
+    tests.push(device_test(device, "buffer_peek", buffer_peek));
+    tests.push(device_test(device, "device_batch", device_batch));
+    tests.push(device_test(
+        device,
+        "device_memory_usage",
+        device_memory_usage,
+    ));
+    tests.push(device_test(device, "buffer_fill_slice", buffer_fill_slice));
+    tests.push(device_test(device, "buffer_copy_from", buffer_copy_from));
+    tests.push(device_test(
+        device,
+        "buffer_copy_to_device",
+        buffer_copy_to_device,
+    ));
+    tests.push(device_test(
+        device,
+        "buffer_into_vec_async",
+        buffer_into_vec_async,
+    ));
+    tests.push(device_test(
+        device,
+        "buffer_into_device_async",
+        buffer_into_device_async,
+    ));
+    tests.push(device_test(
+        device,
+        "buffer_batch_ranges",
+        buffer_batch_ranges,
+    ));
+    tests.push(device_test(device, "buffer_ping_pong", buffer_ping_pong));
 
     if device.is_device() {
         #[cfg(feature = "device")]
@@ -93,6 +130,18 @@ fn buffer_tests(device: &Device, device2: Option<&Device>) -> impl IntoIterator<
                 Ok(())
             }
         }));
+        #[cfg(feature = "device")]
+        tests.push(device_test(
+            device,
+            "device_select_by_name",
+            device_select_by_name,
+        ));
+        tests.push(device_test(device, "device_list", device_list));
+        tests.push(device_test(device, "device_build_all", device_build_all));
+        tests.push(Trial::test("device_pipeline_cache_dir", || {
+            device_pipeline_cache_dir();
+            Ok(())
+        }));
         tests.push(
             Trial::test("buffer_device_to_device", {
                 let device = device.clone();
@@ -194,6 +243,249 @@ fn buffer_from_vec(device: Device) {
     }
 }
 
+fn buffer_write_visible_after_drop(device: Device) {
+    let n = buffer_test_lengths().last().unwrap();
+    let x = vec![0u32; n];
+    let mut x = Slice::from(x.as_slice()).to_device(device).unwrap();
+    {
+        let mut y = x.as_slice_mut();
+        y.fill(1).unwrap();
+    }
+    let y = x.as_slice().to_vec().unwrap();
+    assert!(y.iter().all(|y| *y == 1), "{y:?}");
+}
+
+fn buffer_fill_slice(device: Device) {
+    let x = vec![0u32; 10];
+    let mut x = Slice::from(x.as_slice()).to_device(device).unwrap();
+    x.slice_mut(2..7).unwrap().fill(1).unwrap();
+    let x = x.into_vec().unwrap();
+    assert_eq!(x, [0, 0, 1, 1, 1, 1, 1, 0, 0, 0]);
+}
+
+fn buffer_copy_from(device: Device) {
+    let x: Vec<u32> = (0..10).collect();
+    let x = Slice::from(x.as_slice()).to_device(device.clone()).unwrap();
+    let mut y = Slice::from([0u32; 5].as_slice())
+        .to_device(device.clone())
+        .unwrap();
+    y.slice_mut(1..4)
+        .unwrap()
+        .copy_from(&x.slice(2..5).unwrap())
+        .unwrap();
+    assert_eq!(y.to_vec().unwrap(), [0, 2, 3, 4, 0]);
+    assert!(y.copy_from(&x.slice(..4).unwrap()).is_err());
+    if !device.is_host() {
+        let z = Slice::from([0u32; 5].as_slice())
+            .to_device(Device::host())
+            .unwrap();
+        assert!(y.copy_from(&z.as_slice()).is_err());
+    }
+}
+
+fn buffer_copy_to_device(device: Device) {
+    let mut y = Slice::from([0u32; 4].as_slice())
+        .to_device(device.clone())
+        .unwrap();
+    for i in 0..3 {
+        let x: Vec<u32> = (i..i + 4).collect();
+        Slice::from(x.as_slice())
+            .copy_to_device(&mut y.as_slice_mut())
+            .unwrap();
+        assert_eq!(y.to_vec().unwrap(), x);
+    }
+    assert!(Slice::from([0u32; 3].as_slice())
+        .copy_to_device(&mut y.as_slice_mut())
+        .is_err());
+    if !device.is_host() {
+        let x = y.to_device(device.clone()).unwrap();
+        assert!(x.copy_to_device(&mut y.as_slice_mut()).is_err());
+    }
+}
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::{
+        sync::Arc,
+        task::{Context, Poll, Wake},
+        thread::Thread,
+    };
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        std::thread::park();
+    }
+}
+
+fn buffer_into_vec_async(device: Device) {
+    let n = buffer_test_lengths().last().unwrap();
+    let x = (10..20).cycle().take(n).collect::<Vec<u32>>();
+    for n in buffer_test_lengths() {
+        let x = &x[..n];
+        let y = Slice::from(x).to_device(device.clone()).unwrap();
+        let y = block_on(y.into_vec_async()).unwrap();
+        assert_eq!(x, y.as_slice());
+    }
+}
+
+fn buffer_into_device_async(device: Device) {
+    let n = buffer_test_lengths().last().unwrap();
+    let x = (10..20).cycle().take(n).collect::<Vec<u32>>();
+    for n in buffer_test_lengths() {
+        let x = &x[..n];
+        let y = krnl::buffer::Buffer::from(x.to_vec());
+        let y = block_on(y.into_device_async(device.clone())).unwrap();
+        assert_eq!(y.device(), device);
+        assert_eq!(x, y.to_vec().unwrap().as_slice());
+    }
+}
+
+fn device_batch(device: Device) {
+    let x: Vec<u32> = (0..100).collect();
+    let y = Slice::from(x.as_slice()).to_device(device.clone()).unwrap();
+    let y = device.batch(|| {
+        let y = y.cast::<f32>().unwrap();
+        let y = y.cast::<i32>().unwrap();
+        y.cast::<u32>().unwrap()
+    });
+    assert_eq!(y.into_vec().unwrap(), x);
+}
+
+#[cfg(feature = "device")]
+fn device_select_by_name(device: Device) {
+    let info = device.info().unwrap();
+    let selected = Device::builder()
+        .name(info.name())
+        .uuid(info.uuid())
+        .build()
+        .unwrap();
+    assert_eq!(selected.info().unwrap().uuid(), info.uuid());
+    // the index is among the matching devices
+    assert_eq!(selected.info().unwrap().index(), 0);
+    let error = Device::builder()
+        .name("krnl device that does not exist")
+        .build()
+        .err()
+        .unwrap();
+    assert!(error.is::<krnl::device::error::DeviceNotFound>(), "{error}");
+}
+
+fn device_list(device: Device) {
+    let info = device.info().unwrap();
+    let devices = Device::list().unwrap();
+    let available = devices.iter().find(|x| x.uuid() == info.uuid()).unwrap();
+    assert_eq!(available.name(), info.name());
+    assert!(available.features().contains(info.features()));
+    assert!(!available.memory_heaps().is_empty());
+    for (i, available) in devices.iter().enumerate() {
+        assert_eq!(available.index(), i);
+    }
+}
+
+fn device_build_all(device: Device) {
+    let info = device.info().unwrap();
+    let available = Device::list().unwrap();
+    let devices = Device::builder().build_all().unwrap();
+    assert!(devices.len() <= available.len());
+    assert!(devices
+        .iter()
+        .any(|x| x.info().unwrap().name() == info.name()));
+    for device in devices.iter() {
+        let name = device.info().unwrap().name().to_string();
+        assert!(available.iter().any(|x| x.name() == name));
+    }
+}
+
+fn device_pipeline_cache_dir() {
+    let dir = std::env::temp_dir().join(format!(
+        "krnl-device-pipeline-cache-dir-{}",
+        std::process::id()
+    ));
+    let cache_files = || std::fs::read_dir(&dir).unwrap().count();
+    for _ in 0..2 {
+        {
+            let device = Device::builder().pipeline_cache_dir(&dir).build().unwrap();
+            let x: Vec<u32> = (0..100).collect();
+            let y = Slice::from(x.as_slice())
+                .to_device(device)
+                .unwrap()
+                .cast::<f32>()
+                .unwrap();
+            assert_eq!(y.cast::<u32>().unwrap().into_vec().unwrap(), x);
+        }
+        // saved when the device is dropped, a single cache file is reused by the second device
+        assert_eq!(cache_files(), 1);
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+fn device_memory_usage(device: Device) {
+    if device.is_host() {
+        assert!(device.memory_usage().is_none());
+        return;
+    }
+    // allocations are tracked per device, other tests may allocate on the shared device
+    let uuid = device.info().unwrap().uuid();
+    let device = Device::builder().uuid(uuid).build().unwrap();
+    let usage = device.memory_usage().unwrap();
+    let x = Slice::from([0u32; 1000].as_slice())
+        .to_device(device.clone())
+        .unwrap();
+    let allocated = device.memory_usage().unwrap().allocated();
+    assert!(allocated >= usage.allocated() + 4000);
+    drop(x);
+    assert_eq!(device.memory_usage().unwrap().allocated(), usage.allocated());
+    assert!(usage.capacity() > 0);
+}
+
+fn buffer_peek(device: Device) {
+    let x: Vec<u32> = (0..10).collect();
+    let x = Slice::from(x.as_slice()).to_device(device).unwrap();
+    assert_eq!(x.peek(2..5).unwrap(), [2, 3, 4]);
+    assert!(x.peek(5..11).is_err());
+}
+
+fn buffer_batch_ranges(device: Device) {
+    let x: Vec<u32> = (0..10).collect();
+    let x = Slice::from(x.as_slice()).to_device(device).unwrap();
+    let batches = [x.slice(2..5).unwrap(), x.slice(7..).unwrap()];
+    assert_eq!(batches[1].offset_in(&x.as_slice()), Some(7));
+    let ranges = x.batch_ranges(&batches).unwrap().into_vec().unwrap();
+    assert_eq!(ranges, [2, 3, 7, 3]);
+    let y = Slice::from([0u32; 3].as_slice())
+        .to_device(x.device())
+        .unwrap();
+    assert!(x.batch_ranges(&[y.as_slice()]).is_err());
+}
+
+fn buffer_ping_pong(device: Device) {
+    use krnl::buffer::PingPong;
+
+    let mut buffers = PingPong::<u32>::new(device, 3).unwrap();
+    buffers.upload(&[1, 2, 3]).unwrap();
+    assert_eq!(buffers.active().to_vec().unwrap(), [0; 3]);
+    buffers.swap();
+    buffers.upload(&[4, 5, 6]).unwrap();
+    assert_eq!(buffers.active().to_vec().unwrap(), [1, 2, 3]);
+    assert_eq!(buffers.idle().to_vec().unwrap(), [4, 5, 6]);
+    assert!(buffers.upload(&[1]).is_err());
+    let [active, idle] = buffers.into_buffers();
+    assert_eq!(active.into_vec().unwrap(), [1, 2, 3]);
+    assert_eq!(idle.into_vec().unwrap(), [4, 5, 6]);
+}
+
 #[cfg(feature = "device")]
 fn device_buffer_too_large(device: Device) {
     use krnl::buffer::error::DeviceBufferTooLarge;
@@ -203,6 +495,20 @@ fn device_buffer_too_large(device: Device) {
     error.downcast_ref::<DeviceBufferTooLarge>().unwrap();
 }
 
+#[cfg(feature = "device")]
+fn buffer_try_new(device: Device) {
+    use krnl::buffer::error::DeviceBufferTooLarge;
+    let x = Buffer::<u32>::try_new(device.clone(), 10).unwrap();
+    assert_eq!(x.device(), device);
+    assert_eq!(x.into_vec().unwrap(), [0; 10]);
+    let error = Buffer::<u32>::try_new(device.clone(), usize::MAX)
+        .err()
+        .unwrap();
+    if device.is_device() {
+        error.downcast_ref::<DeviceBufferTooLarge>().unwrap();
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 fn buffer_transfer(device: Device, device2: Device) {
     let n = buffer_transfer_test_lengths().last().unwrap();
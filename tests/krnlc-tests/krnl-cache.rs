@@ -1,16 +1,27 @@
 __krnl_cache!("0.1.2-alpha", "
-abZy8000000@}T[378EDWxw!66+HQD0C90xqiTH)t?x-RG+#5)g6fll7L^bI1VGl&r9UI<kO$n/vp7)f0t<J5JzThAqdCLtyvWtfPWcbQYGV8/mGo&][5#5LMkPROj9w&F}FkA[pGpFss2oZwJfZOHWMXLE)Wh%=8:^NnZT[z-L@**zE0jxwYIt32:Srw<j2^6ZD/QN:6mmIubwp0drj7Q>hoW[#JXVf<}2fb:v#-kXymzPg82!fh}JlyzQEoNbSrvNkYx.NT.q76=4z(5Y?!IaIc+KhH&ss*+l*fNu.YuUL4-27V&=+&Y8?<#(.E9bJ1(-iwX#=M/ibMKBne+zIN$vXDNcQ]t7Fj7B.T}=iBGEBTHmeR0i?4t[IM+TAg1!}=E^zv&@*&siFrqSeKBYT!*Q8dfFLIJWRNo!h]]2%La+Pw0lO[r})6GiJu$h>zg@]7nn9PG7QTz1]!GNZ3kVYbw4LaAQdW[HKYuA%<BloZZ^l!c3w*T/SqDCp+?%5du=l5m9i6!/iLB<=@BlI*+vpqP>K&eh$x3S-GacgvAGPuIA]f.<ed0Dv5i*>YCK$qbmaxX[V86(l.sP%%*GSE^39#OI=mD>/J<W5DubMaV=PGu!t]+Vp68dOd#VB=#*8=MIW[aJZA4lJ(s@rz.l(eHIK^XTNG*ZRf<s+$/EX6IQ{Ey+cE[][:0wwDGAd<iQG7#o=4.ABPVjOg#sx?${}TK*!n!DG35!nYS([4we0SXE8#[U>tKnwvvNW*3V{4$t9={f2V6D8QRu2+NewEk($jf4v6J@SPK[lGNA-VBU0Z]OGuXLRkCqI&.K7GFb!QY)}uxhbTvp.-QP8E=kzR@{DA5lQ&<fPaGNm8NpT3O}X!gUmlO}.ZdX3XZS<PvAu2g/$Mhl!6TElD]Gj1kA49f{RB>hyZfl1A+FC9@G5a^()F6YBVg0b?5Vgt75sB8OWj!WC74FI4S<FsEIk]emGhy)z-z]@+@>5::$#t}9TF%M.UsJd
-XbqD13LuD?h?=KyDr#p1BaA!6sq8h?=I@SkH<<t*eKwernB5M{7Gm%Ffw+dDs{zPIre7E}l{zMiW&xJC4%o8{x9FczCJ.EF@PCA8Tl#]p>$-?6LqBO#3Bl9(S(^(fW#8>v1mMkRYCjow7q+kz}R[/RTwVw6!$rJIQ0E=njCU&jzy]Vjv7g*qPau>ZR0)Qz0I]q!(qVh[C3+lW@5S0d&sfF5n4E!z4:KL3S%j^Mk!GY:NDg6.Qbk&t><SyG6^{0kRZOmHNLAq%gLRul-.Z:37&3h>l^mF#SjrXuq.9PqKm]J.hRDXC2X(M=?</Osfa6ltR3V&wZ{Xg:QZX$a-TO(2&y@?nq@<${*<(di/sp{&LLF8gof{RaqaA>z*<&5t4g@AW[B[jm4$K3[S3za3-&ENeyaZEPD%4w59/#bBfy=1p5#aI#:#?Ke^O>jYl#2FsxIY4J=tRmoWa18CjDw?uzOz[PAp+>t9dnlnBu)dE<kTT9QBRCcG2+{LqAz>r[AU7c(hZK2:Ib}=[nWnt-(C%hEzJX?DOLr8fua4kZX@ZvZ)Ci%5Cr@Pc2CK.4FH{4[K&va5p3kJ*<p42iQT24EKqHQZ[(fI9%jW]9yOYG+5W:p}ixqok>(?E9F10G?[5?NtZO%?XWY$D-kNf8G$m03g*CAd8zi8?AQ:4*&>11(7XYpf)N{/jg48}sf7e4BALC$)XC[<nAMhBbtZP{<X(Du^wx.Rwy!MgLQ2OK39HzIv(=dp>?55hQ8:?U*w9yBu3)0@{BN3&((*V2pPfS8$90vqlfq)<qBNh}#u$U7.e:cu>fF:e3/twdDDaB]tST0&l9}pjkPmzwsWU3(Hc.L{>{ul!anU3+g-OW6?xtDFt]]<9:xijhpt^&<vxC@}Sn<*T!9VX(a9Oh$oBET+lJUr./AIvL%OysH]FA/O/{)E!BJ)3ey{]by3J?l*7sf3RODy#YFtomDIO?gEdmq6^Cp5F&6A*5K*9]$-s<!?lT}atyOTdQz/
-A3<MOm12I3^qluBHsvv&Z/XVZZ:pCw{d{N..W]u6vl1amyCMnAp=y0?bd[*t(MB8I1acwnU1sVp2F}YiD$9>RFNT9#rkc+QM**wMp9@+S&JZI]=pFH)OybN.e$vXTBk=VgYoDW(wi8EYAWQ+bl]7ajE4}R*9pykAa6(FfP]CQ(G3^p1e<6.*&><quiM1Ej&tYBI?(6Ks/[BvY0p65>RU-=#LHbwX@By[paBG9{mSI}<X3Vhcc{ek)=4PlTuuoLG!5&vSmbJ4zhb}[ck+DN?h<0}7DtougA09U1h*P)!6wzsch>{?LEV7ZR}$GM6&Jo467V6)cBN:LVSCXWO%d0py<dBzo&.5y1zymynvB3JN-b!M[GakC?KhE1l^KceIXaAmjP@]U+]b2+lYb+Rv^W!-BX9kv5%0.#7O<<=$oWwyzVV5MY&CW[Bk8lU]NDPHL]n7eB*@*IPY+HB6&Q)&yZj->1k>)^9X7=&nA^l#5?5X5-zPDch7X5l&:<ROu4sjq{^PmMTVgjenzMhBSUr?[B-!dR:MxQW#thE<Oa4D6PGyQR<h]B$k:M6DMY%<8fzGqn{{w2fU=Gn#@J3K[IlHFWDFZ2QG}5OJGPyi#XxV/H+LUYYwa6#YLk7xhvyPU+<KGR5)jKTb<4[MeS={>>]ly7ceE$QKu.U/izmGopP}wtOwQX#EKko[AEZ)6j]0>DIaR&UhtX!9LcdeKD:{zp]86lVXnscS2HLkMiTE/VA=/5@dbZv&ff}x%!2!C[AR(dUe9:A1mj@U][CcTP+SI2M>+DR&hGI#hHbG-s*iE*{qrm&qKUz4#OSVIK&D=D2@K=Iu9.}4iG?Lg78-@tbjYHWWIl]z:[owa2bxf>EPA){S!:I^RAa2^jVq81GhA7gCPJ+Ndz1xh1.@H[3gIs^p^PwX]dfMgg^XJh<W{JbPJGjpHEgw&lSf-YS4ROh45TRS:6DUnUiP0Nf*ma3(n:b7mD[+bQ%^yG=#Vw?y>l@N6O78Yk#l
-sQTh-3g/1f=Zq:RL@4Mxr]]oyHChe7h[XzRQAhbPay7l!K4o59D4G3Rw7FasxuQNkdF!!T!D^1KFy%}YHj[j%>}=]+T8=ix^w&Y>N?zQZN$T{+u]ea2q?QRL>1eaof&bkcxuTqQ:@dI?vt+6l[h.0}RVBac.LX2UoqG[3R^2Ax<@4jYJm<7xmkaL**w)#TfK2ZQNLrFuEA1y#S*>!Ps(Sf^VPR-OuBy2V1v+)?WNW?fvrL0lJm():Oh4STL@02N.w8@K&Dm4j=iv(ZGWg<^Bc5N}O[?uKNba<^8m8zkIOqP9]-N-Wv0k5I2vWr)}IYY>rxjMXz=L8IY:mTf7LC<ivdTFiz?G+^+!uSuf!Y)-xuVpn+7*<uvIp@s/S6rz>fE{Dn<yz]w4}u%}:pt@+Mx?2]6uk5Pia?+0[^jK^n302TFNUZEX-fLkEDv(X^u-6u3vWJorEjC21zz9{)CcL?OwtD[H?e9bF9M{AuBErWGAR<Bo/?sGFy?6piQ9op4nA<-sZ]!>>K1FpQVP]-PW.*J!zzarH*kc>T+lDW2c9tWB1Cb??(lNVwN9L7&oK6^<!atv6(RLs6JSQ?5tH4enELWGrsdu0(!@cf#LLpd}1(p[Rgk^5NuM4jvRmQ*BPx+eR8lsfTv?uV&ANpIKd//m%7/8wa14@W.]iagZct[.wEe9RZTqrqm}MZ9mp)yJYBGS/]Ap]^/plT2&=&7i1pynKk/n8-sZ}l^6o=JI!>)!-I&@#*jRi6dO.0(!tvF=T(tetflDK.rvuJQr=I<PQxUd=WtE(<7=r.#iib3k(Jng8C9Qfc=NIXju@OCh[M6Uf1evu++EruNX*%E:PvGa(Wn}n%10-BLUcT0:85Kk9z?p$e^m]sPEER.xxp&&L-@+p96jCc:6#{yotz^SSrufAVGPtjouY%/or=N?wjL@^Gh^abMHCsig-K<!M=oxCQ{TkA[:.C7=K[rOhtX{Z@WvG(EN-Bk[rEL$k]kz3KVX))}W][$(
-Q=EuET8*bBiLj4F+cdZ6&8D=GlCWYFx&:t8[8/sFsE5^P.JdJhVant-?iTH0EVg<^54p{uF8k[S[MZu2AKL914ZglaZWv!wXZ%sxBc(7!TJFNY9N1G/=X7X}ycd6<hQ!XYM2eMo)<umYf{]qH+e.)bD5+[nPvO96!ZE^7C9q?#L-+p@j/V$Rr{.iE!Bb]a^(gXicAR<yX4jiK]8a2rs+ujNZ]DhK=.Zjqvj[{4{A]y!bvtbD!IRzO6me39OC/Ug!rV?o5)+:uIVK&]W?O8%N:r*<A+zY#hOpxz.M8zAjoMQf6iY9I)^^{hyck/ot21/bEwbprPkgz7/ilAnUl?r#DtVC<Y&t@N32Lh4{W:w$dF=+V)/MHq?i#XaT7?c[=@Nh/M->mbwXov%?d>R*p3u2^A>SthV0aqEBNPm+S@cqpkoviZCys6F]18}jyEj6.!3WlRr+pRNkvENq@P3LI3^[$#YMLB@9K)+]medN.@.(<&vEP-j!4*u*)4gEi>x$vIRdWC*)4O(-@Y9KLXx=xwYaxBK<{J*+EA3VZS*>!JVmoIh?gNlNM=0OHf.LG/h]miY<%@#{f<?srxuTZ^L@4MrGEM+8H1CUaCPAfPa]A>cD&@Q?>9]AkJqmm&A(JG+fH=2h[oM2<zV/O.GNVvRZEw0T}hODnwb:=NOh5[EoWQP>uHF0REpf^E[oY&:D.!#Pa6s^:<@ZXE8s{kj6-xa+YAVwC.2a=5kn9aJKuNf6y/O=FF3{?t+UFHy]Q*(&xtBoJN4CJ0dpJhV>}?EeT&.KMZPtM}]k?+.@OLGT3T!t.+*c[28!5.8^R3=ST&.KM)]FLeXTb0s%cp*QF:>PK{AGIIvG3!=xuUp}+2u(FT&:O[I3WbM@%k/DF:](lo+EvfvG4v%xuZrP<TGJlFYZ{xIgV7j]G:QDriPaqZ#[f?Bo*xN15kyxd*vF0sTKXUP0z9+N4*+%ur8LN6{$l#U0OIha:}wi7n%ubOJ6C>:#Rm=IVeC3
-SSw4%Z]SN=5RLAP^R3!00*PW+69*0.sTLK&^h%xnWsIf]N6^F?NLo^*}IYYoPZ[s&&Y#Rrcr8u?XXxE<7JL11S2>3jcGK.yZIY6KE4Uf}4CPP5g=EAr^dx=UK95G(8I8vNzus]+/vw0Kl7*iBFkH}M8u#6$3M7-Huzx=9m7ji#)N9nYWSd%1!QZO.fQ-c4qYMYPdC/]j&!RE)BBn1eVtyHdpgW*z5jS#>AvI5nGw[:06I0mXQ2QGGai!7!3kyIZ<6d#zO/xes1&!1=[037Sm7ivY9)snuzhQV0{f(nzhdr0[d9+32Db3k3RV?%:4@jHsDT(Zhwzn>oOv#-kF?P}g6Lx6MZmYZ-1D6J4Bjh^8)!IJLtlP]QiwrSwJmJNtD>8aYc%&xdAx.Bi=$sz$EN{[:1i76s7-%vfNsTvB0/Sf*uB@ExkeAYmn1M{Nf4)Vz6Z4?>YIxv+0(:%D81gzoJ!chqH=7[2jS&(tVH5tO=BZ(pN>M(dIT@CO?LLK60(YB3yEh@^ZtiNn*CI/$W$a0fvvO5X=rF8eO-lgXd9=406meHas:c7%iF3h528>Y#aQomrG03iWbWN@BABcLK=(QHH?:L0f1i5}5JopIxn1.&NF:>>w(LRa@zMBdc[69}=b?(TR&{l-^4-ggUp[g?b/G]5M4?@T)>7kWhh0jJxg{kF4}FG3>F<v}vDi.?WU1$Zhuk8PyS12{pt]oJg/mU:-BDdXexlOu*A:xBA7WeIdU:?!APMmqY]tma5d!&aW8!(CmL0FAg4J&ULs8p%QWVGUmksLM@U6.WrW=&hRuyb8VZL}C?kOhh^u7Io9d^5W.lbqA7[A2kP4X*mm5VBGI)nvu>J4?C].mRl]iJ&:21$Gw@X?]ld(fpL3b(Gk&mY@VtrhT+usNmuF!Bb(R(0&o$Q4J#gF:NWwhk43o<T@+I?Esvy4+an45<j1Bb$}lViF1sBwE3R]-iqI*U3Yp@<ZA-d>x0{iwmFd*0<.dlKgDVm*Cdg*
-A&ydpEQ)X.loSGW^[1z0PX9dGcMvZgUa@y]V4p6NtfBAvwWm}CbL3%kSc!:fv)5<u].ED*e+g71tm{P<-j.O#pn2a>Pd5!<?0p{(z:mNVHbPq^+Cc&G6LMrZQQ[8QVrKmWcJ#0.DULs$l?E>eN1G!wn]n!(^Z@JgZMvrpk&{*oKi?BQY-&&nG^S7RBVT$RV6+UQ:&^VaI}yYhUr9r}4Jo&+476>&b?VEO+5mSYB/sLQ+3A(3bctL[[9VNI)G(<wqXj}?{xAbox}>m*3SML(knv-AN&c!h3fDt]m@fM*B%s}J(38>bX[u82^Pt0XO/aFO61cyzSWDiu4Zd(-zW@4xf4$a%}H@t3lWx#Q%i826B@]=bFK5%/JT..)3J=POPFpKi(so}*L%[kZvl<d}FY+K(eV9MAEO1=0PD9K*{js[a!tDztE7g.7C#9.%+yNnRI2cs)k&{=>7xXdN*qnKWqY*z[En>2-ayyq{M6t{^sTA8K&&+*$d?M8R@IIu@DHsx2Acr:CP0oRs*F$E+p3]FbEru1([PxA9u7e9Y>4}taK9Fy-eB6bL0>1kpLibxuPh3J-JE/!REcGJ!vlkKs}ip5pZ-DkA=e}pMe<y#BC/58CSgx.gCOtQ&3!{/[X3&36CC&1yWM@{#YnuyXCh-T>:a<+ZKllc8k&{:RpKV70TH0mgp$}QvElOHC*)cjEiHv[.C+xjT4K[Dyu5<oB3z3GwpxXm&{x:=u=*L:$=Ig}yWZ?=D)XfrcuJ^@0NCSXS{8H?j=OoRDyp+Bdfh.O(0aN$sf5ksBxoyGCXf&LYR&{YPPwkX8Xk#AW:gVH=kjfeOg@M399RQD+*8U]cEbsg)H*bt+Tl=wJ}PL+b{XG^%E&DKW^+vQN}l10Ii]x4fpN/&&uLk3D>:s96IYv}b-.jg^p^n#$+BFVPC$YE}]?pWEh6J]}]FfGeHWnPOC(N^w2Hmom=WX-I]Vn)%LK9>kJRrj)RZz96J{B)R)2G&{%0+9)xlN%Q
->=A6CLx}y+J.8yCgAOJfwdMEJ@YbFM9Pud!i$1^XK[7@/iLbI<@mNTW=]zP%QUQ%93xN+b^]8@hP5]s:A@o^&sT$4+E+*dSHy*8sHoAC{(LS#bs=G}(K>9cTs5DQ%fwWRxySo?)MDA2f/=15es+/3!fWQ?C!%>)&=n(Sf(dGF7?%^]-Y65QrMRAMMc/X#/h:+)Z/!5Cb^At^98$HI-m0*w>bFsiR2{pfRBlVmrCv/PZBHC7dksW:tD[0dtP%L[]Oyq{bAZe}y>Nkp5BBF3R]0jnu<4n52tP-wx8p#[sST)ICg:.tdN9jIC7/9bL=Qd6<Xc8nAqHa[L/U()19=+3w1<3UHC2iD>GXYyhoA5?BYZm2{:bqoFsj^$)N(!p(sUwmD9(]0]c:cvqH*H2fuM}.HR1v$XDlYY]aNcA)wip9AOZFg+(]/WzmeJxSDCq{}QP<.u(aElX=*t3$^h<RAZIY^$8Vf*q{xG4S9lDyULOZP!]ipVa@Yq@({-n]X41hpP!vQ*OE7UC-ZOMhz-L^9BA}.F??:op.cAT:O2c[49W.A1tj1fLFUZI^Q%3j8%[3$k&/hb/M2!v&}x$V<yahNwB<u-tZ]oP6@XPc$i7@EccJcx$jw}7lzskX>nhplg?@!$&+uSk}6*M*oW)RDbK(@E6YomY@@]XE44ULsjMMnBm/Mp5y0-cB#V@4PEm&/Z3fgJM#]qOybQp3UlR>/^Q9+/]fkD1G@sw[B@kw*Ol-7i?S}1#H%C5hO@LH^[xA}:LbN>PBESJLcjIi=A3y@*#skKtIs1QWw:J11v%7aV26vM%[88mvmJQ.cMws82YQN0D12M?B%U{^u&VW*sY3e0Ch@NvDRknJzI?G&Gkjx}ToxMjD)<yNmN*xJvO7uz%1EUeb89JT9cdgt8K]4sa8Dw^rx>x](OJXZ85Y]rRaz6RQiIQ)^[9R}]F%bn-62i>:ZDWVkVLZV.qB+]C(}w4VNc5KW*>HjB2Jrrx(2GOMB5?{Z%Zv
-4)o:1cvcL]U<rMy/[F}X7N%TcoP)%J6tovC-ARuve5pxpuDRuP3<}/IMz47oQe32xQ<3ergodw&f<X#sfWQk1!4Jn<f=j<&wko(DCR.%VWxba10Q=eFv8a%lT+Bo*f81Nx5Z](MBCrS#2d>-B:UhvK4p8iZb+s:4xfLQ54q6UU0&Ino}LmAcQC8DTs?bhKn:/w&!4eJEo(ep8mlgq!7{YLd8PQii&#4TMG$wV^BATxGE[!50>%rW6VWV#>}YUjbhR]h3^8g2vr3b%l!t6]dS6!x@0j@N3[i>[<i*qJ.5W&]HBXL:3GHZ!xoZl%kBmG03GH-[5dc:9EO0zDjNwv)Uh1S:.4lUi]>Y52#w3W{^Kbmuff&oX?cq3^?iC?}Mfq(?)lL!cXVLX187djecIsc?M^a}nmj!tvvcM-EOO(gscqj:E(NBC<$oPvE:sSPBU:8AljQK.8s!ozR)9w{DoOcmP]PT{s:>20fSJ}Pz>eDi{YCPpLapZpdBA*oNks!%6xx@D2R8FWFuylXz^etyc.:#?Mz6HN<ub#XqG(8ltKue5wQQ@EdXFfE^CP#Ze*bcc*HqrlYeYCr[XeN*c%rj4{=!yhIrW1C3)MsVBcEoI)an-8Gj/.9SYC]/X*Z%BpT<bFyOSNx>bUY?]Mze}ax]u><YQ1[V>N-$It[&DYtHWUD0<%xt!v!d18It&=sw.A*2(^wR>GFVU@xhSx8C%)]H+k+Kyd3=tI5>*0X8To{#wnP@breW<trlna)gkmn.-.Zl&7]/Y?Spfu}(n#[U+2S6g(l94hBVAE0p#$8wR3f<>8+]$6[]Lo(F{/!^px%)>0EEt4^K5Rx{6d)Be0P{SK1b8qSpzqkjRuUxvlk62SLvK]sYRJ)RzIm$A#I6G>UZCfQrq4#O*>z)p?uwiS.wUqdS:sKu0f!1jGF@/w:phky-L)dTZ!rn4n*G480U]5qrsiRF963[wXh#.jv2gq5iyg$sS^2!:!M%o7/*=-gh$##cFMz]
-^wjcttId!:hA?^=P6T>=eGNTyHqb8Vjo-fSk:dy{6JX4[wTaPXoga[w^:m52LE*JIId<h28kb7@I(1K4-N5e0L4=WdiIxMzoWVX#SnffNXIY9!og$q2vq[Ay1AD5I>Q4eBYbHRpIxQ<n9+/i@9YF0am0Vkf/#/BC6otmJf@W}LI^4wus+!*?@%rlwLLP%X:UD0H({#vwI&:S#]%^)jv(rE4G=r@Ojh{o]q+V4f8un%3O*X3g?qTMks0y^>A)o8ac]Kk4[rX8Eb2FTSlliP<[a(rxD2FQD<][jI.T@x7:)PyEg>z/D/R=q&fFw9[3gF$^9y^/BAv.yfeaK(Vk@Q+b?9B1!Ad6#<t%UvoM:hZIZM!5QvZW%VPhKkJ]v%xh[kQOeQxxJh4.N?q*LCU0k%D93XdF<(YgJ3#!p<}nFLOVheA&##r@{Q2]OYYkkuz=)H}>sHNfW6.AF]VGenUDtCCC5tYnn.k}t<vO?2RMWlXKg@MM{S1*=!coj<TyXghZckaG*}W.Yj]:]+!M}f2X]c[-HHv]-k7PYfP#<:L0aRtl2P?&!o!0Qf!3Ux5.[.R.f:LLGQ{UZAa/nDQtoYQ[h%rn/dZOt0r?.fDuidozmRc:[x7cB(LU[Y2nw2HX3Q.VL67Rc*y&$aRQByi2sMP3cesGEE#4t?X$Y2aK4lDXD.*6DzFrEfX}P0BYr>U1i<0QRC+bq.1D-&1.23QkzgZLkMg[9}kHv-:[WdmfDknF3g{+BL1{%Y=z]iqn[vtk[RJyYD6@I9:k(yCwWV[jHqUe^c?e@Z&[DNd?^cwvuKdp<@/enX/f(kZMgi(RSv9MDBW%>UaN.$]8a^Rh]$qG.5qcD[0+NL(EHD=zjssI-zHh6Iy<2)F@2RwU([?FM7%-H9HJ8%hz##[.pqvT-PxRHk79W:w0(/EWZ.>x7Xc8ocP}raX1wB.[0>9zIZ=6HB/BDOnt<m=e>]=C#*qo0u([?E/}=Xm+It8(JAPWFGcJY-5[GjC0
-35Xhi-xgX=woQ5!XG*UjVYU?i&t^8+5%p?0aLl-e=7CPW(C#vHsgp[rdldAIFsv1huRhd=2T!3vHD%jDH-KFxa#<Mz)F@9I6r?&dBgSAp)y[uO2&Z1$&#KdwDs3}9kYCq2pc=mAr^F3HT+RuqvqWji](bdma]Lb*XDf0}rdyyV30q-Gz3Ec+=+l^sE@bh2F}hP&[Sh=mg+1X6KD)slV?n8**}P$Y}]Y#Nz$gt!Tng{O&3x]/[U}PXh{?+Sdrki$b!<(:(B!mp=+OgOa9vLd6z>HWkF%o((]gsOCvFtu<mB]AR/aDu<BjDh)I2F=x/HIRBAc9[d#9{wR{uUMXThs4gW#dQ}oW92%dyj%Cn5CV+!G#VvrZIzTl:.kX}A60Z0Xnokev(GsvKc2&Rb8zisLUdGYKLH/GXCpX{F0-*@aVrHVucg>FeOVo/gKE6z>9{ByKYt+3B$Fch@4O(J?L-C+oolvxIAlwz+}{m=K7odbNIL}TJd8QoyrXuN:%q}2q+juI):/.q&N%oan]:Py6T9>jrq-&}53@{=GNDPnbdQD3ljd7ADimq=L=FXPb2c-)}zeoCdjq8Dd]ejb>Wff+i1HYd1N}X(8>WlC83gWOtCPzOgF#gMXcOQQ{%Rm?a4VM}CW#V$!i(qY3?RZW=ZJ4!Yn?h7C+P]e&h*)JHa#.)[BB==:RT:SAxZD$8-D.mu(cw..bSq?c-XfA}k:zG81So1hTkY4tr^<-2Qqeam3nyd*QUd4Ka4?2AKy@)+ss3+W56*3s-9K$H1DDbT{2f6F$>PO7w>If4RyiD%7MM]zi<hTpbS3IQh6eN..MG4Z3K(-0!^*3x&Jsl8soc2&^gK<RA7vS%MHgSl7gSVIqK!sbP.@1d/-HVx9}iWAO]z[[NS{vSBiCe^J7zpsRLv99Eq6d[183.n#J[0&LIW$<oShqM^7W)DM$bF+>+!AoUPO@o9m4IJZUXyi?+q<85.h{]RV.mf.dHgE@1wEq%=NO$kAp&x2z
-0<(I$v2g{?>5+=5:/U)4iK^+NoTx>h}iu{GO-fd@19X-q*?krc&-{g1{Ly*=H$^aO*}tY{*wB<q>vav@U^=0^1/uxmxP(qqohN6Tp::XLMzan?l4oW9)jsd79c7z8nQ&FB^f!KqJuFD{h<W+Hh*+U]Lz-bZxgOhu/TV$]I^=u5R9t+kI&>-OR*g*C9{MhxM<]zmz90I17t>%VM#Z0#sRZlMG$ZIDN&-oj7HK(qUim3fz#Eih=c4h(]GHd/rAtA#QV*2UUf#E1S{LY3V^R@$]%y(2myTLbJJQNq71E}VR7c!]L=b<5Ray3%Np?Q/<!xs%IN0N[b$o?x{{/XbsQGEeTgJv7tsBkhb3UDkw^zEc{-$J[aBJ-CX]^t/:O:PqCbBGCz-Z)t<*NziWj*LoEsA=Z>v7z4??))k.a&#N=Z??C*Gq>0)T.L4G!4JedY4ky!47:?zufkKwGycFKFp5!tr0BtA2GKkXAuh&{BV[kX)0<GtU62:*z!%&(H)khkhh<5W%tdqAfE<Kjkrl8U}A^gjLaRG>utD-k5Xl4=B6fiUh*Q{yK4qY+EI?vP$KYQi[?=h)).T:W@R=4wlnzL6s>2X)QS^zCHCf?pucJ*6wRB>Dv<(X)DT4.8=*><znUT[@]9i*8<ULbxpdi0*jO7k<>NU<d4tpSn0)@[W&s%B^n7dyYv6.LHl<9/2d=Xk<1/blh{gf3+hUm!EeXpmX2<AHo:$c$O:JjmUy.c.!R/{d[B!tX}t1=7}J)/Z-YyGOdYKqe7nz&v)Q:pep5G{6XLggvAA/H]mPu16!IN@J(ycTh/on?*7gW{1=cEu3jUw?tEBU*5TYiOi>W9W1LSGdnF}HqLA$C^Di%+(q4-g6LJsfW$9ens4.W%q<d:zDJx!uuV>o9:{90yB4)^Rn3B}g3URIS)b9c6p%<1N#>*rKOh8I.+7IpMM<O9oyB)>Bm>?qfG8YZ4S/@cl?w)C@-O9lNqEm#Q8qW!>)#3=(:UljQ[h>=RpT
-LI{95X0J*@oR}AXDMY{lVk1Ta=v[tF-M(CD@w]*Se^*16^[c$LEpBgXD98}iG0x%j/0$aoNM2D2J#[JE+>rVAU]h@yJ{p]#iu:)JGLS%gRRyX6kot&m9dmhG<aJ{t>y^wSKi5DdL%5)Cm0g<^KiektbRNdrC@rOxx}A]Ciip5Ni!#Zh9perG^L}yel.kI+^RFaWELShaXk#tipB2DqZKlKHW#RZg4USD)2pxviuFeWu%c/<SK1/Fq*Nd>+=o?8y=QvRj9{Er*4a{1iirvMf{)45/I1GJTMhbM/YR?*zn=oiLj89HKQ%xNwi*/WEppV@fKN1[c@v#yY5c6if?bJ28g0>1quIrVkK4zGa@FDB}3?Q.@F0fUm6yyjV=.NedO)+I)qQp8Sa>W</855NpCbfLtIb3P6&}u:g=+$kj5X0nHN!Hv&Pd7Z/o81:V+t3ygL!01comT<lS%TVMMyc(mwP3$-8Pk]RAPMdPE?K%ZX/kIX4iwNuFG+[@uWpOU9%s+FssYiqN8]2Qj<aCF]CLa>YaBuGW<P{)t+KVlAsLmY=?yyiMuaUC/hYtosl3+3[?)T]c&r!S!{V?Ya8z-gW[/Wr*vX0-g2&0O)m{&6l)2VF!jyj6@%FTe?obWP{&I@3GQDSkzht@#(t]4{UJPLr@%mQFG0.%h@4K#ek8mdr++Hz*>&Gc-K0B8M!ddKa{l.KH:kPp4D!h*qDEtiV>BTDQ@Yo/HgNBBGH@YEqc/)!?pz%b$NCZn6Dd=]apOZZZpOYPk]71uKTm1tg-{lGCaWm#aW4q$/gjq5$brswmXS+:$Em&(Q]Pu4CBvc0hL-X=9Ye>t?l)}T5VUyqK)P]Vx0B)I9F81?])uC[lR1Dd:D4:S0GTq^?i<aJWmMN2o>Ar*?iht[.CG#tTgZj=+nzlBQm4qVdztu0aamo.TQp+/4:LzD8]4![Mr>Dtw8a]uWYs-PxXws(KDvKOLW%W}$588xNTm(>P*/r=59aFQukne:n>3)0z
-00000
+abZy8000000@}T[2y)KT^^v#[p^x<I1C)EliI8y1b}p}sn)2Qo7t5=]3$6M?HeOG$2:fnOiFXo>0wSM7v!I{9i@Vbgu*SlACIYRHRK}cm>5<WDrzuMvrco*7(%Dqt(Z?m.{p}onHyHr]p*8Nbog{oWib9hH}Q>BL+}qT:ohcDt@74>?DHcsRl6xA{Ox{o^F)Q%<Y){?NZvuebsLPogct$D>P&pXXxqQ.gA+o@UB+GfJLGSJM<<1Lqxm.byJ2XjFd!6e#k0N6c-pAlA.q)JG&i#0#Iv?>*LNjG9laQ6nUlYJPaL/8*W$pYB1fR3zD.?>na?k.:N-GLe{%}/o<<T/.5GT<JYbK?Dk<Oc<*V&m(Cr$I%&c@b$Nv/b!o}XGJO9qP([>Ho$juiq=qR}yWSL1G!3+/y?@1@IIP2gsNy?S=[U3AI/x{R9/^]cC#JCC&u+wrR05VhK/}Z6kFEnEgQyo^]eoExn:.6OP/s^Q[7:RQZBn%i*UX]+(?k)6R5R3]7kt&5Oi-IFLjh-.$8A!T2g<i&Ye4iy=M6Otm--PdE@B&YNcp.8.Lns6*?BaWB7LT/ru73kLV5ZgH7cS&1pAUf&Yv7uPf2EaaXj-Q./5+myo>PCgziPXI:>deYcm1n8qEC[o=J.=op(64W&RNVk=*t}fJjfIHy>qwB5?vv>qf?uHN?U8h@=C%.Jf$tA}>aG*9i.!^6X*y%fF^+v$0Bx)iIT[8(k(K(@C6f%hXgjegI:k3{.m-an9.G.x<>yKFe1?oFF+xe{/]@7wh-$ZNQ.NkNPndP{xsraU@{DB@n6/M+=-VJ7C>Qb.s[La}?R^64>%14O6({[9><vo(FKU*az/zm#?#d1B<CJG@pQh[bR+CKd>U&&4*6(R6t>fs9Pfu*b[Hn/Ik4Q$%r6lksah)+Q5D14l9S.lz/FH%%?WL9g2MiVnxe%[i($h{6je7DPHtB.qq[tFQT%.Sw0.$}>?V[FNHw(k[i@0>7+]C{}=ONO<<]%u}
+lh{YPeQ@}c0RjxYD7.Hd]fRJg1Suh4s$A1i9%9HnuMrrzKAR1QeKB+r<Y?3htnAAj(6x^OecO-pLtMGNp:QlWcq]s]Q7hqEuF[.xr-91xlrM)ds}d1>L>k)fQ[ja<<x8.Q+3ks1^<5%so69U{W98nZ<YJu4X>8U0m&z1?O-NdW1rG[s0KLG=W$KL5G]z>^UvP*bEcda@G[[rekhZD.sl{89/7f>.*!8wXXf-?yaFyNY<Yb<n4$$.JANujfP2#62s?np#B=2#C9]Tz.n4v{B5IyXuGV+$?&J!wiXA<<DR:MgCf6P[jqBB)Sc%Q@WZQPg=r<1esU1&HrFnt+PF.j{b7u4K=Ec9&2I>BbTGA&/=shLcBuReQJJq6d.+ig2%jH*=}1eiEjZxU(A5vqKomGVGso]^yX)P/Di30^ht1tk4K<fw]K+pXj#Yx>^2e<5fJe<2]KSO8mz<lwLBzNl{I<gu%{=*Dl4}%I(AZpMk$zL7sJ<ekLl[6jUi*(w0VlWW3A{$X>B1:[W-chK]q&IT($-R^9uYfZ9og&Y>)*Du+UC2dt>oA1G9v>$RTdG<(*y:=J9Xy:rrl0U9z21ae3[x[cRPzAla?6]0qG7>#<+4E%sQLy/)DmjjMlI:Qb4NLBHlll9b}SF#XKm5ELnb79{^v2yO=&=Me^Gz:WKMi8vK=Ibs9qF-51/3D!mH%.0mWY=6&$uPJbft>mprvI8ZS60mI/vebbhvpJ2SD3w.CcwPAmr{9pX+p9tFUsdRXn%cn8Zr}>d6U.<FEZGo)Z<dv-8QX*&N?>eZUZBi*tz?U7g]+RCx6)@7pTT<gNz!<=!Yl(evU(9!Aj^Kra.ZvjmgBwv0mmEseP&5EEm+:olybNb+60K^&H#*}h*nezLu&K>>aK{t2q!X@A2EKL9vy&odSmOyU5wkKlw4(f50A?!(SDY?E5^?wZvAh:dyT&cfV>*xeNBU/UXi9=c>(X/LroX2]$9>*<)ZIeMVgAOL-y<f*RW[4?K9
+1moGX=TmA{T@Dp$j36Rq7@f{sw(JZ&O*ldrw9FXkFJ:Njqa9u7Go)O)uf6WmUf-k0iGltrq5-a*4p{{.m29HF4.FAc8QUxawgnxU]&QYX*2C=IuU5xgJc34f=:RYo=Ni$5CnYDFe@/GuNGg7rI?!tyMX#tPG/VpT9weYWrZIMRuryl$qc5qm1x!6$f?sIkXlHJH3B9g9p$vaVBP](<4oY-5!/->)taD/!pMiscPK3@sE4HKG.hPX]j{uwbGL*zX8bB/^BzH9J-UmfYswthCnY#jez>x%Af[FKn-]%<z=%l#tVctXp8=)l}31P/tU:)]1L(%(GE=hIdNM<T46R]^b6Y!25]1Q7yt-Ds}wWSaXB#v9R6hm!oJJ[WRV%oj(XK#<p2(U?eMx.cIwqsj^F6JRW0R!eP@9va}AnR/r@&Mw64dB=E{/z#}>d(DD@3@1&q0{Lc[vTnVP5Cw7=*ewx0>+-tfaEXF4.I(3R5:^}Z:?zlS>H6m.e)q)HiD2OQHS!vf)Be+-klJg{Lg$fuz&xR1=3eF[h-BVM.=tq}Qb(LdweuO^4p:yLn%CUPG?b6klpzg9R{BnE$y4W5a>b/}UW2j>G[NGhX9IiDE5q+5Q13S>nS$9Z70qM[:{gv@WS^68{<94^0(M4=M8/YFC%RB@Zk4Z&lV@R}vsRyE$vP8HYlh7%cDaMcG%ua>?=EuN?2y$PNT}OE*#4Ni}Q:J@Yb=k1</H5^b$BTzHul(*D2S}Q<?5B8-$Tl{?Kv]E3?RT@-vO2IW{c@15mVrk1ThmaxMp[h{:Hdh[*rA[nl+Oh})+fVzILb9Ah]ORDuWyW&*Ye}DE?gJpZ#b1@rWbYxBDtN7?4P=QZZnf^0lUbC9AS3Db-euj0l$>#zyQxNhL}fhnT3Kj9V7{YKr{lMz]FX7cdPL>odB*dlRurW#AoH.f^8O-l9edczR(R%}.(flbihQtD6.&cfKwuFu4aS9Xkh(-OcX4@lGD3g0>LCARAU@qlA!T@}N=
+xJHq5eD&G)se8<<t{!}m/>]-XF/V-rt%Rnp-T}Gd=(0Hu1W92GClHcDQpV2G0z.[MN={]EEzxL#-K0}>s)wR74M.-*jUMobV0xTG&<Rk9.B}#ELcFpU[$9QP@qfguRsL5+nCv+{uZp6+]1CrmUor=[<>Gm+DP!>GFBiz2.*Yych^%ht8Z{{koGj*0Ru%>^?t**m9Ddv&t%X3#Dk<xm5J^VdN6XvV&=#4okaqa)M?h6>ZVo.I9SF>i?uc1rpl6)N?uNq14Ur+.Qwj!dRtgVVr$JRDFubm-bJ0:]]aPLJ[sLPgF$wQN(zMqT=.jAzeeCUZ^sn@Is3O1{f95RwwD0x4OK?[fbVBht-P<1V}8dH$crx1P=-JoTDvcag(H=f9LtJa.vS/^R}WC%/1k)n2?z2An5ZQa<.f#E-2GScM*%pH^uhCnCEUC7[]8x!xSYZ*]hJC{Dj$qtUDzhA(qwK(UGdAp3}o978C$v?7Ev:MN(rjbqCuO9j=+-*%E:t(lw=X5z{>3sZ2R(-Mw=XXnLfy/3=Owq=esZpy>VG)*ej88}uO]oIk$iPIep}$vL6EYnV=[Q@3UL)DjQU[<b$K]>o9ODD:s4jWc6f-Jge0l:+pIWRilKC6VV8jkMsHuptYt%-JpJ6k7u]&+VJx{d+U{UjxpfF$3UL()nt+rv3:dTlq5Ibta:>oJToewLV-mV%evg^T&vIq@:RobZ&5R(lpkv6Uny[#LxmJj&TM->E+Df/dilOx$g6JetAgEdIxf*cDQ%yrOB?<RX/7yO:wY+{(X@L2hbVBgwF#Z59g>:9Hnh?VVJLIe$hUFX(wY&Jf-/woubVBiSF#Z[xg(6qLnh?=YE/>g^Da4wAeB%>c.DyEUK$3f(.pn(Ft0V:dQ*62a/5j>f*JTHVgtv9<=nadpoU[gGT}3Mv0w-yM?jweG3&RZGjKp(E>j$Q5V=[BPq$cufT21pdHrLVGCoSCXZ[kgY]^(RACjNnPwNmRS1+f%1B++}zKA*(P
+7M##-&lKf9Dks^YDWcyLgjbiU.s[nsZl3.Ugj7n4HH*8XF0O^BVV8i<Z{w0xXD8f}mv]iaj7ecElvgDB*aPe(nQ}sptz46YYt*OiC:rK}^dNSrCN%A$F5g!5OA7TIsS8?67c0@j3Y5#gxxw=uJp4%3H?C&wHk+L+fwiWL1H!N{U0Tj^CbP6ntMtUjnYEP#M2=$pBF:D6js-{vTaxqPQAd:VCVENf:ML(AHi(@@n66)V}7zou9kO-UJ]fTFl{5n(A[S/Ge0qTnVVs+=mK#SF(rcc0tl#p0+Bf7hd9{4I0u6cN-+rYvx-P9@dC>#*:}rzo&p[l%Pj(Xoe2K?bg:A$6O%v+sg8:68V7vj9XAgJh[2o3*<2!&&k4HfLga@s5OCFs!7uJfBVVlhT3])%W!ROEZxxvS$Jmgwr1KCk&4h(6xZknCDbE*sXAJz#[xS}6fOA=hkNpGN:sVL1QQCy@y*oU/5/9:M#FYXR)hj*7i2@oU!J7GRaqWCk4q[*wBL{r/gZ&sf(F[RvXTEMOTqhO)?>uf/Ra!]+kYwx(m1LjwFJ-?nK)g2ULqVX.iK=gkxe2JIX6R*4mC#/%-)GwLW[D8a9VUewlFS8]v&$/$-dfpxvdbRd#L{r^>Z&pshbIH)me2JwT<rtE>{Pf>^KDCE(@LL%Z3sJ^3AqE?]?lRxXm!*tCCgN+a)z(=[3!]GwDknYj*vPPmMs-d+{X%zW{&U9yYw6WVV446y?94u-uR*75dv$wU?SY-#DP.+K-%ZTL{JWLxWGjchPny}I)].X2.>ktN71#CG}ZIu!2CrfFI(h-:7w@*3]:{GX5s7CRuF32{OGEwMD)j&(g%1Pk)@V[7pnqD>&ArVO*F(mwKyVT}KOL?/&s&e(jJ>6IM!bg&et}f)R4-?:[qHQEPhp%3[HRxmM?77/w+[2)%3=(bB3nm.Qb{S(eg=!MO9%H&(zG3^c7bc6ozp9X%aCi}M#15d:4I21dv$KU7T/AiiVhBj7Hln(X1q/(
+5s[=+zK6}@BLB(aK9!tnq$X#GNYTJ]y2QD0Pu^U(uEn7d89:bMgNdt%:%RSff9P$JPNdNLupsSo+d12l:YMvA.f2:12AS<*^YVP-W<^@diLl2g2siLB}qaQq9M[9$<==%e{K?=y>NF=[DQ$[E}97W6gktu>Ad588pM+9QQ^7M2uR&{7Tne%GkHs?nD%X2{u=@9{<=.A))$qD#?Y}3/npKe9EX}GF{vHPlf9o%r<OG?V%53HnEWA{JXn?*Q9]Cz/ugN8zt5UwTk:k6:^Habp8QGi=Z5*?wQ<LPVPn.Cr4x:fkd#5BuKs<f3n(dX?8.q>iR9F8?}aq=nLjB)mD[>[ef1x5RT##oumy<G2T3(+]TN&*SSTMFY:x5Yj!bmM.rRL(udZ@)<EMDAxuEwW>so0G0z*v&S*rb8H<Y:{2Yb0wycQ3}Mf<byq!Hu4BtXe:ZzqSHm1PEJY=[z3QtrXs/QDET1+Ev3CW*1/A]aBC}:g39ilpJz!qp&o$+0FMD+2=]#AS&&&iiA=XH{>3o<=>pOvKd.1z@)jlJae3PP(o2#zwva}r%]NRbJ3S)0VuX.mQ$t#dfpxvc%)UB:}rA3&p]ETmyk^qe2JwT<rkz=D[h8{wAoCi=3.9jA4uF0CL(9hSS83:iK{o)fVvHl}=lDA:t=(?=HF1R.7t[LZDZP*z42mz}5e.V{AVJgz@{[QC<*14cKRJL0D5=]@b8v-xJ$vm=Bn]9cco&XtrgOc-*q{[T-9rGI)*={=uGd}Qhx6$iBt4>RZe4Jz@(uE8j%*v4.!k11>>C#iZhT*QcmbqAZ2kplNpXO?]+:ig7Ef}]l&dLw3/kCiSwC-Qv4eGiQYnNl6xTqW@QtTJdWv#VV1%rHQLt>-#1g(*aHa8]epb0aq[Eke9y=<i6^qJ}bU!}xSAa(&!eb8{5:c]/:!@NLz/($d}.7SYIedj}=q0>V@HBQvWK6IWUF!.YA/y4nK=Ik+6sNO{enRhZlOpl*a4DGT<U$kD4!-.
+yG)S@z}=+P@v(*?(SVuTrR(JSHtaiREgbkD:]HtAlfN=)BqK-*=tw&8Ele!j/ahpYZj=%d-!{bT7vGh1tpoYx70]n{w+Xk]pn7@3*i19p3)[g*BmUa?s2dHx:S#8uz%as6*}/T3lnP{njd1l?8sic&RtuGM[^Qv9![)04:0/&[omze-eQiq{PJ%(sBEEdhzMmJHsXO+wEm>%-G.Kh5?^J8[zME^h2i*N@9Y.JPAjaDZZj>xlhOPr$2)qgT/.9-vOzWS93dN?0nm:EHd[8wsoZU0U)}Q+5pbs6W8T%j&w44OG3!Eb::?AECh$DvADr/=uCSL8e9Qa:x(X1Hc>Y5#l58j-QbjA^v{A[3k{)V{47uAe.+jnP]!+w5Y?[(K<xzPkU+Kd$L+yoIn8<PKLZj*U9}vp}v[ok8*uc}Q:VF!3:pV*W*0(8u}3?3HzZ&{{&OV5fHZjVv-j^.oTg<qrU>Rv<l5T[60(PTyAKkxv5EqvP(2>&^!&EyQt[-M)=G@Y8&.0tsH{H1ccHKoHfYb#Hh9}?g#3YP6?&-&}oE*R=ExR$P>pgNbs]9B.TPUhl2p]OV/Elf8L(Frer[Ejr(zY$Q{Q&EaCQRO!}@ast$+.BwWmzflIBhfD1(D-.)^-%1w>j%f}=)g<1jkWKpBOX-=k:/&]Y0q[B7wIs]tJx0F/s/e}9d?JgtUBPbA[yaJ+y5SZ7M=ym)PT]LS}d.-s8.tksnAYTB-YuGep/xfY{O9Gd+4s?sQz=f>HDxm^5-w#a[z/uZTU(UQ58avX8(z0]IAcH6-Uuge:TF6Uo!KkkWvU>B:P%5&Mui*CS[.RN#>%)NmW6[VV27NPyuaM!yf[yC8Hq6cbm%#dwju:WayI!toOtQY/jX[3S?:k?/QhOYkM}!M=M=Bga4RDn#=8Sp%Rj2Yl6@?&r3SF(Ird739936?[)zE([NzmCALhzl/h4yD!=9qD-jPH[gv[qpJ-m1=?BkN(eT>=s([sF8qQ^$x$X/mW@X%G
+gmvrF)kFE!Dd]:m6vvNRkX2/1{&=U8-cIqQ@jGspoJZGL}J=p?>W2Ik=QkZ3WeUn$*6=<g0]smxa-(^L1PgYL?dpW}VSGWQ]Vkkt0*nDoNBK77k(>l$<L+l6ko+3>i>y&7BL&O.B-Y<nOAohsPlv6UBYHe:Anv53O?][L>{h[lU!3b}^ODuK?bV2Fwr.{Tbpz:6cvQ%f]9jJiFrI<gY0+vJK}#I=[*6G>9.PSSvH[)sYbiP8q:a]NWiO)d89q^kmC[)Vgiv=.-ifi9C}sZYad/p)/T!PmaWitTeF[:]Y#AcR[JdyGuThFiOIdc!o#1PhxUKmn6v4br!wPVHPjN3BI{zM^a>O{7:!cOlX(tr+I.u^i]tIHER/W?<]NchFZ]pb(ch^3:MQn-Xcs0Yb]tK!?W$b5}Im+ukXokOp<Mw8{rVu]VJQr]QI[E^rZ8Dk[LY9aRpNodw0nN<9Hin3GR#AcAd3HFO*#h[f3fNj+g#QN/+{E:(PdN&wJVAOS0*T:KkkS1E0o:7GCg6Q:jb!3uH(KL>il5Gp@iPh78)E-t-^XzoEB^/zVXe5t0Itt64ZrjR}W76a{MHctYTKT*J4%pWG=OJKcQ3T0Dqn+2wSlX[ktTu[&2wzh{ju(#^y+6P*[&W%Y1wxYEC=LfU0<q#hr:[p3hU>s1fR3)/8V*X{b-n08A(W>]JW0m/.c]X]a{>y8#IrUuXN#@2xjMl9WDh^F7JuyxDuXBE/xR!e6}uv@n4KC*KPP7il]!kGc]wS&^HQqCFMVcH+sDN7OM#!@2Wc.HT+hQ2!C%:j^k:xg2P7La(@JJ[{Idk)]d2AR(XhA8Y#]Z5s#hKj{QDWKO{t]{ykG-nPK?#A}IPxh4i$^M&vb&A*tk}b><%UY:kYEcU.QS0-3G+u/y(qkJk[OJ>Rz#Gz2+)a@:j=rMEb]x7w$C}yL@D{scF.(P?}B{+dnUB(oEXrnIjQ([7uXjhg<^6?r92<I)lDqh}89wp^?(^}0m.=hd8O
+]!K0%hB>b{J(Lf]-FvL*mqx*5cyBnepaxaRQF:G}<1}Pah%v!Lrqv}CcSMX/:WSgJVGR)qOE0M!UZ:ov/2<cGWWbGm541KY?w-Nc}q3*faamU31z:iApaJ7LO({roF{eJFgem}xWLxtcD[cykfarg:m{Z7AJg%vckgZU7q>HaF^[nxMjOIq)64NJ0hSdgw3eXKv)/MrgjK]pw9%JfA.O0e84yAy>bpm>7(GG1<B!G7+25)f[LJZ0$?j}^IMWoIuPg9br*0AP/x+3>DIg>6JW)[lE}i1d.8Mz4v&ymbjEhw7vu0}E]ZsXadw^LU{cFQ1w]ds$MeYy^#h{urq}k9eGMzmb=QF9.^185%)OxtOTl)O>mJAl-*?bHAZjMu^:6U:>yRx8wbU[<Yt[T.p:xy2UqWGb+@ae-]<F>3uU3F951]*9(]ctmgC(KflLNf#i1qkSWb}R]=c&.BL!/.XgpiC4HO6wO-zX#Yl(tyfVbUEujaeHCN$ru*jcL)B<3cbIlmb@X)..P=84**UA2Uymg6VbSA>e=I5NUMTVzTMW-a/hI=e8SwiFS}+81([(!^3o88%]Y^^xcE>wJJ^s}J=Z<jd:-FiN88&5um<9juxH!Zn<x:7Kz623YB6F^QD3GF2iL]^9bGK-MiptQmn!#q2<&zr}c3%JJx-ik+A[>m[W]<@)zpTIZN(8zB>ip}hpMGEExe<!ncEHBc3B:g7icH=D?ziJ$Kghvp6-i&ht&$qC+.U48aOP#/+RII/f0/d-WXU0.Wae&Z}3LeNJ^1:<meO9.]P=6&I87ftTSiWMt!ndo30=RT30=Q-A*+vVkAYn-T(FnH4:z>44N4bv9gx3SiJ=kice0ydG]PV{sU4vi!/@.lPj3}B3=r.gcrBqh.weTG8(/@4I%eFF3FeCzicH<}YoIGxdAB7:Y8(!<Me+5z<c7Z[Uzhz$AgZ={uFRKi<v){b13rce>F2U&PvV6<B25hKH4I^CY^^4)D1cIecJ85bP#SGc
+OT^GHuyO-:us&b2OMy{YT-CF(^a?^n^7)gr)Yl>pn9Wf)!f4$^Wk/&@j1^)nGBs)}&x(&#Xx}?F66&2%-cNBKTXXPZKhuPe:z)[[f7.imp.bfTy&(ldlHiS{bEjId)7def]*2YkW0>XYom3^ctP.puZ2IB$A9RQ3{<Bghh?Q/+XkoZ{:z)]]TQz/>FExdKFExfpAIq0D3Vf>rYu(=[tXIoxu1=>7t<fK74hJQ9G+=!hu1!&@7UQ1GG/j:aP:/9&hJhQiJt@+*NckFhG:R:X<3CLbSc4mW0Vb2v{pndZPagt35XkwqzBp}WmXA&S//nb=eBvqUABRc5T=Ap>=2GxQ.0+G?Z8:O&Z<TwsnSJWgzR:Fu61Dox6s=v)oNFkQe-Y9+90+]rxQn7)EhqmS@Gk]GkYQ4])Lz:bcdK8Tqg]fW8r0)Y+arVvhA+W+:nt^$:/UXXK]SG?RnxbV[O9p-uuiPK>l/lWWSPk(y=-WdITuLv9LyxS[qu!#jR5QbZ2G<Zj6]fzZrM{BjhFvx3e.e+@+WUpc%@W.5fvS0YKVj1ph5g<)U1R7]%F/Gxqpf?XgjdRN#&ys@.pIfV4<ob&WqHH-G#hHt#6lGK6t-%X[gwi>Aikma8?0s<pOnJW#b2Wn-3E0jK>epr=c/{RA?Pb9T6Xm/HaPqC])mmGqSF)fzu42u+TV0&WZD9hTy.F*m4HRtbvYOBE!nacu7D{qojg$u*Lk@}B}b*(1iqPsWUgN<UdTBT#!=89p/#^Vf4g?68Wc8=gLQJkkxqH0UmpI.B7sZvk1Q>eKt/*(9u(rsQH]$MCuYR1=ZYvP#?WayUR:TALz6>PaA-5h2F))F7Sjuc=.lfpz#8KS+4BBSPO=/)%AZ-:)9agoiT>.cXkuyZv%IGj6*>.EY5%P)fW}kk@jXMejdTO@E<-Kj]or02#qLAHQomqj3f!Ix3A{dM$Bk1f9j=Io/Z<(xmL>5Tde(4PF:@QxmbP]x>(TivA).yG.t)rCqF:1
+GzZ+{Rn)%vZ?Iu1JxK<+6(TIDc@@A}qQ5Oub>HBJ@V(L>fRyP$(g=JBzHIFHcZ^QF@iI#9kf^X5:]2nim=8:IR&++Mxq9^fA.^[@iZeLoK4BZqD0+SmR&*(sS81U8>}akbb*BFcJ.ys.js65N4Oa4Jc:EYPX)76WK$#k%ZGTs.1P2K]]vC*WVG0NbmyZJMcI6]fB^FvpuTZLyn<m<%)KFT/w8YNgO0hZNbLo<MjON)#UHRzv9LmXT2nK-zRtm-)[z[NX{B$NF<2@Kdh$z9xjmw3LZ.#}67W+M0{K?*-cFRD!CqyW#KNtUlZr[oz[#k@fUVC[.7MNR4ag2(&[A$)^N#2tFNUyGwmW]3@/iKAqMoth=R:/cV<j*u[:da{xw8-#pY34%2XdQUpa0=78KCN)hk5xkFJmg752Sq^b(wAce-)3X&U]Nm>zo/bsrL3<K/mSqH0h<Z?jh5MwW1Vb.J)H4XxY3PCe*alt)Npx2pQ]86ctVq+9HhKS>8J6yLt.07CK?>^jjSdT]CMdwUp%y>z<pR<[3H=CR(aXvZlLorpgYYBVG+]oJZ$+JBTBYwT^$D.1iy+hXYE1YGDoe:Kz+!yxNhEF!C-VGE(C?$zahe<m7iS#DoGU)2KKB59if9s@0[$o+.QpApix$)xJKbsUO5am/M[w2?3q?%S19ab^&zkIO3kmY}oK?P<(0)8bfQ.$)).Ms=gl*04gl:S8sDQbU.abr(4p^1vx.H-?$Ia+VHje4iwtOx^X7OEHBe{}4(#B^y{Idd9gHRnLkMk[]BUO{6o27*XD]}95@Sn<y/>4b{fxRZN5y.U1m.8JYh-29}/?H?UvA6u=G1lA?>H2yDIj*g.$:94W(?>e2kkK&h[(0:eZDt?K]l(TikhV@eZDF(ItY3LjIE/[t@0+cpv9h7/ZDzR?>:?$(=1stIt:O0}*z96Cz1MpVl]DO^Inq8QPLnK!Uy=[ups.vf-/HTSxcHy!JjbYmx@2)g]q-{fPA5?6d+!A
+R3SB#UYo(&4{hnL6-+rx.3QlAGj6xkT3[.47WqN#1I+]!({OTT5uDdBE<n5)Z/?wL7*}g!B}OiC66%eAiUwCMvbh8?0<AZGd*Yr1F%uyN0kRnfB{V1c05/HrRVnSlu$xuTl9LxWJv4+MZ@v1*0:$3{B$c)3P)?fV}Q)q@y&e7!^K45[9}]Lk*7M:qqjqh{b?-[$(R<l^I-tNzn#RGMx4&$EtnjDPdB2lsH{!dl]h7(&pA[IRk+1a*oNB74[6Xp<{E-[dtBYw+GeL@<NyimCMh>+5n&7rQQaDY9F%o)3qBBZ@W1P{MW55R/g?Wm}NRx}Rz5HY(kXW8UG>Kl.)tm7&&Kb#b7OJXC7X)KTwzc^D*K[rRE0-75vR]@){c=QYvqHp{KfO8*ZiFn0GneckjXZrJUSH?+zbZ9$iDV.E0eHK$>UI!7]^{]-(S0%k%6MIG2Fo22Qv2^cw7#HP{[11b?ZMNN%aC?X+hUnbiO4:O(BiSr.YCnX7t>o}[oK<)72XYGNScL=vpOFOytG[?U6JhZQN?0Dukq@I(<fd{QPuL[W+xrXP?fWf.JWm2nUtjxI-x%}pp=*Rkflx*)4/L3>[l+5Q)0B)Q!r/lgevVWcTCqRl:oK3Bmq]M]JY(?p0UR7jh0-f0r<{=0n!R/flD$xlyzOmg0itwohHwiuwZI/]FhV8RKa4^hn665SoGM}lRT:J8esEP+23M5p>>ABI.-/Oi*utn/p@:iY$]xlYH)F{E7l4j&R/%p{FY!4HL4/=(RKpLW(T0DD{u#(H)tB>{iT-?!uIyuD%Ca*^z{}s9^#ACLrLbn[R#sl.Hr1!kw[t!50sasg>]zwGhhz-08dPLL&(?ou2wGmjjT}]*0GQ<&frTYQ8MvtzV=[V)?1rmm0xh4Rf!M)e*v(R0Jg5tcC42Y{A1oC{DD$la?y4RdCUj%h&l-Z1wXhBbdY^5R[j]r?6UPHM/@LymY30v>&JFF):w4Q@#[#Hm5NTKd=2PhzuneH)U:Zi
+711PUi]9!k(aOsLmms4ncO0}@l]QK$BEcvh}*)@D6wX.+dCW=/W$J%]22sBZm%4yX)L5PLcOH$qeH9p!JCUJ-IeCu3:Qb7k^D8Z^6*%C94.mAGfvu)EX{Q9*dp4y%tsATOltRaBd[B=.Ib3*BeL6#qIeA^IO-0Uv^W-{JyL+!YZDE.yIw1-aQlumnIXs&qm7n=Kpwckl(53eg2bmrBEtX+AnUT%gBAj%inUN.fBEO8?JaWc5Cm8-6olth?[Hp6%scZp%98oa>)#Ea5Mz-gV1Z-B.pq)JhA:KTn6!cuU?havTLA#D!1?)q0*@Z<W1US6wcPsYj^(=@iGR/yySxMPv=YrS&7Rh8/]*@c:UJw$f.Zw]e?^ZlkSZj7<T2/F{pthpAt}2Aph9q?)3xr0hrDkhDCY?w9}o3?j.:cv7W8kibhFr[msO&JM]8oD@SzdbN7i+NJqJ2unCSz=2oZ3^H*2KZbT2u%qsVCfiMkub?=GOIyLE^#P^a0Re!U:FwmcJ{NcosD2a-bNjD(Fhx(h^=WRe9U[LDKwI(YB)WQWmr:77Ck32ZvP1nFx:Hkje(0z?$Yll]Hf>*-93^NVHmjC]3ce/}ZTYmdtX:j!{N2y{IeyLVIYCR{.i$/RyK#@({u%hZ+94GIvb%1(#TDT&I?Q/buLY19!55Q-GlsbB=4wTsW%#X@z[>)w9%Gp1(xetF?j[AbMbejf?A./Q$a&ze:AKVt8(/iOgQIksN&A::6F%EnhWXPkk.DJ=HNgBNqEO>$DxMcs5.wxpCALYDaYX].A3YUcr=fBB=N8RYjqS>Q}xWe^e*Gy%&AkDYQ$k0Oeo%hgqfZZq9r:bB!r[/<s.-t.)-JZ<zeqDCG!4&c(<q?bdio:r[O-+LMwwq>9BHeBDfpnNF=W&qpR5HTrB[/<>9ArH.5^]z%$D}xx&xffXiUy&RmDa#y94P6zIchw9I[v3ud(4$WHWGyriNnFkA-ay0p%O+aj6i/dVd1z95?y+VH}l4xE)
+N<t(QM/74s{DZjd.dn+6P5][%[#kto]^]JnVDj/aIqRr=flwjfhO1!qS.C%c7s1/QHp!%wE0MT.wf+$ng:sbs]*{E02&c]YwhJjOv3rCpo^ud60Yym*h9GU+K8&WFwrRb1@v3rch11&NpA#u/G:*Jgj@TeId62hg:VdSq@UCsVcyo5{{AOwL3BkJD-%pi0>SjohglIdco%jatOnAoI[@AF7^]RS9U7xx3>1rY[ExB]^2&dwx:T1UH.Zr#![3MX&S@XW#/{XI[Q{1^^scDvYl!L/Gtth[Zgt{=Eo%flXUebJ0)VQHG/oe<C[gs2IKXY?mQI1O4:Te%eo:2@!AnpO3zY$R9n{O@lW%n+mhN%ctz$-hth<3&l4X0:GzigW(@dw/fBHPdmCl07ty&Qu!?Bfu#2vI:xWX&Ij^ZIs5E#)z7Il:(pWZVCli%09Oy&REJA69Gi&c)5@(mPx:j!y+-82qVdq-]#Ymp*A>/*Lu71>S.1])Ti*1c=(7gs-ZIW-Mk7D.haDZl-Wbaz-kUqZ1%fD5cnXBpVnoJ@RuMhKL5?!5-{E0x7y)ht@t{E+mjRl]HD:Vvr.1CM^+PFkmLZ+.cAD-@cI8R>Hh?Ltg9?G=8qNNpIH:P2G/^gmg=]5=wfSNHrA:L1>7ouBv[5=+!uZVYPD(7Ovo[hDZ3H2*E+r+uiE:hw9I?U.:#Zsos%y1(Dd!Lz9s/vLBX@jlYgeDlH%L8oNyuy{s&Kc4ES{N>fs77u8$A{A.k*Rdt=8P5?M=X14ou^ZcCIr0GzAcw4OFRpN#:hdR]ULVIYCR-}qby{By[s9%G4rB?r^U.*-dGfmW%1kc9ahO{/g0n!iO[f#[5Lmr4VHhoO5A)+[$]AIRyr*rLL/KtJZN/RzkhRwoNy@y-n)WE6OtGweCj<d6LzYvwuHI7P}S0)<jo:5iYXopl>?dh.&d6IunK7WA/LBY!b+zPf+b+3[aN*vmW:wWIUaE-U)LD-ecFiKHjjoJszKwhto.QE9%
+@GJH{^N=U?hRB1]E?Ghm3IQC0E$?^B4(OUB{OR$W8FeNI*2MqZ[%n>3m(CUrV:=i#44X&o2(U1N-gpkrhHgeIS.IL1}J&g>jDEcdC>74Y:TjjbcpmqV<F+tDL1*R$hp#C7&ig3B2F*6l*2MeGuwAt>)%0i-iQ6R!cu2NzuXo%7<nI9$2GOlg.uNj5Z90a3qc0}ycu1f}X0k^twrTF=IZ}kc[dabt[csQiufIbq*2MI8s8EID=+!uZ)&.6+ffYsA*2LrGl$(MeD8/i/wd$T#1E<=s{>M8}n8TmR*2NN%k7)-DF8EakiQcpWclG2H55x26&qK[TacxnYFEJaF@ShBeQo3K5N%:H7?CO/{Kwd}geuQK?.rktx.t+))Zjrv[KwkZEDdslGDks^:}5BXW<hE]Xc.2F!N*vo9KF?@5o/rP{z^X=E0SJS/}5T{#c.2F)/MSBV(2ic+h+%i+VDiR9}!](2z/L^![BaECkF^SZ&qLs@?>vc=c.74{s(959kA:qRlX?phk0]ch=YiHjuA(vrZmwi&}6GPFrR2#CJALW:<6osy@$yVizAv2^[oGQofy[nAy1fXQ=o]nnF.eVaCfiQ[1yApY3l<yOiW)9Ou<gGJBzNF/W{#Vx.<A]f9/B%S(IyQOg1Lw?OA+bsBM1%lbbydPIW0lq:sP()6ALo-(2I@9:8taELykI*PDAW6ol5>Grf[lAH5pBlK$Itt6<NNUW(0tE60LofobVSE@JX5N{&+H:kJ1uqDvgT]80=-9*>PgbBp&[O&5RP[pBfq?16f${SP-8Rw7EGtz%6^=173ag=9]W.R80eKZs$ckI@pSus2F73m.hL1TsAEj?m3[tTv<2W=EIa-YY%KGz/I/28pj9menHz3G0b[]Wir1q>#5h0tboufZ8:y?uc-3djDU9dT1FKxI=i{DlkP6<T4f-bZj72UFzpv8ofH8QLp^q)jj.V/h1H2NM43JHe@+pqq^C?S7W!5ko1Cqxs-ulN*#+z9q]1&:
+e}rGWi63qBWR$uH]AeAxiMFP*!UJB>&L7.CQ(jen1Y]GXzb4QG1GSv5^-e+a)5S&:?{fHh2FxuI=Bk^-R9e}Vk8x=BI@q[<UjA!gjKQ5dvcrX9>LGBIO]%qY*J=9-Flc2q}4/)tBCqB67vKP32!>7[B+wE!Q!qJtX@[VZ{&ZFCB?b(dBcuw2UEX&IeLZDM0BrhA*llQ<E6qF[4y%ezM.5:2b3>6uC3Ugm^:$#1Q51![Y7COCpiC!iuaxnVFohz3mzTa3<x+J>3&]Sr^K02AA%=3oome/qS#{&hI.ae6GMF7muawclM#&@rKL&-=**[&AfKNmsM.zc:Kheti(F3O(@Ev%PHwqQ.X$?OcJ<TUae}Q??Xje:K2?0ozv3^0dbyIUuFPTMS)hSGZZ]G8.E2y{emNE#Lf#]wdF>(=SBJvf7ub=(gm.jMK)k$W9wT)Y4ZX4Xnx!Pi$*!:IGsniLqMse:33QoRU6mpfLONraV3:h}4d$4!zoT7NB3QozOsnJ=&J:QGl7t:)QLy9=bpo.<OH4=?AWz4t9b?NmNG+2AX]$Dcva@/v@*ChY@8*@8ct3pquXeL:76kR{ewvx-1kA5JV{arA0ox6o.RnP#z4@Q]Ou69Y01[wRzWC*3Q(TPVvT)^7p5YBYc?]V=r@V.#P(IAx8}25%SYOq5mgv+f}z*q=%PjEDsz]8IlBgVvo4f&C.Io^iR<UW)5e^rA@=H&CDCLWSS0pYkEEd3LSUl0Og8*}qj?g+U4!3(#oF>[t+Bgl7k]-Q[8&g{pI1m-}xX4Gq5=W0DwIF)=.0Iw#0m$2hD&=zqwD36aV/2l-n?$34jkcqT><a:CH>yhsVy6^8GIo=pr<<:#>NUcWvlIleXrnWmY3o!]P=X5%[!F9xHFrdY]hhPTDZu5u9D$<s!HKEvD9@aj?ab%B75t8Eu+C##+r3KPQE9nMq{/4tqJL&{MPxT8KrrW+Bb%Bbj+C#0BOc?ElWKsZ{x8m/{DgimSD(?@jUKZQI
+8HO{9rd$4<[?45d&8tQf+>rS(LhGmEE6:uIPzlU2eW3wRq&{^#]!0u>&8w0pO8#4Y]l!.ikLTp=kgj5m3/2ZVGPucQ}cOJi[q}?52E@@L(QmP5Jv{09laY)99::X*G4:*Z3%U.ftRv12kn@r18R^1+*:B8@i2ZM$8R^dVOgASt(lH]io-Rw-0a4><jPni=Xz{VHSTQDq9>^^@eQtBE.o2pk<3})REg*/G)rducBBb10KMuKE5HSFGCP(EVt[&+!cwgoumr/KQ&BvUtb[y#U.7cDjx?=a0![#s5aF%HQOpLx*11he4(}aDB]%p#Xh80yQaybMyVl&/+R:/i7.fIeUrG)jIrE}LK0p@[Zbg&bc]ch!LcvR8T:zjlns#<lB/}MD!7B/^(!!bH5J)LFEAGw.{OIq&^wY:0ruwNg7?vS[$!YVOG90kNqXtD)bAdJ!uYp0SysBm6SVGK%R:o4wT[vG!78&Y]MRPh2wp0qyH.L^IeS/>VttFTV=0VdDl>Wt7)ON.epf)-6#tl66544MCLT]$:mfm?r-H@)DEn4M=Fq8f35cHDvgBFVkws:H/m*b?mhHiy%Jm}iw5(b6Q=PY5s^>BcwqO#h9u(4gl!GX3xIU!ptV3kL)g7s*KuZP&ZRSSjU.5oW&K[tNtz<=d*X{rDUs]$hQw*Zvn!Z*wCvS67rlad=i++%xWl!(ll&o6-ie::VLCW*h)@D(3&yYsUG2[b2TuWAfWEtXCx%T[e<BnnGC(usn$ohkn7R[*Bc/o%=Q.[GSsRNq!<ZQ]ha9NW%Kwx)Ib)b-vWJHDjp32SrGD7=z]q7=AGl<Niq*79Hxw*K?!>JZ.=uG]E&6P@ZtBA6>hUW?}93ts9BqIeB+Y?2YR^>vD7s=uJb0Z8VS?A4fVV-ci^5t)W4HTS>t]m5Jadonw5g*IoSuYJJAY6lTS!ukvaNHWAV/Va7OlB^c/%Ltq@]yY-AtyohpMy[[m{thpMAU)HeeLLn#6(tga>]+>gtR*k-{
+?Vw$C9wVZPhnicNKxpJhg<g8S-Q9<K.951Th}S7:7Ojp{j}tq/qYd=I@9j$LqDTq8SO&7vD7LqAcY@>n[&=3]*z:z{k^#/C)?@}mqAl<$gtCPn{-bNTVS8X91e*AKk(0JrUOUb>l4[3aSo^?S@>n7N)JZJg&kr5d[ggYn)e1OqAHw]}9ZYPJxRbsY!5l.-<uA+CJc=078k<=EGy15GZx1mEkJ8Gq9?}ao>^g.v5Q*DY)e.OB>^jVmaL*yWTuv[nk=1k%fYtvv@>O6oWu*Q20Bu.r)%s0+cu%xTODQx->}e]dL3Xz2?Vrg&aNpRPeIqnlO1!/MttU[v&-*Ws!Q{lgCzL[^0p>&g3@RIbCsSJ7(o1RA[{S{]^5j$+kd}t@K]x%0FVF![)%&ds3-7JWj]Qxml1<!V>{$$>3Lls0z&<Z./N4c3{.JV!LoeRz9]v>$PZ&iQ+6+D7@!LQOYGYn1.n=:BKOsmK<0QTLJ>I]Ev/DfS}2#gV4f*sH++T[?58RF]>Hk7+4*ae?f]hx&2dw@}1H4pag@&T=Ahb2XE-K<)6qgcsR9^0>8Cz+p??##QPyvl=a]KN*e}ULyqpVRq/qc4pwGWY&COZIK@74RDY:CA9<Ze<SbkhVG6nTAYpScTfTttN4>leN}bPf4!Tb[dKncPh(1wS!V3Lwqxvv+=:IcIA=Lobsx=IS&!s=$&dof8<>v-6%]d)%f00<1lyB{-swm7R[Pma5cb7YN/Oxcy:7&iTpMfW}>TH++z{]%+UbS7qlQG2gbVmZzKLg]DqjKrf1umsA.0AabD*rD[82-EjUp)Su(U7B[1egcbJG=8k+V7xS>T:(o$4=vwG6n2v&=i$7#jbak<LAiS!^:Gd-:<Z13>T+Y9EZ0G<5vN30)EV+HNS/JmXDo%ccH3<5}gGz8b0J54FEDIFUFNgX%tQloA!C3?lG8b[nOm=J@LUU!AW>c/W3o?gvCvOBS5BTjguXC[:Ypc@?8DlC*K0(a2YfqHvez}h}
+-WEElpwE<jt3o]l]PAO7WyNf/Kxc5v[HP86[i4I[}D/Eit3V!-9</Gzto70@Ooe+[*y?*Xg+yzO:LT3iH/Ln$3vM{ndDk.t]aOkS2O^aGZ}VKP<a/K6n1(60G1NinDN@jEmt#cZA1?7KsXer{zlH:nNY?X!}FxwG/Db5U9+[h6MC)*B9uh4^}hTCWu7/*O=&1J@1Z:O=8$l@e]CddGwX{({hLdX!g%*V9]YI4lC&tB8CIE[k)QwTqKiWyN!O?9kG-3wy:x!M?BH4MpxGLkHi(a>MneCO7C+HEG[Uk<Ws.J6b[C$!eM/8D5vk3ZemENAjPZknc+IIZ?2GWky.8{FxE1i:/({A%477G$WD{Ahfl?4xf[GM5!H}U!:jnNapZE#jUqk0i3U[]23rnv:BW$A-zwtd)CDl#!:c:kK3W&{=9s]ph)@d*DVa+g(/h%%ObdC)9R501Z!GZcr6(G^(/E%)]DSEz]PkCo9H}K@/>B[aR{O}M2:GdFX@0Le(sK1KtyJgKEN]9=>m[>0$G+FH%uuBSLMt$u@jC-OM{bd$@Qk7BB.ywBU]LN/BI!BF-QFKk&-[!{zJpK/a7-E*Gg}k4sH1$.FFr:5U}cImnMX0E]sk?KJIlC%}({D(Kgm{p(ne??yZW{0b^&YC1*<$o4oi:GJX9]B<wrgZz<[n+A?{51F3Eq-Y!PWm1@>L*{:W&W@R72VpM^t#9abrUqxN5{9r&2+3$!3&25B6t}Xq32Z5.0hi6X9a$V^f0B!mX#eun>V(Xiw0zE4Z]t<fDN-I8A:3$&hPR<FVAopvd&X1Fn$)Zn%]wQrdq{?LH9wQDQ!ILdA&*t^sAL:Ab5@t4(*%z}k(D{kEHU?z6B7u6qo:(Lx<$dnV9M2gvUDw^PG(*D$y8+Ce{-(CO!dM?oyPPplJubGAB%&Jleq(&=S<%-A9eR2h1SRRM?e7!GvG?Jd*Q@BsbooT@D[}k[15(ojoJKG9.^g=vZH}+yAwov*vxbsKrSo=nj$)
+kiFve0IcwhhNWt}COJFE2xva4*^8}o/<j]6t7J0y3Ac4*KMQJJzW(YbGyhQ{)$5ill/kHI@2=HrRMTXP-f(!U=rg0ccvMJ74t3T>pS@LuL.[9?(YzRnnCLBM/B$+kvyGwq!]]56C}5>x:[8ei2xq3/Ko(eaBwLErR+#7P6yIwz11!k=1fHHiBsfKviy0?1BK$(oN[E>VH!:32LMv4cxSN^hX[WEV0P^aL@jknf^K=VO3Y4K.EK7OLY03)N+})-L^fI}F2HJ?S6^=4tmS:=sToWc{g2SQJlAwdKSXgtH=ECELhkDe*Riug}I66}NudLyE6iUC[SC?91HbfzkT+UbAfVZgJYBII>Z{^!T.f1(rzLTfKKI(D.coVHr@h[sWe9TOZUQ%KcH[rv]>sc}<)sqmY9vK&IfLz&Q6UFL.)/@*FbY/<&2l-KKKd<>6O+z0j)e<}+/=Gwp]4F}[n1TAI^RsK{nB{mRAy2-I1vDDy)d&I&qPf<=o6DC#KdF3Kb0ZD4b0=1^tJuQA!!X7@}=rC^FrVRTh:$*)Lcj3LkEz{mS1/):9pjdHG$gBeW(i+X:CQKBnqxarve}OqX192l*X!PS!6&zu3w^A7[i<{D/Da3*/hE1KgfOt!b:$ZcR%Te$]a0Fk{rmNt{:u/EQ9>6EUUvdcOLZZ@mxY:J=GWj#hM?=ary&fq}FbjR3ClP^sKp[$b%u$8wpK$Zgozb28)bmiOPv^@@B(Z3qZ54F+6{u7<v<VEOqv%v>RG$B0Gi=hQjtY0Ug{!bI7u%WDK(QIHVYl.F5p506mW)QQO6sAN}oE8cviL=y}$$*QU=g0i{L=*jW&xn<}g(W^DJ99o1fumX!l1*c{n$z(4F]hBr3$ClvZ&GAEpm-p{=Nb1)82vF=Ni*C}]LC-0{mKpqv*0Z#luQs{YFO=UzU*+z.c)4Ef?ooJ^ciZ{u}*P@$n2Hso}e]CP9<*sSmtY+3>2{nilIb>V<@?f@sp]-SwnPZroz@?b!)*66D/
+y7>?B/5jEMvM6.B1IVK!HPsc]@eV!Cz]V+#@DxyM4ZLR1<LJvpX@gyq6m[zrcFXD=xfbk+Lb/hkXf<+agu0jRjpt:eO&KA?vd:nucxaK({ZD2EeZM@2vs7Z[0](8j+rB&-MUhCw(?CK%I@nfz+l%Be0{Pm}v9+2?fxdy&u0+ySSUs)4+GEWo4.?1!EKj4KwY[)ixE1Bx33.:bdRc+-^c0A?8)s?^DdokroXsA}aj2CjsV9yn{bJ^JT48>aFq&U+ici9g3ffr8C[LGCbr/tF(Q*P[DBsogWWz8T+4p^@i+{l^S^?(JC4/}J]}scfu7(K.a2):4UZ]htf=HeY&diboabyhw?]VmAVKy!6i@%jl3new&3nc*Nk?)K:+NGwjl<gBWoY>iDMl-V}zYFX?&GR(ZE@K)cjuGq?*8U/NsKj1>(tjQ^coN^6Zz3Kx&}Z=>9<}+JW#eNOS>$vkdX{Ny6dx+92:uX@F+26%(K?Eq!7>YP{EAOt2ToQWfWpSrq=b&=!ti{cETlih6fQ=#)Fimf1Jv8ODD]ZT]b.>5<tSOKs<HbdFA$I}bZmyyKLzdKl2{c!GxffwG5&Jrb1Rbt5HvaGaO^8dM*s<%@sHsj@u&2pDZX[6(kEs&yJiY]C-3kMbX]VE>k>kL1b2Wa:0:FVuv9(F?]yP99T6L7I0o0BL>3gI5pzRbEMF+W!8o%HS>lNSR[hkO2.8Jge{!fMA:U(T>V4d^nTFRh&p/?DOeRqjEL!=ifg8oIGs[e?GKqW(n=)&=08}-cm*cE!?cGBC3tu52Y)m[Rxp3t&xxDJ[D78Esu8*iZkf#C:@p-N#kjB4Slbqm7GJ2D72oL[.?49=9ecsXWE%5K*e]!XcDco?r/Xc})rnAy(QUl.2qsgLIrxW$O/#F5pDmj?Es9pmtt27>@N<bS35M9b.1HbUT.P3:hY0)QAY}9nc=B/e6^/A?bbq0.@>XWa4YdOPPb1Cv*g2S$QQ&!que>PvQz}C{!G*gIHuSv=O
+sN=$>0Uky+M1k.w/0e<]y-6N&=%m5Z4S#GY2]rP)RIGmPy*L(mCG(d8/<tr=pzkt^y(QtxJn{E+AxDWGQtD:cD=i&QaLZC=7x/:P^f*2LuplvJ*!7Cwf2V-MF:d@#&.3N2p:N<q81nx{lkM[3h{)MJw%^Lcp3uhT>/4.a0[N)z-vIVPS&eL=xh!^:L+l0+DZX]P/<dNPw6J:aQ(dFVb[EUsjN^-yr6CCgY}p)ZY+2iiQcv(!T@ir.5(yc9}dg*s?&wH0jw/x}etGdzc/7]2VjP)C68ZAn8m{>IA-KA<^$*(.TFhT<cTr/i[E5L<a{}NF<*MpQ9Z/bfH3IFb78H-Yz8Qt%Mn%i7=5?nN*w)qpY01DOj(@nhhrO%YB5.h7lNfSWHW)AvT)yr7Q{hu[LHCZlb-J<1e<li4.3Gkrjia[{icT(C>@wAym@W[fo)>Np^k}2dcoPNO^Rh&zDV!g70n-T(^{=2TI%>z$31$?qig9fOO:XwU2[yL&ig7f}2m6#AGwW>fl2XJ]2[BGxdz9Bj?a?}Ep9=rp5{CZ*lQl#}gt(LFjjjSZOR)vtm*67>m*65UOzW!M6ccuuyZmah.nf9mD4Gc]im>a!u(0YU=B[1DBjYtTe&/!yLZ:+opOW<y1>z7Pt#kATPJ$wP[N@H{b6v^Y:*<0P<k]XKBYaa#tvLED{+Af^w<Hq:(I&S5V65Us}({G*tog8[sv[JGF}Br^*=mt@1o9b6SndLRN9reES2TUWPCuK7AdSZw6hxz8:ZQ/rUd[>WM[u6&6DDs]hR!VZS{v=jQ0meS5]1=5&[(mCSgp0Fu}wD4n+DzHHZwm!.#@7PFE9p<924V)E%=(Q^:A9H3gGU@z1685u&I*@aaZRB/ZXT-[i8j6Xz%Lku[pqjgW#E^tG%wq2?Xqm}T6rD?^KyEFnKCnClDbX&oL1S7zNQ9JFE[</uU^d]dYo#w1(3^CV-aF3a-Nz1LO@yZ<YBEnG}JH0pZ6^I&n)yoXqv#4Cr5*
+(T#V<0J&%vSF]{:v&som{e9qbHP::CUP)Lv<lG=$E]F}NydBr51)^h:&IFEV(<cZagiS(52vbC<1nMI[XSHAukS(<Ys.hFcvDSfb?XL!QBmBSZaLj(E^psr{&XB.#WaYlM[8-(ox2AkYn>&5Ma2Uq({45DcZBd7R>xIvy*u<dZ:fKW>1Wi0b)?*q0&G-Y^w-?^ELUKCfBrzT(ALL6Be{%G:Zz)9G5=AbF2J}#A^X$1IJtuYBdQnsE=2xx-uEy:HI6?V/4wO=ppbD$vqa88(imkVccI$cQ+C1VzQZrx{x?#Mu6vcQtxYsP0xYsP0xKWqwp3^4$In]RJG!31S[GtFBi=2zUbF.X{r]9Y]p4lN^OS4Ok!TruSFPN9^m<8Lxl5)p^w]tlp&ZZtZoLpPjPLd^AD10nB66njRwZ!*}g+)LJ]5sJrFJ})T</AW!H<9GITA7WbvfrlXSFyShxKS^Qt7fXa>ol4>bY:u/!9OF4LVKpbO3m){zWeYA3?nQaaLj(o2xmlJd7W!{QubW}sU@#28{}5*5ke/B!Lc}%@psmuKu#Ycvuff9.Twjowm{Zbc/y$0vdxRva7AP[fZYGtfz0i[f^E8u5!cO[L0E#d3]9X$m&(c^kP%=@&PM4lBGLyTj&x55@qNQy7?}#+7<.Qua+[66&Q*{&3iKjZbSesnfoFPD<B-%[&AeOSZVv.k&?YNpPa-8Q/to+y:y9t3i5iDe0$[aY430d!VhDr*8i1w8l<vf2wrBZDks5*l<$vX(o-j]68jWdbwh4#?/Tc(e<hJBrz./un{X}gqawX>VaD=Y)FBle9^]dlo!5]RjX76lD]N2sl?}FUkTR7G!]SsnFj[L&*9-A6J8=[n2!97Ddv^OpOy-ZdqP(GB.r$c)0ro]9Hwxrl9fH!@j?a2UT1Zbsm<K&Gf/HbV*wMw9SN=G.fz=k<nQE]@tDudDXAJaPhRI[a/ihfMZk)bONJCaiJ%5B+V&d$51Fa8H}/<gsV-hJ:!oNH]T
+iYe1Vm$*Rh62lG/7D$MIpG5SpCa6E/9x&1m6$7exq6?foyR]i6)ubd7d^?OXXBGY)HJ6MjIc?sY?O.7dz&q#@p}wC.]Tq3o5h5EE5tuqyRo6ui}Ax#&Cl]wp.0*d1e}jXGk6SWxoXowH6x$SIMXg?YGzV7Xq-bkr+2&!Z:0b}mJ5C[7k5CptFnH:9{ibVP6Lhl}nQM$]MCBGq4a->I?P*=Zi[AkLM&f3)*0vZZEXc}kV1&Ha>)6[(**1wL1r6UiHE0qqhVP-DX@4SOX!33cf4^DOwc?NA3B.VB&xi(}6B1]nJ{ZTM{9/N1YdA[%I%%!&YBQZHGXiNAs)3njGLf[rslx)jn9z(tA<Aq+:xZth70+dKw&J0)wW-7Vg7dUG/4r*hfV)]zlE+a}O0[m0[&NM3E>^jJJW#!GY<CKvgt.Cj63Oyy^+wqswtvh=(iR6XlaqWB=Xvy$&ihl02yLW3An%P@?cv+UNGLYCYchk+0gZ[lzl]hiF%Xaf}#n21kKh!833y-)vY{ty)ciKm-IeS<Y/fE)qT!<S77>O7IZXe5AC]oC?nc&s=pwELEZ(T&YmT1Q1aG&}Add.c1aH54<6<18sgHN0JYgSor>ye#HYcv5X/f2)K/<mS0I&HQ+fYTF1tEqz{-bHdc&j)EXHj#):.N1/GGMYPf(p]p^xgec*#NpV*wX$ULmKISFo]D7@7:**hP5J?CoJJ?jk<]+jNl@l6CZJ]6yI!A]<Se$p&}TfIL.QY*$k@{B0}::KHdCcW6j&zGKcn1OoE1q>[>8>T31zu)#O!Sh-&hF7ahJ2lQS([n!!2RocJLFpkBkh2[J}JI+(kLRkIgpNTL*]PYbh[[z]WOX^upfrTsW%bNv+5}G)A1{dz%Qeb8UWh^@[in7Z&aCMIb]6hB8zxU@O(GPsV@{DWv?=brz0kBRok8CR{8VUs9AzO&yw9F3A]]&d<6u/ZsdRi^RtE1Oon)-K4%/F91+eKeEeF]>mT^9Vd[T)tdKqwiK$
+)dpqxYAW(8r4=(c&T6$$nt+sw8?MXkyQ@V2)cvl.m+zw-McgJ7@F[V^C!RpMZ3<fXO+2J9oJC@Z]jhtENV$[I&GLMZfV$d.bN>!He}4LRtjN=55.!(8=lvjvLm]}p1p/]?&ipVp9cQAD4-ZXJp*Ow9iKfO{*#OUKCo69{Vbl>[B#>gx!kk.-]Mxnr0/8d#&j%]ZlRX=ul4^3^N6pH59S0$.FRm{9QJA*P1qnqhQTEyU5j(qpX:y^:-=.B<un5khi8hA>C5P%-uqqK65<j]<A*PGGKxs&2-4{m6]76=F+C&=ul/2wh}NW^I(@H]55>)]/j=>wIJW8/CCw2ut^6j6sOL3cVS2G%egJa#aUR#^%8&NJ^Vaxg[e4&sUxOJ/)-X#E$bBd}B3qO2Min5gy*@Y+zC?X=3ahby>!V/AQSZE1rlY%}!RW=o[9x.e)c:G9(:tCW@fNkC#fV&2FiDQ2aOJ@?LiP.j(pxdJgPyS9D*21Gk)zzRD=hL%lfN[?fFST):Tp3a-b6vE)3hneplbyt@/T7>hsd0%f8T>>[}.W)BcxX&HfV&fn=g^U3EvxRCF}[tv[g-c#kd]%06tDi(0tAj3V*L%ki<eIe}.E:^cX5NMNsAuqcaU=3PyIAlOpc/P?*H{Oi#27QVWw9*)%QP)7K6j#P+Q<Pu/>ij*>UX]&}nFR?AGefXjLgl+=^A2)zF>43hmRD{HeHWgp1j%]m3g3V>6Ih^vw-J+!&z()zCCbXES=PHLHTyylgkD}tgWkR@OM?a2aA:c1!N=z=t}7z2/[VxLtX4H^f(zLt**$+^P-F-)(ee*JenJ51hTE6uQew-R$u.+l$YIFb]SI
 ");
\ No newline at end of file
@@ -87,6 +87,35 @@ mod kernels {
 #[allow(dead_code)]
 enum WithGroups {}
 
+/**
+```no_run
+use krnl::macros::module;
+
+#[module]
+#[krnl(no_build)]
+mod kernels {
+    use krnl::macros::kernel;
+
+    #[kernel]
+    fn coefficients(#[global] coefficients: Slice<f32>, #[item] y: &mut f32) {}
+}
+```
+```compile_fail
+use krnl::macros::module;
+
+#[module]
+#[krnl(no_build)]
+mod kernels {
+    use krnl::macros::kernel;
+
+    #[kernel]
+    fn coefficients<const N: u32>(coefficients: [f32; N], #[item] y: &mut f32) {}
+}
+```
+*/
+#[allow(dead_code)]
+enum PushArray {}
+
 #[module]
 pub mod kernels {
     use dry::macro_for;
@@ -135,9 +164,499 @@ pub mod kernels {
                 use krnl::krnl_core::{num_traits::FromPrimitive, half::{f16, bf16}};
                 [<basic_ $A>]::builder().unwrap().specialize($A::from_u32(16).unwrap());
             }
+
+            #[test]
+            #[ignore = "requires a device"]
+            fn [<test_basic_ $A _dispatch>]() {
+                #[allow(unused_imports)]
+                use krnl::{
+                    buffer::Buffer,
+                    device::Device,
+                    krnl_core::{num_traits::FromPrimitive, half::{f16, bf16}},
+                };
+
+                let device = Device::builder().build().unwrap();
+                // signed spec constants are sign extended
+                let a_spec = $A::from_i32(-3).or_else(|| $A::from_u32(3)).unwrap();
+                let one = $A::from_u32(1).unwrap();
+                let builder = [<basic_ $A>]::builder().unwrap().specialize(a_spec);
+                if builder.supported_on(&device).is_err() {
+                    return;
+                }
+                let mut a = Buffer::<$A>::zeros(device.clone(), 1).unwrap();
+                builder
+                    .build(device)
+                    .unwrap()
+                    .dispatch(a.as_slice_mut(), one)
+                    .unwrap();
+                assert_eq!(a.into_vec().unwrap(), [a_spec + one]);
+            }
         }
     });
 
+    #[kernel]
+    fn global_id_3d(#[global] y: UnsafeSlice<u32>) {
+        let [x_id, y_id, z_id] = kernel.global_id_3d();
+        let [x_threads, y_threads, _] = kernel.global_threads_3d();
+        let index = x_id + x_threads * (y_id + y_threads * z_id);
+        if index < y.len() {
+            unsafe {
+                *y.unsafe_index_mut(index) = index as u32;
+            }
+        }
+    }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_global_id_3d_dispatch() {
+        use krnl::{buffer::Buffer, device::Device};
+
+        let device = Device::builder().build().unwrap();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 2 * 3 * 4).unwrap();
+        global_id_3d::builder()
+            .unwrap()
+            .with_threads(1)
+            .build(device)
+            .unwrap()
+            .with_groups_3d([2, 3, 4])
+            .dispatch(y.as_slice_mut())
+            .unwrap();
+        assert_eq!(y.into_vec().unwrap(), (0..2 * 3 * 4).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_kernel_introspection() {
+        use krnl::{device::Device, scalar::ScalarType};
+
+        let device = Device::builder().build().unwrap();
+        let kernel = specs::builder()
+            .unwrap()
+            .specialize(10u32, 1.5f32)
+            .build(device.clone())
+            .unwrap();
+        assert!(kernel.name().contains("specs<"), "{}", kernel.name());
+        let specs: Vec<_> = kernel
+            .spec_descs()
+            .iter()
+            .map(|x| (x.name(), x.scalar_type()))
+            .collect();
+        assert_eq!(specs, [("X", ScalarType::U32), ("Y", ScalarType::F32)]);
+        assert!(kernel.slice_descs().is_empty());
+        assert!(kernel.push_descs().is_empty());
+        let kernel = global_id_3d::builder().unwrap().build(device).unwrap();
+        let slices: Vec<_> = kernel
+            .slice_descs()
+            .iter()
+            .map(|x| (x.name(), x.scalar_type(), x.mutable(), x.item()))
+            .collect();
+        assert_eq!(slices, [("y", ScalarType::U32, true, false)]);
+        assert_eq!(kernel.features(), Features::empty());
+    }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_global_id_3d_dispatch_async() {
+        use krnl::{buffer::Buffer, device::Device};
+
+        let device = Device::builder().build().unwrap();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 2 * 3 * 4).unwrap();
+        let kernel = global_id_3d::builder()
+            .unwrap()
+            .with_threads(1)
+            .build(device)
+            .unwrap()
+            .with_groups_3d([2, 3, 4]);
+        kernel
+            .dispatch_async(y.as_slice_mut())
+            .unwrap()
+            .wait()
+            .unwrap();
+        assert_eq!(y.into_vec().unwrap(), (0..2 * 3 * 4).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_global_id_3d_dispatch_async_drop() {
+        use krnl::{buffer::Buffer, device::Device};
+
+        let device = Device::builder().build().unwrap();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 2 * 3 * 4).unwrap();
+        let kernel = global_id_3d::builder()
+            .unwrap()
+            .with_threads(1)
+            .build(device.clone())
+            .unwrap()
+            .with_groups_3d([2, 3, 4]);
+        // dropping the handle does not cancel the dispatch
+        drop(kernel.dispatch_async(y.as_slice_mut()).unwrap());
+        device.wait().unwrap();
+        assert_eq!(y.into_vec().unwrap(), (0..2 * 3 * 4).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_global_id_3d_dispatch_owned() {
+        use krnl::{buffer::Buffer, device::Device};
+
+        let device = Device::builder().build().unwrap();
+        let y = Buffer::<u32>::zeros(device.clone(), 2 * 3 * 4).unwrap();
+        let kernel = global_id_3d::builder()
+            .unwrap()
+            .with_threads(1)
+            .build(device)
+            .unwrap()
+            .with_groups_3d([2, 3, 4]);
+        let (y,) = std::thread::spawn(move || kernel.dispatch_owned(y).unwrap())
+            .join()
+            .unwrap();
+        assert_eq!(y.into_vec().unwrap(), (0..2 * 3 * 4).collect::<Vec<u32>>());
+    }
+
+    #[kernel]
+    fn spec_i16_op<const A: i16>(#[item] a: &mut i32) {
+        *a = (A as i32) * 2 - 1;
+    }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_spec_i16_op_dispatch() {
+        use krnl::{buffer::Buffer, device::Device};
+
+        let device = Device::builder().build().unwrap();
+        let builder = spec_i16_op::builder().unwrap().specialize(-3i16);
+        if builder.supported_on(&device).is_err() {
+            return;
+        }
+        let mut a = Buffer::<i32>::zeros(device.clone(), 1).unwrap();
+        builder
+            .build(device)
+            .unwrap()
+            .dispatch(a.as_slice_mut())
+            .unwrap();
+        assert_eq!(a.into_vec().unwrap(), [-7]);
+    }
+
+    #[kernel]
+    fn mixed_push(#[item] y: &mut u32, a: u8, b: u32, c: u16) {
+        *y = a as u32 + 256 * c as u32 + 65536 * b;
+    }
+
+    #[test]
+    fn test_mixed_push_to_bytes() {
+        use krnl::kernel::DynKernelBuilder;
+
+        let bytes = mixed_push::builder().unwrap().to_bytes();
+        let loaded = DynKernelBuilder::from_bytes(&bytes).unwrap();
+        assert!(loaded.name().ends_with("mixed_push"), "{}", loaded.name());
+        assert_eq!(loaded.to_bytes(), bytes);
+    }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_mixed_push_dyn_kernel_dispatch() {
+        use krnl::{buffer::Buffer, device::Device, kernel::DynKernelBuilder, scalar::ScalarType};
+
+        let device = Device::builder().build().unwrap();
+        let builder = mixed_push::builder().unwrap();
+        if builder.supported_on(&device).is_err() {
+            return;
+        }
+        let kernel = DynKernelBuilder::from_bytes(&builder.to_bytes())
+            .unwrap()
+            .build(device.clone())
+            .unwrap();
+        // push constants are sorted by descending size
+        let push_descs: Vec<_> = kernel
+            .push_descs()
+            .iter()
+            .map(|x| (x.name(), x.scalar_type()))
+            .collect();
+        assert_eq!(
+            push_descs,
+            [
+                ("b", ScalarType::U32),
+                ("c", ScalarType::U16),
+                ("a", ScalarType::U8)
+            ]
+        );
+        let mut y = Buffer::<u32>::zeros(device.clone(), 1).unwrap();
+        // declaration order does not match push_descs
+        let result = unsafe {
+            kernel.dispatch(
+                &[y.as_slice_mut().into()],
+                &[1u8.into(), 2u32.into(), 3u16.into()],
+            )
+        };
+        assert!(result.is_err());
+        unsafe {
+            kernel
+                .dispatch(
+                    &[y.as_slice_mut().into()],
+                    &[2u32.into(), 3u16.into(), 1u8.into()],
+                )
+                .unwrap();
+        }
+        assert_eq!(y.into_vec().unwrap(), [1 + 256 * 3 + 65536 * 2]);
+    }
+
+    // 16 slices, the most that fit in 128 bytes of push constants.
+    #[kernel]
+    fn many_buffers(
+        #[item] x0: u32,
+        #[item] x1: u32,
+        #[item] x2: u32,
+        #[item] x3: u32,
+        #[item] x4: u32,
+        #[item] x5: u32,
+        #[item] x6: u32,
+        #[item] x7: u32,
+        #[item] x8: u32,
+        #[item] x9: u32,
+        #[item] x10: u32,
+        #[item] x11: u32,
+        #[item] x12: u32,
+        #[item] x13: u32,
+        #[item] x14: u32,
+        #[item] y: &mut u32,
+    ) {
+        *y += x0 + x1 + x2 + x3 + x4 + x5 + x6 + x7 + x8 + x9 + x10 + x11 + x12 + x13 + x14;
+    }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_many_buffers_dispatch() {
+        use krnl::{buffer::Buffer, device::Device};
+
+        let device = Device::builder().build().unwrap();
+        let kernel = many_buffers::builder()
+            .unwrap()
+            .build(device.clone())
+            .unwrap();
+        let xs: Vec<_> = (0..15)
+            .map(|i| {
+                Buffer::from(vec![i; 10])
+                    .into_device(device.clone())
+                    .unwrap()
+            })
+            .collect();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 10).unwrap();
+        // more descriptors than fit in the pool of one frame
+        device.batch(|| {
+            for _ in 0..3 {
+                kernel
+                    .dispatch(
+                        xs[0].as_slice(),
+                        xs[1].as_slice(),
+                        xs[2].as_slice(),
+                        xs[3].as_slice(),
+                        xs[4].as_slice(),
+                        xs[5].as_slice(),
+                        xs[6].as_slice(),
+                        xs[7].as_slice(),
+                        xs[8].as_slice(),
+                        xs[9].as_slice(),
+                        xs[10].as_slice(),
+                        xs[11].as_slice(),
+                        xs[12].as_slice(),
+                        xs[13].as_slice(),
+                        xs[14].as_slice(),
+                        y.as_slice_mut(),
+                    )
+                    .unwrap();
+            }
+        });
+        assert_eq!(y.into_vec().unwrap(), [3 * (0..15).sum::<u32>(); 10]);
+    }
+
+    #[kernel(reduce)]
+    fn reduce_sum(a: u32, b: u32) -> u32 {
+        a + b
+    }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_reduce_sum() {
+        use krnl::{buffer::Buffer, device::Device};
+
+        let device = Device::builder().build().unwrap();
+        let kernel = reduce_sum::builder()
+            .unwrap()
+            .build(device.clone())
+            .unwrap();
+        let threads = kernel.threads() as usize;
+        for n in [1, 7, threads, threads + 1, 3 * threads + 5, 100_000] {
+            let x = Buffer::from(vec![1u32; n])
+                .to_device(device.clone())
+                .unwrap();
+            let y = kernel.reduce_partial(x.as_slice()).unwrap();
+            let groups = (n / threads + usize::from(n % threads != 0))
+                .min(device.info().unwrap().max_groups() as usize);
+            assert_eq!(y.len(), groups, "{n}");
+            assert_eq!(y.into_vec().unwrap().iter().sum::<u32>(), n as u32, "{n}");
+            let y = kernel.reduce(x.as_slice()).unwrap();
+            assert_eq!(y.into_vec().unwrap(), [n as u32], "{n}");
+        }
+    }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_reduce_sum_errors() {
+        use krnl::{buffer::Buffer, device::Device};
+
+        let device = Device::builder().build().unwrap();
+        let kernel = reduce_sum::builder()
+            .unwrap()
+            .build(device.clone())
+            .unwrap();
+        let x = Buffer::<u32>::zeros(device, 0).unwrap();
+        assert!(kernel.reduce(x.as_slice()).is_err());
+        let x = Buffer::from(vec![1u32]);
+        assert!(kernel.reduce(x.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_reduce_sum_host() {
+        use krnl::device::Device;
+
+        assert!(reduce_sum::builder()
+            .unwrap()
+            .build(Device::host())
+            .is_err());
+    }
+
+    #[kernel(host)]
+    fn host_reverse_group<const N: u32>(
+        #[global] x: Slice<u32>,
+        #[group] x_group: UnsafeSlice<u32, { N as usize }>,
+        #[global] y: UnsafeSlice<u32>,
+    ) {
+        use krnl_core::buffer::UnsafeIndex;
+
+        let global_id = kernel.global_id();
+        let thread_id = kernel.thread_id();
+        unsafe {
+            *x_group.unsafe_index_mut(thread_id) = x[global_id];
+            kernel.group_barrier();
+            *y.unsafe_index_mut(global_id) =
+                *x_group.unsafe_index(kernel.threads() - thread_id - 1);
+        }
+    }
+
+    #[test]
+    fn test_host_reverse_group() {
+        use krnl::{buffer::Buffer, device::Device};
+
+        let threads = 4;
+        let x: Vec<u32> = (0..3 * threads).collect();
+        let mut y = Buffer::<u32>::zeros(Device::host(), x.len()).unwrap();
+        host_reverse_group::builder()
+            .unwrap()
+            .specialize(threads)
+            .with_threads(threads)
+            .build(Device::host())
+            .unwrap()
+            .with_groups(3)
+            .dispatch(x.as_slice().into(), y.as_slice_mut())
+            .unwrap();
+        assert_eq!(
+            y.into_vec().unwrap(),
+            [3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8]
+        );
+    }
+
+    #[kernel(host)]
+    fn host_saxpy(alpha: f32, #[item] x: f32, #[item] y: &mut f32) {
+        *y += alpha * x;
+    }
+
+    #[test]
+    fn test_host_saxpy() {
+        use krnl::{buffer::Buffer, device::Device};
+
+        let x = Buffer::from(vec![1f32, 2., 3.]);
+        let mut y = Buffer::from(vec![1f32; 3]);
+        host_saxpy::builder()
+            .unwrap()
+            .build(Device::host())
+            .unwrap()
+            .dispatch(2., x.as_slice(), y.as_slice_mut())
+            .unwrap();
+        assert_eq!(y.into_vec().unwrap(), [3., 5., 7.]);
+    }
+
+    #[kernel(host)]
+    fn host_scale(#[item] x: u32, #[item] y: &mut f32) {
+        *y = x as f32 / 2.;
+    }
+
+    #[test]
+    fn test_host_scale_run() {
+        use krnl::buffer::Buffer;
+
+        let x = Buffer::from(vec![1u32, 2, 3]);
+        let y = host_scale::run(x.as_slice()).unwrap();
+        assert_eq!(y.into_vec().unwrap(), [0.5, 1., 1.5]);
+    }
+
+    #[test]
+    fn test_host_scale_default_threads() {
+        use krnl::device::Device;
+
+        host_scale::set_default_threads(Some(8));
+        let scale_threads = host_scale::builder()
+            .unwrap()
+            .build(Device::host())
+            .unwrap()
+            .threads();
+        let saxpy_threads = host_saxpy::builder()
+            .unwrap()
+            .build(Device::host())
+            .unwrap()
+            .threads();
+        host_scale::set_default_threads(None);
+        assert_eq!(scale_threads, 8);
+        assert_ne!(saxpy_threads, 8);
+        host_saxpy::set_default_threads(Some(8));
+        assert_eq!(
+            krnl::kernel::default_threads(concat!(module_path!(), "::host_saxpy")),
+            Some(8)
+        );
+        host_saxpy::set_default_threads(None);
+    }
+
+    #[test]
+    fn test_host_saxpy_cancelled() {
+        use krnl::{buffer::Buffer, device::Device, kernel::CancelToken};
+
+        let x = Buffer::from(vec![1f32, 2., 3.]);
+        let mut y = Buffer::from(vec![1f32; 3]);
+        let cancel_token = CancelToken::new();
+        let kernel = host_saxpy::builder()
+            .unwrap()
+            .build(Device::host())
+            .unwrap()
+            .with_cancel_token(cancel_token.clone());
+        cancel_token.cancel();
+        kernel.dispatch(2., x.as_slice(), y.as_slice_mut()).unwrap();
+        assert_eq!(y.into_vec().unwrap(), [1.; 3]);
+    }
+
+    #[test]
+    fn test_host_requires_host_kernel() {
+        use krnl::device::Device;
+
+        let error = global_id_3d::builder()
+            .unwrap()
+            .build(Device::host())
+            .err()
+            .unwrap();
+        assert!(
+            error.to_string().contains("expected device, found host"),
+            "{error}"
+        );
+    }
+
     macro_rules! impl_group_kernel {
         ($($k:ident(|$n:ident| $e:expr)),* $(,)?) => {
             $(
@@ -160,6 +679,26 @@ pub mod kernels {
                     fn [<test_group_ $k>]() {
                         [<group_ $k>]::builder().unwrap().specialize(11);
                     }
+
+                    #[test]
+                    #[ignore = "requires a device"]
+                    fn [<test_group_ $k _dispatch>]() {
+                        use krnl::{buffer::Buffer, device::Device};
+
+                        let device = Device::builder().build().unwrap();
+                        let x = Buffer::from(vec![2f32]).to_device(device.clone()).unwrap();
+                        let mut y = Buffer::<f32>::zeros(device.clone(), 1).unwrap();
+                        let kernel = [<group_ $k>]::builder()
+                            .unwrap()
+                            .specialize(5)
+                            .build(device)
+                            .unwrap()
+                            .with_groups(1);
+                        unsafe {
+                            kernel.dispatch(x.as_slice(), y.as_slice_mut()).unwrap();
+                        }
+                        assert_eq!(y.into_vec().unwrap(), [2.]);
+                    }
                 }
             )*
         };
@@ -169,6 +708,9 @@ pub mod kernels {
         n(|n| n as usize),
         n_times_4_plus_1(|n| (n * 4 + 1) as usize),
         n_div_2(|n| (n / 2) as usize),
+        one_shl_n(|n| (1 << n) as usize),
+        n_shr_1_or_1(|n| ((n >> 1) | 1) as usize),
+        n_and_7_xor_1(|n| ((n & 7) ^ 1) as usize),
     );
 
     #[allow(non_snake_case)]
@@ -235,6 +777,64 @@ mod dependency {
     fn add_one_i32(#[item] x: i32, #[item] y: &mut i32) {
         *y = add_one(x);
     }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_add_one_i32_empty() {
+        use krnl::{buffer::Buffer, device::Device};
+
+        let device = Device::builder().build().unwrap();
+        let x = Buffer::<i32>::zeros(device.clone(), 0).unwrap();
+        let mut y = Buffer::<i32>::zeros(device.clone(), 0).unwrap();
+        add_one_i32::builder()
+            .unwrap()
+            .build(device)
+            .unwrap()
+            .dispatch(x.as_slice(), y.as_slice_mut())
+            .unwrap();
+    }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_add_one_i32_prepare() {
+        use krnl::{buffer::Buffer, device::Device};
+
+        let device = Device::builder().build().unwrap();
+        let x = Buffer::<i32>::from(vec![1, 2, 3])
+            .to_device(device.clone())
+            .unwrap();
+        let mut y = Buffer::<i32>::zeros(device.clone(), 3).unwrap();
+        let kernel = add_one_i32::builder().unwrap().build(device).unwrap();
+        let prepared = kernel.prepare(x.as_slice(), y.as_slice_mut()).unwrap();
+        prepared.dispatch().unwrap();
+        // prepared dispatches can be shared between threads
+        std::thread::scope(|s| {
+            s.spawn(|| prepared.dispatch().unwrap());
+        });
+        drop(prepared);
+        assert_eq!(y.into_vec().unwrap(), [2, 3, 4]);
+    }
+
+    #[test]
+    #[ignore = "requires a device"]
+    fn test_add_one_i32_cancelled() {
+        use krnl::{buffer::Buffer, device::Device, kernel::CancelToken};
+
+        let device = Device::builder().build().unwrap();
+        let x = Buffer::<i32>::from(vec![1, 2, 3])
+            .to_device(device.clone())
+            .unwrap();
+        let mut y = Buffer::<i32>::zeros(device.clone(), 3).unwrap();
+        let cancel_token = CancelToken::new();
+        let kernel = add_one_i32::builder()
+            .unwrap()
+            .build(device)
+            .unwrap()
+            .with_cancel_token(cancel_token.clone());
+        cancel_token.cancel();
+        kernel.dispatch(x.as_slice(), y.as_slice_mut()).unwrap();
+        assert_eq!(y.into_vec().unwrap(), [0; 3]);
+    }
 }
 
 #[module]